@@ -0,0 +1,105 @@
+// Mock wallet bridge for tests.
+//
+// Drop-in replacement for `wallet.js`: swap it in for "/wallet.js" under `[web.resource]` in
+// Dioxus.toml. It implements the same contract the app binds to in `src/wallet_adapter.rs`:
+//
+//   window.MountWalletAdapter()  renders a connect button into #ore-wallet-adapter
+//   "ore-pubkey" event           dispatched with { pubkey: number[] | null }
+//   window.OreTxSigner({ b64 })  resolves to the base64 wire tx signed by the mock key
+//
+// The key is an Ed25519 WebCrypto key generated per page load. Set
+// `window.OreMockWallet.reject = true` to simulate the user declining a signature.
+
+(() => {
+  const state = {
+    keypair: null,
+    pubkey: null,
+    reject: false,
+    signed: [],
+  };
+  window.OreMockWallet = state;
+
+  function dispatchPubkey() {
+    const pubkey = state.pubkey ? Array.from(state.pubkey) : null;
+    window.dispatchEvent(new CustomEvent("ore-pubkey", { detail: { pubkey } }));
+  }
+
+  async function connect() {
+    state.keypair = await crypto.subtle.generateKey({ name: "Ed25519" }, true, ["sign", "verify"]);
+    state.pubkey = new Uint8Array(await crypto.subtle.exportKey("raw", state.keypair.publicKey));
+    dispatchPubkey();
+  }
+
+  function disconnect() {
+    state.keypair = null;
+    state.pubkey = null;
+    dispatchPubkey();
+  }
+
+  function readCompactU16(bytes, offset) {
+    let value = 0;
+    let size = 0;
+    for (;;) {
+      const byte = bytes[offset + size];
+      value |= (byte & 0x7f) << (7 * size);
+      size += 1;
+      if ((byte & 0x80) === 0) {
+        return [value, size];
+      }
+    }
+  }
+
+  function equal(a, b) {
+    return a.length === b.length && a.every((v, i) => v === b[i]);
+  }
+
+  async function signTransaction(msg) {
+    if (state.reject || !state.keypair) {
+      return undefined;
+    }
+    const tx = Uint8Array.from(atob(msg.b64), (c) => c.charCodeAt(0));
+
+    // Wire format: compact-u16 signature count, signatures, then the message
+    const [numSignatures, sigLen] = readCompactU16(tx, 0);
+    const messageOffset = sigLen + 64 * numSignatures;
+    const message = tx.slice(messageOffset);
+
+    // Message: 3 byte header, compact-u16 account count, account keys
+    const [numKeys, keysLen] = readCompactU16(message, 3);
+    const keysOffset = 3 + keysLen;
+    for (let i = 0; i < Math.min(numKeys, numSignatures); i++) {
+      const key = message.slice(keysOffset + 32 * i, keysOffset + 32 * (i + 1));
+      if (equal(key, state.pubkey)) {
+        const signature = new Uint8Array(
+          await crypto.subtle.sign({ name: "Ed25519" }, state.keypair.privateKey, message)
+        );
+        tx.set(signature, sigLen + 64 * i);
+        state.signed.push(msg.b64);
+        return btoa(String.fromCharCode(...tx));
+      }
+    }
+    return undefined;
+  }
+
+  window.MountWalletAdapter = () => {
+    const container = document.getElementById("ore-wallet-adapter");
+    if (!container) {
+      return;
+    }
+    container.innerHTML = "";
+    const button = document.createElement("button");
+    button.id = "ore-mock-wallet-button";
+    button.textContent = state.pubkey ? "Disconnect" : "Connect";
+    button.onclick = async (e) => {
+      e.stopPropagation();
+      if (state.pubkey) {
+        disconnect();
+      } else {
+        await connect();
+      }
+      button.textContent = state.pubkey ? "Disconnect" : "Connect";
+    };
+    container.appendChild(button);
+  };
+  window.OreTxSigner = signTransaction;
+})();
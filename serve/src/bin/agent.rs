@@ -0,0 +1,174 @@
+//! Native hashing agent for the web app.
+//!
+//! Listens for WebSocket connections from the app, advertises its core count and hashes
+//! `WebWorkerRequest`s on OS threads, one request per core. The app treats each core as one more
+//! web worker and merges the results into its own batches.
+//!
+//! ```sh
+//! ore-agent --listen 0.0.0.0:9100 --cores 8
+//! ```
+
+use std::{
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+use futures_util::{SinkExt, StreamExt};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+use warp::{
+    ws::{Message, WebSocket},
+    Filter,
+};
+
+// The app's hashing loop, so agent cores hash exactly like web workers
+#[path = "../../../src/miner/hasher.rs"]
+mod hasher;
+
+use hasher::find_next_hash;
+
+/// Mining request, same shape as the app's web worker request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebWorkerRequest {
+    pub challenge: [u8; 32],
+    pub nonce: [u8; 8],
+    pub offset: u64,
+    pub cutoff_time: u64,
+    pub power_level: usize,
+    pub worker: usize,
+    pub target: Option<u32>,
+    #[serde(default)]
+    pub share_difficulty: Option<u32>,
+}
+
+/// Mining response, same shape as the app's web worker response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebWorkerResponse {
+    pub hash: [u8; 32],
+    pub digest: [u8; 16],
+    pub nonce: [u8; 8],
+    pub difficulty: u32,
+    pub offset: u64,
+    pub challenge: [u8; 32],
+    pub power_level: usize,
+    pub worker: usize,
+    pub hashes: u64,
+    pub elapsed_ms: u64,
+    #[serde(default)]
+    pub share: bool,
+}
+
+/// Message sent to the app.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum AgentMessage {
+    Hello { cores: usize },
+    Result(WebWorkerResponse),
+}
+
+/// Message sent by the app.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum AgentCommand {
+    Hash(WebWorkerRequest),
+    Stop,
+}
+
+#[tokio::main]
+async fn main() {
+    let (addr, cores) = match parse_args() {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("{}", err);
+            eprintln!("Usage: ore-agent [--listen 0.0.0.0:9100] [--cores N]");
+            std::process::exit(1);
+        }
+    };
+
+    let routes = warp::path::end()
+        .and(warp::ws())
+        .map(move |ws: warp::ws::Ws| ws.on_upgrade(move |socket| serve(socket, cores)));
+
+    println!("Hashing on {} cores, listening on ws://{}", cores, addr);
+    warp::serve(routes).run(addr).await;
+}
+
+fn parse_args() -> Result<(SocketAddr, usize), String> {
+    let mut addr: SocketAddr = ([0, 0, 0, 0], 9100).into();
+    let mut cores = std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1);
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--listen" => {
+                let value = args.next().unwrap_or_default();
+                addr = value
+                    .parse()
+                    .map_err(|_| format!("Invalid listen address: {}", value))?;
+            }
+            "--cores" => {
+                let value = args.next().unwrap_or_default();
+                cores = value
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|cores| cores.gt(&0))
+                    .ok_or(format!("Invalid core count: {}", value))?;
+            }
+            _ => return Err(format!("Unknown argument: {}", arg)),
+        }
+    }
+    Ok((addr, cores))
+}
+
+/// Hashes requests from one app connection until it closes.
+async fn serve(socket: WebSocket, cores: usize) {
+    let (mut ws_tx, mut ws_rx) = socket.split();
+    let (tx, mut rx) = mpsc::unbounded_channel::<AgentMessage>();
+
+    // Forward results to the app
+    tokio::spawn(async move {
+        while let Some(msg) = rx.recv().await {
+            let text = serde_json::to_string(&msg).unwrap();
+            if ws_tx.send(Message::text(text)).await.is_err() {
+                break;
+            }
+        }
+    });
+    tx.send(AgentMessage::Hello { cores }).ok();
+
+    // A new challenge or a stop command cuts the running requests short
+    let mut challenge = [0u8; 32];
+    let mut stop = Arc::new(AtomicBool::new(false));
+    while let Some(Ok(msg)) = ws_rx.next().await {
+        let Ok(text) = msg.to_str() else {
+            continue;
+        };
+        match serde_json::from_str::<AgentCommand>(text) {
+            Ok(AgentCommand::Hash(req)) => {
+                if req.challenge.ne(&challenge) {
+                    stop.store(true, Ordering::Relaxed);
+                    stop = Arc::new(AtomicBool::new(false));
+                    challenge = req.challenge;
+                }
+                let stop = stop.clone();
+                let tx = tx.clone();
+                tokio::task::spawn_blocking(move || {
+                    let should_stop = |_| stop.load(Ordering::Relaxed);
+                    let res = find_next_hash(req, should_stop, |share| {
+                        tx.send(AgentMessage::Result(share)).ok();
+                    });
+                    tx.send(AgentMessage::Result(res)).ok();
+                });
+            }
+            Ok(AgentCommand::Stop) => {
+                stop.store(true, Ordering::Relaxed);
+                stop = Arc::new(AtomicBool::new(false));
+                challenge = [0u8; 32];
+            }
+            Err(err) => eprintln!("Invalid command: {}", err),
+        }
+    }
+    stop.store(true, Ordering::Relaxed);
+}
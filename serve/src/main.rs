@@ -1,7 +1,18 @@
+mod pool;
+
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
 use warp::Filter;
 
+use crate::pool::Pool;
+
 #[tokio::main]
 async fn main() {
+    // Reference mining pool
+    let pool = Arc::new(Mutex::new(Pool::new()));
+    tokio::spawn(pool::run_rounds(pool.clone()));
+
     // Define the directory to serve files from
     let dir = warp::fs::dir("../dist");
 
@@ -12,7 +23,11 @@ async fn main() {
     let fallback = warp::any().map(|| warp::reply::html(include_str!("../../dist/index.html")));
 
     // Combine routes
-    let routes = dir.or(index).or(fallback).with(warp::log("ore-app-classic"));
+    let routes = pool::routes(pool)
+        .or(dir)
+        .or(index)
+        .or(fallback)
+        .with(warp::log("ore-app-classic"));
 
     // Start the warp server
     warp::serve(routes).run(([0, 0, 0, 0], 8080)).await;
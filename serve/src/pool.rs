@@ -0,0 +1,450 @@
+//! Reference mining pool with in-memory state.
+//!
+//! Members fetch a job (challenge and nonce range), hash it, and post every solution above the
+//! share difficulty. Each accepted share credits the member in proportion to its difficulty.
+//! At the end of each round the pool submits the best solution for its shared proof.
+//!
+//! This server is for running the protocol locally. Rounds use random challenges, and
+//! `submit_best` only records the solution. A production pool would read the challenge from its
+//! on-chain proof and send the mine transaction there.
+
+use std::{
+    collections::{HashMap, HashSet},
+    convert::Infallible,
+    ops::Range,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use drillx::Solution;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+use warp::{http::StatusCode, Filter, Rejection, Reply};
+
+/// Length of a round. Matches the time between submissions of an ORE proof.
+const ROUND_DURATION: Duration = Duration::from_secs(60);
+
+/// Nonces handed out per job.
+const JOB_NONCES: u64 = 1 << 32;
+
+/// Minimum difficulty of a share. Low enough that browsers find shares every few seconds.
+const SHARE_DIFFICULTY: u32 = 8;
+
+/// Minimum difficulty the program accepts for a submission.
+const MIN_DIFFICULTY: u32 = 8;
+
+/// Work handed to a member.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Job {
+    pub round: u64,
+    pub challenge: [u8; 32],
+    pub nonce_start: u64,
+    pub nonce_end: u64,
+    pub share_difficulty: u32,
+    pub cutoff_time: u64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct JobQuery {
+    pub member: String,
+}
+
+/// A solution posted by a member.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Share {
+    pub member: String,
+    pub round: u64,
+    pub digest: [u8; 16],
+    pub nonce: [u8; 8],
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShareResult {
+    pub accepted: bool,
+    pub difficulty: u32,
+    pub reason: Option<String>,
+}
+
+/// A member's credit with the pool.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MemberStats {
+    pub member: String,
+    pub shares: u64,
+    pub contribution: u64,
+}
+
+/// A solution the pool submitted for its proof.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Submission {
+    pub round: u64,
+    pub member: String,
+    pub difficulty: u32,
+}
+
+#[derive(Default)]
+struct Round {
+    id: u64,
+    challenge: [u8; 32],
+    next_nonce: u64,
+    assigned: HashMap<String, Vec<Range<u64>>>,
+    seen: HashSet<u64>,
+    best: Option<(String, u32)>,
+}
+
+pub struct Pool {
+    round: Round,
+    started_at: Instant,
+    members: HashMap<String, MemberStats>,
+    submissions: Vec<Submission>,
+}
+
+pub type PoolState = Arc<Mutex<Pool>>;
+
+impl Default for Pool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Pool {
+    pub fn new() -> Self {
+        Self {
+            round: Round {
+                challenge: rand::thread_rng().gen(),
+                ..Default::default()
+            },
+            started_at: Instant::now(),
+            members: HashMap::new(),
+            submissions: vec![],
+        }
+    }
+
+    fn job(&mut self, member: &str) -> Job {
+        let round = &mut self.round;
+        let nonce_start = round.next_nonce;
+        let nonce_end = nonce_start.saturating_add(JOB_NONCES);
+        round.next_nonce = nonce_end;
+        round
+            .assigned
+            .entry(member.to_string())
+            .or_default()
+            .push(nonce_start..nonce_end);
+        Job {
+            round: round.id,
+            challenge: round.challenge,
+            nonce_start,
+            nonce_end,
+            share_difficulty: SHARE_DIFFICULTY,
+            cutoff_time: ROUND_DURATION
+                .saturating_sub(self.started_at.elapsed())
+                .as_secs(),
+        }
+    }
+
+    fn share(&mut self, share: Share) -> ShareResult {
+        let reject = |reason: &str| ShareResult {
+            accepted: false,
+            difficulty: 0,
+            reason: Some(reason.to_string()),
+        };
+        let round = &mut self.round;
+        if share.round.ne(&round.id) {
+            return reject("stale round");
+        }
+        let nonce = u64::from_le_bytes(share.nonce);
+        let is_assigned = round
+            .assigned
+            .get(&share.member)
+            .is_some_and(|ranges| ranges.iter().any(|r| r.contains(&nonce)));
+        if !is_assigned {
+            return reject("nonce not assigned to member");
+        }
+        let solution = Solution::new(share.digest, share.nonce);
+        if !solution.is_valid(&round.challenge) {
+            return reject("invalid solution");
+        }
+        let difficulty = solution.to_hash().difficulty();
+        if difficulty.lt(&SHARE_DIFFICULTY) {
+            return reject("below share difficulty");
+        }
+
+        // Only accepted shares claim their nonce, so a bad post cannot block the real one
+        if !round.seen.insert(nonce) {
+            return reject("duplicate share");
+        }
+
+        // Credit doubles with each bit of difficulty above the share difficulty
+        let stats = self
+            .members
+            .entry(share.member.clone())
+            .or_insert_with(|| MemberStats {
+                member: share.member.clone(),
+                ..Default::default()
+            });
+        stats.shares += 1;
+        stats.contribution = stats
+            .contribution
+            .saturating_add(1u64 << (difficulty - SHARE_DIFFICULTY).min(63));
+        if round.best.as_ref().map_or(true, |(_, best)| difficulty.gt(best)) {
+            round.best = Some((share.member, difficulty));
+        }
+        ShareResult {
+            accepted: true,
+            difficulty,
+            reason: None,
+        }
+    }
+
+    /// Closes the round if it is over and starts the next one.
+    fn tick(&mut self) {
+        if self.started_at.elapsed().lt(&ROUND_DURATION) {
+            return;
+        }
+        if let Some((member, difficulty)) = self.round.best.take() {
+            if difficulty.ge(&MIN_DIFFICULTY) {
+                self.submit_best(member, difficulty);
+            }
+        }
+        self.round = Round {
+            id: self.round.id + 1,
+            challenge: rand::thread_rng().gen(),
+            ..Default::default()
+        };
+        self.started_at = Instant::now();
+    }
+
+    fn submit_best(&mut self, member: String, difficulty: u32) {
+        println!(
+            "Round {}: submitting difficulty {} found by {}",
+            self.round.id, difficulty, member
+        );
+        self.submissions.push(Submission {
+            round: self.round.id,
+            member,
+            difficulty,
+        });
+    }
+}
+
+/// Closes rounds on time, even while no member is asking for work.
+pub async fn run_rounds(pool: PoolState) {
+    loop {
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        pool.lock().await.tick();
+    }
+}
+
+pub fn routes(pool: PoolState) -> impl Filter<Extract = (impl Reply,), Error = Rejection> + Clone {
+    let with_pool = warp::any().map(move || pool.clone());
+
+    let job = warp::path!("pool" / "job")
+        .and(warp::get())
+        .and(warp::query::<JobQuery>())
+        .and(with_pool.clone())
+        .and_then(get_job);
+    let share = warp::path!("pool" / "share")
+        .and(warp::post())
+        .and(warp::body::json::<Share>())
+        .and(with_pool.clone())
+        .and_then(post_share);
+    let members = warp::path!("pool" / "members")
+        .and(warp::get())
+        .and(with_pool.clone())
+        .and_then(get_members);
+    let member = warp::path!("pool" / "members" / String)
+        .and(warp::get())
+        .and(with_pool.clone())
+        .and_then(get_member);
+    let submissions = warp::path!("pool" / "submissions")
+        .and(warp::get())
+        .and(with_pool)
+        .and_then(get_submissions);
+
+    job.or(share)
+        .or(members)
+        .or(member)
+        .or(submissions)
+        .with(
+            warp::cors()
+                .allow_any_origin()
+                .allow_methods(["GET", "POST"])
+                .allow_header("content-type"),
+        )
+}
+
+async fn get_job(query: JobQuery, pool: PoolState) -> Result<impl Reply, Infallible> {
+    let job = pool.lock().await.job(&query.member);
+    Ok(warp::reply::json(&job))
+}
+
+async fn post_share(share: Share, pool: PoolState) -> Result<impl Reply, Infallible> {
+    let result = pool.lock().await.share(share);
+    let status = if result.accepted {
+        StatusCode::OK
+    } else {
+        StatusCode::BAD_REQUEST
+    };
+    Ok(warp::reply::with_status(warp::reply::json(&result), status))
+}
+
+async fn get_members(pool: PoolState) -> Result<impl Reply, Infallible> {
+    let pool = pool.lock().await;
+    let members: Vec<&MemberStats> = pool.members.values().collect();
+    Ok(warp::reply::json(&members))
+}
+
+async fn get_member(member: String, pool: PoolState) -> Result<impl Reply, Infallible> {
+    let pool = pool.lock().await;
+    let stats = pool.members.get(&member).cloned().unwrap_or(MemberStats {
+        member,
+        ..Default::default()
+    });
+    Ok(warp::reply::json(&stats))
+}
+
+async fn get_submissions(pool: PoolState) -> Result<impl Reply, Infallible> {
+    let pool = pool.lock().await;
+    Ok(warp::reply::json(&pool.submissions))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Hashes the job's range until it finds a share.
+    fn find_share(member: &str, job: &Job) -> Share {
+        let mut memory = drillx::equix::SolverMemory::new();
+        for nonce in job.nonce_start..job.nonce_end {
+            let Ok(hash) =
+                drillx::hash_with_memory(&mut memory, &job.challenge, &nonce.to_le_bytes())
+            else {
+                continue;
+            };
+            if hash.difficulty().ge(&SHARE_DIFFICULTY) {
+                return Share {
+                    member: member.to_string(),
+                    round: job.round,
+                    digest: hash.d,
+                    nonce: nonce.to_le_bytes(),
+                };
+            }
+        }
+        panic!("no share in job range");
+    }
+
+    fn end_round(pool: &mut Pool) {
+        pool.started_at = Instant::now().checked_sub(ROUND_DURATION).unwrap();
+    }
+
+    #[test]
+    fn job_allocates_disjoint_ranges() {
+        let mut pool = Pool::new();
+        let a = pool.job("a");
+        let b = pool.job("b");
+        let a2 = pool.job("a");
+        assert_eq!(a.nonce_start, 0);
+        assert_eq!(a.nonce_end - a.nonce_start, JOB_NONCES);
+        assert_eq!(b.nonce_start, a.nonce_end);
+        assert_eq!(a2.nonce_start, b.nonce_end);
+        assert_eq!(a.share_difficulty, SHARE_DIFFICULTY);
+        assert_eq!(a.challenge, pool.round.challenge);
+        assert_eq!(
+            pool.round.assigned["a"],
+            vec![a.nonce_start..a.nonce_end, a2.nonce_start..a2.nonce_end]
+        );
+        assert_eq!(pool.round.assigned["b"], vec![b.nonce_start..b.nonce_end]);
+    }
+
+    #[test]
+    fn share_credits_by_difficulty() {
+        let mut pool = Pool::new();
+        let job = pool.job("a");
+        let share = find_share("a", &job);
+        let res = pool.share(share);
+        assert!(res.accepted);
+        let stats = &pool.members["a"];
+        assert_eq!(stats.shares, 1);
+        assert_eq!(stats.contribution, 1 << (res.difficulty - SHARE_DIFFICULTY));
+        assert_eq!(pool.round.best, Some(("a".to_string(), res.difficulty)));
+    }
+
+    #[test]
+    fn share_rejects_stale_round() {
+        let mut pool = Pool::new();
+        let job = pool.job("a");
+        let mut share = find_share("a", &job);
+        share.round += 1;
+        assert_eq!(pool.share(share).reason.as_deref(), Some("stale round"));
+    }
+
+    #[test]
+    fn share_rejects_unassigned_nonce() {
+        let mut pool = Pool::new();
+        let job = pool.job("a");
+        pool.job("b");
+        let mut share = find_share("a", &job);
+        share.member = "b".to_string();
+        assert_eq!(
+            pool.share(share).reason.as_deref(),
+            Some("nonce not assigned to member")
+        );
+        assert!(!pool.members.contains_key("b"));
+    }
+
+    #[test]
+    fn share_rejects_duplicate() {
+        let mut pool = Pool::new();
+        let job = pool.job("a");
+        let share = find_share("a", &job);
+        assert!(pool.share(share.clone()).accepted);
+        assert_eq!(pool.share(share).reason.as_deref(), Some("duplicate share"));
+        assert_eq!(pool.members["a"].shares, 1);
+    }
+
+    #[test]
+    fn share_rejects_invalid_solution() {
+        let mut pool = Pool::new();
+        let job = pool.job("a");
+        let share = find_share("a", &job);
+        let mut invalid = share.clone();
+        invalid.digest = [0; 16];
+        assert_eq!(
+            pool.share(invalid).reason.as_deref(),
+            Some("invalid solution")
+        );
+        assert!(!pool.members.contains_key("a"));
+
+        // The rejected post does not mark the nonce as seen
+        assert!(pool.share(share).accepted);
+        assert_eq!(pool.members["a"].shares, 1);
+    }
+
+    #[test]
+    fn tick_rolls_over_round() {
+        let mut pool = Pool::new();
+        let job = pool.job("a");
+        let share = find_share("a", &job);
+        let difficulty = pool.share(share).difficulty;
+
+        // Nothing happens before the round is over
+        pool.tick();
+        assert_eq!(pool.round.id, 0);
+
+        end_round(&mut pool);
+        pool.tick();
+        assert_eq!(pool.round.id, 1);
+        assert_ne!(pool.round.challenge, job.challenge);
+        assert_eq!(pool.round.next_nonce, 0);
+        assert!(pool.round.assigned.is_empty());
+        assert!(pool.round.best.is_none());
+        assert_eq!(pool.submissions.len(), 1);
+        assert_eq!(pool.submissions[0].member, "a");
+        assert_eq!(pool.submissions[0].difficulty, difficulty);
+        assert_eq!(pool.members["a"].shares, 1);
+
+        // Shares for the closed round are stale
+        let share = find_share("a", &job);
+        assert_eq!(pool.share(share).reason.as_deref(), Some("stale round"));
+    }
+}
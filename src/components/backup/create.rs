@@ -0,0 +1,114 @@
+use dioxus::prelude::*;
+
+use crate::{
+    components::{BackButton, Spinner, MIN_PASSPHRASE_LENGTH},
+    keystore, utils,
+};
+
+use super::BackupStep;
+
+pub const BACKUP_FILENAME: &str = "ore-backup.json";
+
+#[component]
+pub fn BackupCreate(step: Signal<BackupStep>) -> Element {
+    let nav = navigator();
+    let mut passphrase_input = use_signal(|| "".to_string());
+    let mut confirm_input = use_signal(|| "".to_string());
+    let mut err_msg = use_signal::<Option<String>>(|| None);
+    let mut is_busy = use_signal(|| false);
+
+    let error_text = if passphrase_input.read().is_empty() {
+        None
+    } else if passphrase_input.read().len().lt(&MIN_PASSPHRASE_LENGTH) {
+        Some(format!("Use at least {} characters", MIN_PASSPHRASE_LENGTH))
+    } else if !confirm_input.read().is_empty() && passphrase_input.read().ne(&*confirm_input.read()) {
+        Some("Passphrases do not match".to_string())
+    } else {
+        None
+    };
+    let is_disabled = *is_busy.read()
+        || error_text.is_some()
+        || passphrase_input.read().is_empty()
+        || passphrase_input.read().ne(&*confirm_input.read());
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-16 grow w-full h-full",
+            div {
+                class: "flex flex-col gap-4 -mt-3.5",
+                BackButton {
+                    onclick: move |_| {
+                        nav.go_back()
+                    }
+                }
+                div {
+                    class: "flex flex-col gap-3",
+                    h2 {
+                        "Back up"
+                    }
+                    p {
+                        class: "text-lg",
+                        "Download an encrypted backup of your keypairs, account names and settings."
+                    }
+                    p {
+                        class: "text-sm text-gray-300 dark:text-gray-700",
+                        "The backup can only be opened with the passphrase you choose here. "
+                        "Store the file somewhere other than this device."
+                    }
+                }
+            }
+            div {
+                class: "flex flex-col gap-4",
+                input {
+                    class: "mx-auto w-full py-2 text-center placeholder-gray-200 dark:placeholder-gray-700 bg-transparent",
+                    r#type: "password",
+                    placeholder: "Backup passphrase",
+                    value: "{passphrase_input}",
+                    oninput: move |e| passphrase_input.set(e.value()),
+                }
+                input {
+                    class: "mx-auto w-full py-2 text-center placeholder-gray-200 dark:placeholder-gray-700 bg-transparent",
+                    r#type: "password",
+                    placeholder: "Confirm passphrase",
+                    value: "{confirm_input}",
+                    oninput: move |e| confirm_input.set(e.value()),
+                }
+                if let Some(error_text) = error_text.or(err_msg.read().clone()) {
+                    p {
+                        class: "text-red-500 text-sm text-center",
+                        "{error_text}"
+                    }
+                }
+            }
+            button {
+                class: "bg-green-500 disabled:opacity-50 hover:bg-green-600 active:bg-green-700 transition-colors text-white rounded text-center font-semibold py-3 mt-auto",
+                disabled: is_disabled,
+                onclick: move |_| {
+                    is_busy.set(true);
+                    err_msg.set(None);
+                    spawn(async move {
+                        async_std::task::sleep(std::time::Duration::from_millis(50)).await;
+                        match keystore::create_backup(&passphrase_input.read()) {
+                            Ok(contents) => {
+                                utils::download_file(BACKUP_FILENAME, &contents, "application/json");
+                                step.set(BackupStep::Verify);
+                            }
+                            Err(err) => {
+                                log::error!("Failed to create backup: {:?}", err);
+                                err_msg.set(Some("Failed to create backup".to_string()));
+                            }
+                        }
+                        is_busy.set(false);
+                    });
+                },
+                if *is_busy.read() {
+                    Spinner {
+                        class: "mx-auto"
+                    }
+                } else {
+                    "Download backup"
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use dioxus::prelude::*;
+
+use crate::{components::CheckCircleIcon, route::Route};
+
+pub fn BackupDone() -> Element {
+    rsx! {
+        div {
+            class: "flex flex-col grow justify-between",
+            div {
+                class: "flex flex-col gap-2",
+                h2 {
+                    "Backup verified"
+                }
+                p {
+                    class: "text-lg",
+                    "You can restore your accounts from the backup file on any device."
+                }
+            }
+            div {
+                class: "flex flex-col gap-8 w-full",
+                CheckCircleIcon { class: "h-12 w-12 mx-auto" }
+            }
+            Link {
+                class: "w-full py-3 rounded font-semibold transition-colors text-center text-white bg-green-500 hover:bg-green-600 active:bg-green-700",
+                to: Route::Settings {},
+                "Done"
+            }
+        }
+    }
+}
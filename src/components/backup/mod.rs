@@ -0,0 +1,41 @@
+mod create;
+mod done;
+mod restore;
+mod verify;
+
+pub use create::*;
+pub use done::*;
+pub use restore::*;
+pub use verify::*;
+
+use dioxus::prelude::*;
+
+pub enum BackupStep {
+    Create,
+    Verify,
+    Done,
+}
+
+pub fn Backup() -> Element {
+    let step = use_signal(|| BackupStep::Create);
+
+    let e = match *step.read() {
+        BackupStep::Create => {
+            rsx! {
+                BackupCreate { step }
+            }
+        }
+        BackupStep::Verify => {
+            rsx! {
+                BackupVerify { step }
+            }
+        }
+        BackupStep::Done => {
+            rsx! {
+                BackupDone {}
+            }
+        }
+    };
+
+    e
+}
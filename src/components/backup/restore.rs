@@ -0,0 +1,138 @@
+use dioxus::prelude::*;
+use web_sys::window;
+
+use crate::{
+    components::Spinner,
+    keystore::{self, BackupPayload, KeystoreError},
+};
+
+/// File picker and passphrase field for opening a backup file.
+#[component]
+pub fn BackupFileFields(contents: Signal<Option<String>>, passphrase: Signal<String>) -> Element {
+    let mut filename = use_signal::<Option<String>>(|| None);
+
+    rsx! {
+        label {
+            class: "mx-auto text-sm font-semibold px-4 py-1 rounded hover-100 active-200 transition-colors hover:cursor-pointer",
+            if let Some(filename) = filename.read().clone() {
+                "{filename}"
+            } else {
+                "Choose backup file"
+            }
+            input {
+                class: "hidden",
+                r#type: "file",
+                accept: ".json,application/json",
+                onchange: move |e| async move {
+                    if let Some(file_engine) = e.files() {
+                        if let Some(name) = file_engine.files().first() {
+                            contents.set(file_engine.read_file_to_string(name).await);
+                            filename.set(Some(name.clone()));
+                        }
+                    }
+                },
+            }
+        }
+        input {
+            class: "mx-auto w-full py-2 text-center placeholder-gray-200 dark:placeholder-gray-700 bg-transparent",
+            r#type: "password",
+            placeholder: "Backup passphrase",
+            value: "{passphrase}",
+            oninput: move |e| passphrase.set(e.value()),
+        }
+    }
+}
+
+/// Restores accounts and settings from a backup file. Shown in the import page.
+pub fn RestoreBackup() -> Element {
+    let contents = use_signal::<Option<String>>(|| None);
+    let passphrase = use_signal(|| "".to_string());
+    let mut payload = use_signal::<Option<BackupPayload>>(|| None);
+    let mut err_msg = use_signal::<Option<String>>(|| None);
+    let mut is_busy = use_signal(|| false);
+
+    // Require opening the file again if the inputs change
+    use_effect(move || {
+        let _ = contents.read();
+        let _ = passphrase.read();
+        payload.set(None);
+    });
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-4",
+            BackupFileFields { contents, passphrase }
+            if let Some(err_msg) = err_msg.read().clone() {
+                p {
+                    class: "text-red-500 text-sm text-center",
+                    "{err_msg}"
+                }
+            }
+        }
+        if let Some(payload) = payload.read().clone() {
+            div {
+                class: "flex flex-col gap-1",
+                for profile in payload.profiles.iter() {
+                    div {
+                        class: "flex flex-row gap-4 justify-between w-full px-3 py-2",
+                        p {
+                            class: "font-medium",
+                            "{profile.name}"
+                        }
+                        p {
+                            class: "font-mono text-sm opacity-50 truncate my-auto",
+                            "{profile.pubkey().map(|p| p.to_string()).unwrap_or_default()}"
+                        }
+                    }
+                }
+            }
+        }
+        button {
+            class: "bg-green-500 disabled:opacity-50 hover:bg-green-600 active:bg-green-700 transition-colors text-white rounded text-center font-semibold py-3 mt-auto",
+            disabled: *is_busy.read() || contents.read().is_none() || passphrase.read().is_empty(),
+            onclick: move |_| {
+                is_busy.set(true);
+                err_msg.set(None);
+                spawn(async move {
+                    async_std::task::sleep(std::time::Duration::from_millis(50)).await;
+                    let opened = payload.read().clone();
+                    match opened {
+                        // Second click restores the previewed backup
+                        Some(opened) => match keystore::restore_backup(&opened) {
+                            Ok(_) => {
+                                // Reload so restored settings take effect
+                                window().expect("should have a window").location().reload().ok();
+                            }
+                            Err(err) => {
+                                log::error!("Failed to restore backup: {:?}", err);
+                                err_msg.set(Some("Failed to restore backup".to_string()));
+                            }
+                        },
+                        None => {
+                            let file = contents.read().clone().unwrap_or_default();
+                            match keystore::open_backup(&file, &passphrase.read()) {
+                                Ok(opened) => payload.set(Some(opened)),
+                                Err(KeystoreError::InvalidPassphrase) => {
+                                    err_msg.set(Some("Incorrect passphrase".to_string()));
+                                }
+                                Err(_) => {
+                                    err_msg.set(Some("Not a valid backup file".to_string()));
+                                }
+                            }
+                        }
+                    }
+                    is_busy.set(false);
+                });
+            },
+            if *is_busy.read() {
+                Spinner {
+                    class: "mx-auto"
+                }
+            } else if payload.read().is_some() {
+                "Restore"
+            } else {
+                "Open backup"
+            }
+        }
+    }
+}
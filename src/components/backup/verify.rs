@@ -0,0 +1,171 @@
+use dioxus::prelude::*;
+use rand::Rng;
+
+use crate::{
+    components::Spinner,
+    hooks::{use_keypair, use_pubkey, use_show_backup_warning, ShowBackupWarning},
+    keystore::{self, KeystoreError},
+};
+
+use super::{BackupFileFields, BackupStep};
+
+/// Number of private key characters asked for when verifying from memory or paper.
+const CHALLENGE_LENGTH: usize = 8;
+
+#[derive(Clone, Copy, PartialEq)]
+enum VerifyMode {
+    PrivateKey,
+    BackupFile,
+}
+
+#[component]
+pub fn BackupVerify(step: Signal<BackupStep>) -> Element {
+    let mut mode = use_signal(|| VerifyMode::BackupFile);
+    let tab_class = |m: VerifyMode| {
+        if mode.read().eq(&m) {
+            "font-semibold px-4 py-1 rounded bg-gray-100 dark:bg-gray-900"
+        } else {
+            "font-semibold px-4 py-1 rounded opacity-50 hover-100 active-200 transition-colors"
+        }
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-16 grow w-full h-full",
+            div {
+                class: "flex flex-col gap-3",
+                h2 {
+                    "Verify backup"
+                }
+                p {
+                    class: "text-lg",
+                    "Prove your backup works before the reminder goes away."
+                }
+            }
+            div {
+                class: "flex flex-row gap-2 mx-auto",
+                button {
+                    class: tab_class(VerifyMode::BackupFile),
+                    onclick: move |_| mode.set(VerifyMode::BackupFile),
+                    "Backup file"
+                }
+                button {
+                    class: tab_class(VerifyMode::PrivateKey),
+                    onclick: move |_| mode.set(VerifyMode::PrivateKey),
+                    "Private key"
+                }
+            }
+            match *mode.read() {
+                VerifyMode::BackupFile => rsx! { VerifyBackupFile { step } },
+                VerifyMode::PrivateKey => rsx! { VerifyPrivateKey { step } },
+            }
+        }
+    }
+}
+
+#[component]
+fn VerifyBackupFile(step: Signal<BackupStep>) -> Element {
+    let mut show_backup_warning = use_show_backup_warning();
+    let pubkey = use_pubkey();
+    let contents = use_signal::<Option<String>>(|| None);
+    let passphrase = use_signal(|| "".to_string());
+    let mut err_msg = use_signal::<Option<String>>(|| None);
+    let mut is_busy = use_signal(|| false);
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-4",
+            BackupFileFields { contents, passphrase }
+            if let Some(err_msg) = err_msg.read().clone() {
+                p {
+                    class: "text-red-500 text-sm text-center",
+                    "{err_msg}"
+                }
+            }
+        }
+        button {
+            class: "bg-green-500 disabled:opacity-50 hover:bg-green-600 active:bg-green-700 transition-colors text-white rounded text-center font-semibold py-3 mt-auto",
+            disabled: *is_busy.read() || contents.read().is_none() || passphrase.read().is_empty(),
+            onclick: move |_| {
+                is_busy.set(true);
+                err_msg.set(None);
+                spawn(async move {
+                    async_std::task::sleep(std::time::Duration::from_millis(50)).await;
+                    let file = contents.read().clone().unwrap_or_default();
+                    match keystore::open_backup(&file, &passphrase.read()) {
+                        Ok(payload) if payload.contains(&pubkey) => {
+                            show_backup_warning.set(ShowBackupWarning(false));
+                            step.set(BackupStep::Done);
+                        }
+                        Ok(_) => {
+                            err_msg.set(Some("This backup does not contain the current account".to_string()));
+                        }
+                        Err(KeystoreError::InvalidPassphrase) => {
+                            err_msg.set(Some("Incorrect passphrase".to_string()));
+                        }
+                        Err(_) => {
+                            err_msg.set(Some("Not a valid backup file".to_string()));
+                        }
+                    }
+                    is_busy.set(false);
+                });
+            },
+            if *is_busy.read() {
+                Spinner {
+                    class: "mx-auto"
+                }
+            } else {
+                "Verify"
+            }
+        }
+    }
+}
+
+#[component]
+fn VerifyPrivateKey(step: Signal<BackupStep>) -> Element {
+    let mut show_backup_warning = use_show_backup_warning();
+    let secret = use_keypair().to_base58_string();
+    let secret_len = secret.len();
+    let start = use_signal(move || {
+        rand::thread_rng().gen_range(0..=secret_len.saturating_sub(CHALLENGE_LENGTH))
+    });
+    let mut input = use_signal(|| "".to_string());
+    let start = *start.read();
+    let expected = secret
+        .get(start..start + CHALLENGE_LENGTH)
+        .unwrap_or_default()
+        .to_string();
+    let is_wrong = input.read().len().ge(&CHALLENGE_LENGTH) && input.read().trim().ne(&expected);
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-4",
+            p {
+                class: "text-center",
+                "Enter characters {start + 1} to {start + CHALLENGE_LENGTH} of your private key."
+            }
+            input {
+                class: "mx-auto w-full py-2 text-center font-mono placeholder-gray-200 dark:placeholder-gray-700 bg-transparent",
+                autofocus: true,
+                placeholder: "{CHALLENGE_LENGTH} characters",
+                value: "{input}",
+                oninput: move |e| input.set(e.value()),
+            }
+            if is_wrong {
+                p {
+                    class: "text-red-500 text-sm text-center",
+                    "Those characters do not match"
+                }
+            }
+        }
+        button {
+            class: "bg-green-500 disabled:opacity-50 hover:bg-green-600 active:bg-green-700 transition-colors text-white rounded text-center font-semibold py-3 mt-auto",
+            disabled: input.read().trim().ne(&expected),
+            onclick: move |_| {
+                show_backup_warning.set(ShowBackupWarning(false));
+                step.set(BackupStep::Done);
+            },
+            "Verify"
+        }
+    }
+}
@@ -1,12 +1,8 @@
 use dioxus::prelude::*;
 
-use crate::{
-    hooks::{use_show_backup_warning, ShowBackupWarning},
-    route::Route,
-};
+use crate::route::Route;
 
 pub fn BackupKeypairWarning() -> Element {
-    let mut show_backup_warning = use_show_backup_warning();
     rsx! {
         div {
             class: "flex flex-col gap-3 bg-orange-500 w-full rounded px-4 py-5 text-white",
@@ -24,24 +20,19 @@ pub fn BackupKeypairWarning() -> Element {
                 }
                 li {
                     Link {
-                        to: Route::ExportKey {},
+                        to: Route::Backup {},
                         class: "font-bold text-blue-300 hover:underline",
-                        "ACTION: Export My Keypair"
+                        "ACTION: Back Up My Keypair"
                     }
-                    " and back it up somewhere safe."
+                    " and verify the backup. This reminder goes away once the backup is verified."
                 }
             }
             div {
                 class: "flex flex-row justify-end",
-                button {
-                    onclick: move |_| {
-                        show_backup_warning.set(ShowBackupWarning(false));
-                    },
+                Link {
+                    to: Route::Backup {},
                     class: "flex flex-row gap-2 hover:bg-orange-600 active:bg-orange-700 text-white px-3 py-2 rounded font-semibold transition-colors",
-                    // EyeSlashIcon {
-                    //     class: "w-4 h-4 my-auto"
-                    // }
-                    "I have backed up my keypair"
+                    "Back up and verify"
                 }
             }
         }
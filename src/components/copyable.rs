@@ -3,16 +3,43 @@ use dioxus::prelude::*;
 
 use crate::components::CopyIcon;
 
+/// Seconds a copied secret stays on the clipboard.
+const SECRET_CLIPBOARD_TTL: u64 = 15;
+
 #[component]
 pub fn Copyable(
     class: Option<String>,
     implicit: Option<bool>,
+    secret: Option<bool>,
     value: String,
     children: Element,
 ) -> Element {
     let mut solid = use_signal(|| false);
     let window = web_sys::window().expect("window");
     let clipboard = window.navigator().clipboard();
+    let secret = secret.unwrap_or(false);
+
+    let mut copy = move |value: &str| {
+        if let Some(clipboard) = clipboard.clone() {
+            let _ = clipboard.write_text(value);
+            solid.set(true);
+            if secret {
+                let value = value.to_string();
+                // Outlive this component so navigating away does not leave the secret behind
+                spawn_forever(async move {
+                    async_std::task::sleep(std::time::Duration::from_secs(SECRET_CLIPBOARD_TTL)).await;
+                    // Leave the clipboard alone if the user has since copied something else
+                    let current = wasm_bindgen_futures::JsFuture::from(clipboard.read_text())
+                        .await
+                        .ok()
+                        .and_then(|v| v.as_string());
+                    if current.map_or(true, |current| current.eq(&value)) {
+                        let _ = clipboard.write_text("");
+                    }
+                });
+            }
+        }
+    };
 
     let _ = use_resource(move || async move {
         if *solid.read() {
@@ -27,12 +54,7 @@ pub fn Copyable(
         if implicit.unwrap_or(false) {
             button {
                 class: "flex max-w-full shrink-0 p-2 rounded transition-colors hover-100 active-200 {class}",
-                onclick: move |_e| {
-                    if let Some(clipboard) = clipboard.clone() {
-                        let _ = clipboard.write_text(value.as_str());
-                        solid.set(true);
-                    }
-                },
+                onclick: move |_e| copy(value.as_str()),
                 {children}
             }
         } else {
@@ -40,12 +62,7 @@ pub fn Copyable(
                 class: "flex flex-row gap-1 justify-end max-w-full {class}",
                 button {
                     class: "flex shrink-0 p-2 rounded transition-colors hover-100 active-200",
-                    onclick: move |_e| {
-                        if let Some(clipboard) = clipboard.clone() {
-                            let _ = clipboard.write_text(value.as_str());
-                            solid.set(true);
-                        }
-                    },
+                    onclick: move |_e| copy(value.as_str()),
                     CopyIcon {
                         class: "w-4 h-4 my-auto",
                         solid: *solid.read(),
@@ -0,0 +1,340 @@
+use std::str::FromStr;
+
+use dioxus::prelude::*;
+use ore_api::consts::TOKEN_DECIMALS;
+use solana_client_wasm::solana_sdk::{native_token::lamports_to_sol, pubkey::Pubkey};
+use solana_extra_wasm::program::spl_token::amount_to_ui_amount;
+
+use crate::{
+    hooks::{use_watchlist, use_watchlist_stats, WatchedAccount, WatchedStats},
+    route::Route,
+};
+
+const STALE_MINUTES_OPTIONS: [u64; 5] = [2, 5, 10, 30, 60];
+
+pub fn Dashboard() -> Element {
+    let mut watchlist = use_watchlist();
+    let mut stats = use_watchlist_stats();
+    let all_stats = stats.read().clone();
+    let stale_minutes = watchlist.read().stale_minutes;
+    let groups = watchlist.read().groups();
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-16 w-full pb-24",
+            div {
+                class: "flex flex-col gap-4",
+                div {
+                    class: "flex flex-row justify-between",
+                    h2 {
+                        "Dashboard"
+                    }
+                    button {
+                        class: "font-semibold hover-100 active-200 transition-colors px-4 py-1 rounded my-auto",
+                        onclick: move |_| stats.restart(),
+                        "Refresh"
+                    }
+                }
+                p {
+                    class: "text-lg",
+                    "Watch miner addresses without importing their keys."
+                }
+                div {
+                    class: "flex flex-row gap-8 justify-between w-full sm:px-1",
+                    p {
+                        class: "font-medium text-sm opacity-50 my-auto",
+                        "Highlight stale after"
+                    }
+                    select {
+                        class: "text-right bg-transparent dark:text-white hover:cursor-pointer py-1",
+                        onchange: move |e| {
+                            if let Ok(minutes) = e.value().parse::<u64>() {
+                                watchlist.write().stale_minutes = minutes;
+                            }
+                        },
+                        for minutes in STALE_MINUTES_OPTIONS {
+                            option {
+                                initial_selected: stale_minutes.eq(&minutes),
+                                value: "{minutes}",
+                                "{minutes} minutes"
+                            }
+                        }
+                    }
+                }
+            }
+            AddWatchedAccount {}
+            if watchlist.read().accounts.is_empty() {
+                p {
+                    class: "text-sm text-gray-300 dark:text-gray-700 text-center",
+                    "No watched accounts yet."
+                }
+            }
+            for group in groups {
+                WatchGroup {
+                    key: "{group}",
+                    group: group.clone(),
+                    stats: all_stats.clone(),
+                }
+            }
+        }
+    }
+}
+
+fn AddWatchedAccount() -> Element {
+    let mut watchlist = use_watchlist();
+    let mut pubkey_input = use_signal(|| "".to_string());
+    let mut label_input = use_signal(|| "".to_string());
+    let mut group_input = use_signal(|| "".to_string());
+    let pubkey = Pubkey::from_str(pubkey_input.read().trim()).ok();
+    let is_duplicate = pubkey.map_or(false, |pubkey| watchlist.read().contains(&pubkey));
+    let error_text = if pubkey_input.read().is_empty() {
+        None
+    } else if pubkey.is_none() {
+        Some("Invalid address")
+    } else if is_duplicate {
+        Some("Already watching this address")
+    } else {
+        None
+    };
+    let input_class = "w-full py-2 px-2 placeholder-gray-200 dark:placeholder-gray-700 bg-transparent rounded bg-gray-100 dark:bg-gray-900";
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2",
+            div {
+                class: "flex flex-col sm:flex-row gap-2",
+                input {
+                    class: "{input_class} font-mono sm:grow",
+                    placeholder: "Address",
+                    value: "{pubkey_input}",
+                    oninput: move |e| pubkey_input.set(e.value()),
+                }
+                input {
+                    class: "{input_class} sm:w-40",
+                    placeholder: "Label",
+                    value: "{label_input}",
+                    oninput: move |e| label_input.set(e.value()),
+                }
+                input {
+                    class: "{input_class} sm:w-40",
+                    placeholder: "Group",
+                    list: "watch-groups",
+                    value: "{group_input}",
+                    oninput: move |e| group_input.set(e.value()),
+                }
+                datalist {
+                    id: "watch-groups",
+                    for group in watchlist.read().groups() {
+                        option { value: "{group}" }
+                    }
+                }
+                button {
+                    class: "bg-green-500 disabled:opacity-50 hover:bg-green-600 active:bg-green-700 transition-colors text-white rounded text-center font-semibold px-6 py-2",
+                    disabled: pubkey.is_none() || is_duplicate,
+                    onclick: move |_| {
+                        if let Some(pubkey) = pubkey {
+                            let label = label_input.read().trim().to_string();
+                            watchlist.write().accounts.push(WatchedAccount {
+                                pubkey,
+                                label: if label.is_empty() { pubkey.to_string() } else { label },
+                                group: group_input.read().trim().to_string(),
+                            });
+                            pubkey_input.set("".to_string());
+                            label_input.set("".to_string());
+                        }
+                    },
+                    "Watch"
+                }
+            }
+            if let Some(error_text) = error_text {
+                p {
+                    class: "text-red-500 text-sm",
+                    "{error_text}"
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn WatchGroup(group: String, stats: Option<Vec<WatchedStats>>) -> Element {
+    let mut watchlist = use_watchlist();
+    let stale_minutes = watchlist.read().stale_minutes;
+    let accounts: Vec<WatchedAccount> = watchlist
+        .read()
+        .accounts
+        .iter()
+        .filter(|a| a.group.eq(&group))
+        .cloned()
+        .collect();
+    let stats = stats.unwrap_or_default();
+    let find_stats = |pubkey: &Pubkey| stats.iter().find(|s| s.pubkey.eq(pubkey)).cloned();
+
+    // Group totals
+    let group_stats: Vec<WatchedStats> = accounts.iter().filter_map(|a| find_stats(&a.pubkey)).collect();
+    let total_ore: u64 = group_stats.iter().filter_map(|s| s.ore_balance).sum();
+    let total_sol: u64 = group_stats.iter().filter_map(|s| s.sol_balance).sum();
+    let total_stake: u64 = group_stats.iter().filter_map(|s| s.proof.map(|p| p.balance)).sum();
+    let stale_count = group_stats
+        .iter()
+        .filter(|s| is_stale(s, stale_minutes))
+        .count();
+
+    let header_class = "text-left text-sm font-medium opacity-50 px-2 py-1";
+    let cell_class = "px-2 py-2 text-nowrap";
+    let title = if group.is_empty() { "Ungrouped".to_string() } else { group.clone() };
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-4",
+            div {
+                class: "flex flex-row justify-between",
+                h2 {
+                    class: "text-lg md:text-2xl font-bold",
+                    "{title}"
+                }
+                if stale_count.gt(&0) {
+                    p {
+                        class: "my-auto text-sm font-semibold text-red-500",
+                        "{stale_count} stale"
+                    }
+                }
+            }
+            div {
+                class: "overflow-x-auto",
+                table {
+                    class: "w-full",
+                    thead {
+                        tr {
+                            th { class: "{header_class}", "Miner" }
+                            th { class: "{header_class} text-right", "ORE" }
+                            th { class: "{header_class} text-right", "SOL" }
+                            th { class: "{header_class} text-right", "Stake" }
+                            th { class: "{header_class} text-right", "Multiplier" }
+                            th { class: "{header_class} text-right", "Last hash" }
+                            th { class: "{header_class}" }
+                        }
+                    }
+                    tbody {
+                        for account in accounts {
+                            WatchRow {
+                                key: "{account.pubkey}",
+                                account: account.clone(),
+                                stats: find_stats(&account.pubkey),
+                                stale_minutes,
+                                onremove: move |pubkey: Pubkey| {
+                                    watchlist.write().accounts.retain(|a| a.pubkey.ne(&pubkey));
+                                },
+                            }
+                        }
+                        tr {
+                            class: "font-semibold border-t border-gray-100 dark:border-gray-900",
+                            td { class: "{cell_class}", "Total" }
+                            td { class: "{cell_class} text-right", "{format_ore(total_ore)}" }
+                            td { class: "{cell_class} text-right", "{format_sol(total_sol)}" }
+                            td { class: "{cell_class} text-right", "{format_ore(total_stake)}" }
+                            td { class: "{cell_class}" }
+                            td { class: "{cell_class}" }
+                            td { class: "{cell_class}" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn WatchRow(
+    account: WatchedAccount,
+    stats: Option<WatchedStats>,
+    stale_minutes: u64,
+    onremove: EventHandler<Pubkey>,
+) -> Element {
+    let cell_class = "px-2 py-2 text-nowrap";
+    let is_stale = stats.as_ref().map_or(false, |s| is_stale(s, stale_minutes));
+    let row_class = if is_stale { "text-red-500" } else { "" };
+    let pubkey = account.pubkey;
+
+    let Some(stats) = stats else {
+        return rsx! {
+            tr {
+                td { class: "{cell_class}", "{account.label}" }
+                td {
+                    colspan: 6,
+                    div { class: "h-6 w-full loading rounded" }
+                }
+            }
+        };
+    };
+
+    let ore_balance = stats.ore_balance.map(format_ore).unwrap_or("–".to_string());
+    let sol_balance = stats.sol_balance.map(format_sol).unwrap_or("–".to_string());
+    let stake = stats
+        .proof
+        .map(|p| format_ore(p.balance))
+        .unwrap_or("–".to_string());
+    let multiplier = stats
+        .multiplier
+        .map(|m| format!("{:.4}x", m))
+        .unwrap_or("–".to_string());
+    let last_hash = match (stats.proof, stats.last_hash_age) {
+        (None, _) => "No proof".to_string(),
+        (Some(_), Some(age)) => format_age(age),
+        (Some(_), None) => "–".to_string(),
+    };
+
+    rsx! {
+        tr {
+            class: "{row_class}",
+            td {
+                class: "{cell_class} max-w-48 truncate",
+                Link {
+                    class: "font-medium hover-100 active-200 transition-colors rounded px-1 -ml-1",
+                    to: Route::User { id: pubkey.to_string() },
+                    "{account.label}"
+                }
+            }
+            td { class: "{cell_class} text-right", "{ore_balance}" }
+            td { class: "{cell_class} text-right", "{sol_balance}" }
+            td { class: "{cell_class} text-right", "{stake}" }
+            td { class: "{cell_class} text-right", "{multiplier}" }
+            td { class: "{cell_class} text-right", "{last_hash}" }
+            td {
+                class: "{cell_class} text-right",
+                button {
+                    class: "text-sm font-semibold opacity-50 hover:opacity-100 transition-opacity px-2",
+                    title: "Stop watching",
+                    onclick: move |_| onremove.call(pubkey),
+                    "Remove"
+                }
+            }
+        }
+    }
+}
+
+fn is_stale(stats: &WatchedStats, stale_minutes: u64) -> bool {
+    stats
+        .last_hash_age
+        .map_or(false, |age| age.gt(&(stale_minutes as i64 * 60)))
+}
+
+fn format_ore(amount: u64) -> String {
+    format!("{:.4}", amount_to_ui_amount(amount, TOKEN_DECIMALS))
+}
+
+fn format_sol(lamports: u64) -> String {
+    format!("{:.4}", lamports_to_sol(lamports))
+}
+
+fn format_age(seconds: i64) -> String {
+    if seconds.lt(&60) {
+        format!("{}s ago", seconds.max(0))
+    } else if seconds.lt(&3600) {
+        format!("{}m ago", seconds / 60)
+    } else if seconds.lt(&86400) {
+        format!("{}h ago", seconds / 3600)
+    } else {
+        format!("{}d ago", seconds / 86400)
+    }
+}
@@ -1,7 +1,16 @@
 #![allow(non_snake_case)]
 use dioxus::prelude::*;
 
-use crate::components::CodeBlock;
+use crate::{
+    components::CodeBlock,
+    hooks::{
+        use_fee_url, use_power_level, use_priority_fee, use_priority_fee_cap,
+        use_priority_fee_strategy, use_rpc_url, PriorityFeeStrategy,
+    },
+};
+
+/// Path `solana-keygen` writes the default keypair to.
+const CLI_KEYPAIR_PATH: &str = "~/.config/solana/id.json";
 
 pub fn Download() -> Element {
     rsx! {
@@ -50,7 +59,49 @@ pub fn Download() -> Element {
                 CodeBlock {
                     text: "ore -h"
                 }
+                ServerCommand {}
             }
         }
     }
 }
+
+fn ServerCommand() -> Element {
+    let rpc_url = use_rpc_url();
+    let fee_url = use_fee_url();
+    let power_level = use_power_level();
+    let priority_fee = use_priority_fee();
+    let priority_fee_cap = use_priority_fee_cap();
+    let priority_fee_strategy = use_priority_fee_strategy();
+
+    let fee_args = match *priority_fee_strategy.read() {
+        PriorityFeeStrategy::Estimate => format!(
+            "--dynamic-fee --dynamic-fee-url {} --dynamic-fee-max {}",
+            fee_url.read().0,
+            priority_fee_cap.read().0
+        ),
+        PriorityFeeStrategy::Static => format!("--priority-fee {}", priority_fee.read().0),
+    };
+    let command = format!(
+        "ore --rpc {} --keypair {} {} mine --cores {}",
+        rpc_url.read().0,
+        CLI_KEYPAIR_PATH,
+        fee_args,
+        power_level.read().0
+    );
+
+    rsx! {
+        p {
+            class: "text-lg sm:text-xl md:text-2xl lg:text-3xl font-bold mt-8 font-hero",
+            "Run this on a server"
+        }
+        p {
+            "To mine from a server with the same settings as this browser, export your keypair as id.json from the settings page and copy it to "
+            code { "{CLI_KEYPAIR_PATH}" }
+            ". Then run the command below. "
+            "Adjust the number of cores to the server's hardware."
+        }
+        CodeBlock {
+            text: command
+        }
+    }
+}
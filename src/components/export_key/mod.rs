@@ -1,7 +1,9 @@
 mod secret;
+mod transfer;
 mod warning;
 
 pub use secret::*;
+pub use transfer::*;
 pub use warning::*;
 
 use dioxus::prelude::*;
@@ -9,6 +11,7 @@ use dioxus::prelude::*;
 pub enum ExportKeyStep {
     Warning,
     Secret,
+    Transfer,
 }
 
 pub fn ExportKey() -> Element {
@@ -22,7 +25,12 @@ pub fn ExportKey() -> Element {
         }
         ExportKeyStep::Secret => {
             rsx! {
-                ExportKeySecret {}
+                ExportKeySecret { step }
+            }
+        }
+        ExportKeyStep::Transfer => {
+            rsx! {
+                ExportKeyTransfer {}
             }
         }
     };
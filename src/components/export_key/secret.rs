@@ -3,11 +3,18 @@ use dioxus::prelude::*;
 use crate::{
     components::{Copyable, EyeSlashIcon},
     hooks::use_keypair,
+    keystore,
     route::Route,
+    utils,
 };
 
-pub fn ExportKeySecret() -> Element {
+use super::ExportKeyStep;
+
+#[component]
+pub fn ExportKeySecret(step: Signal<ExportKeyStep>) -> Element {
     let keypair = use_keypair();
+    let keypair_json = keystore::keypair_to_json(&keypair);
+    let mut is_revealed = use_signal(|| false);
     rsx! {
         div {
             class: "flex flex-col gap-16 grow w-full h-full",
@@ -25,11 +32,46 @@ pub fn ExportKeySecret() -> Element {
             EyeSlashIcon {
                 class: "w-12 h-12 mx-auto opacity-50"
             }
-            Copyable {
-                class: "mx-auto break-all text-wrap text-center",
-                value: keypair.to_base58_string(),
-                implicit: false,
-                "{keypair.to_base58_string()}"
+            div {
+                class: "flex flex-col gap-2",
+                Copyable {
+                    class: "mx-auto break-all text-wrap text-center",
+                    value: keypair.to_base58_string(),
+                    implicit: false,
+                    secret: true,
+                    if *is_revealed.read() {
+                        "{keypair.to_base58_string()}"
+                    } else {
+                        span {
+                            class: "my-auto font-mono opacity-50 select-none",
+                            "••••••••••••••••••••••••••••••••"
+                        }
+                    }
+                }
+                button {
+                    class: "mx-auto text-sm font-semibold hover-100 active-200 transition-colors px-4 py-1 rounded",
+                    onclick: move |_| {
+                        let revealed = *is_revealed.read();
+                        is_revealed.set(!revealed);
+                    },
+                    if *is_revealed.read() { "Hide" } else { "Reveal" }
+                }
+            }
+            button {
+                class: "mx-auto font-semibold hover-100 active-200 transition-colors px-4 py-1 rounded",
+                onclick: move |_| {
+                    utils::download_file("id.json", &keypair_json, "application/json");
+                },
+                "Download id.json"
+            }
+            p {
+                class: "text-sm text-center text-gray-300 dark:text-gray-700 -mt-12",
+                "Keypair file for the Solana CLI and ore-cli."
+            }
+            button {
+                class: "mx-auto font-semibold hover-100 active-200 transition-colors px-4 py-1 rounded -mt-8",
+                onclick: move |_| step.set(ExportKeyStep::Transfer),
+                "Transfer to another device with a QR code"
             }
             Link {
                 to: Route::Settings {},
@@ -0,0 +1,117 @@
+use dioxus::prelude::*;
+
+use crate::{
+    components::Spinner,
+    hooks::use_keypair,
+    keystore,
+    route::Route,
+};
+
+/// Time each frame of a multi-frame code stays on screen.
+const FRAME_INTERVAL_MS: u64 = 800;
+
+pub fn ExportKeyTransfer() -> Element {
+    let keypair = use_keypair();
+    let code = use_signal(keystore::generate_transfer_code);
+    let mut frames = use_signal::<Option<Vec<String>>>(|| None);
+    let mut frame_index = use_signal(|| 0usize);
+
+    // Derive the encryption key off the render path
+    use_hook(move || {
+        spawn(async move {
+            async_std::task::sleep(std::time::Duration::from_millis(50)).await;
+            match keystore::encode_transfer(&keypair, &code.read()) {
+                Ok(encoded) => frames.set(Some(encoded)),
+                Err(err) => log::error!("Failed to encode key transfer: {:?}", err),
+            }
+        });
+    });
+
+    // Cycle through frames when the payload does not fit in one code
+    use_future(move || async move {
+        loop {
+            async_std::task::sleep(std::time::Duration::from_millis(FRAME_INTERVAL_MS)).await;
+            let count = frames.peek().as_ref().map_or(0, |f| f.len());
+            if count.gt(&1) {
+                let next = (*frame_index.peek() + 1) % count;
+                frame_index.set(next);
+            }
+        }
+    });
+
+    let frame_count = frames.read().as_ref().map_or(0, |f| f.len());
+    let qrcode = frames.read().as_ref().and_then(|f| {
+        f.get(*frame_index.read()).map(|frame| {
+            qrcode_generator::to_svg_to_string(
+                frame,
+                qrcode_generator::QrCodeEcc::Medium,
+                256,
+                None::<&str>,
+            )
+            .unwrap()
+        })
+    });
+    let display_code = {
+        let code = code.read();
+        let (a, b) = code.split_at(code.len() / 2);
+        format!("{}-{}", a, b)
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-16 grow w-full h-full",
+            div {
+                class: "flex flex-col gap-3 justify-start",
+                h2 {
+                    "Transfer key"
+                }
+                p {
+                    class: "text-lg",
+                    "On the other device, go to Settings, Import, QR code. Scan or photograph this code and enter the one-time code below."
+                }
+                p {
+                    class: "text-sm text-gray-300 dark:text-gray-700",
+                    "The QR code is encrypted with the one-time code. Never show both to anyone else."
+                }
+            }
+            if let Some(qrcode) = qrcode {
+                div {
+                    class: "flex flex-col gap-4",
+                    div {
+                        class: "text-center w-64 h-64 bg-white mx-auto",
+                        dangerous_inner_html: "{qrcode}",
+                    }
+                    if frame_count.gt(&1) {
+                        p {
+                            class: "text-sm text-center opacity-50",
+                            "Frame {*frame_index.read() + 1} of {frame_count}"
+                        }
+                    }
+                }
+            } else {
+                div {
+                    class: "flex w-64 h-64 mx-auto loading rounded",
+                    Spinner {
+                        class: "m-auto"
+                    }
+                }
+            }
+            div {
+                class: "flex flex-col gap-1",
+                p {
+                    class: "text-sm text-center opacity-50",
+                    "One-time code"
+                }
+                p {
+                    class: "text-3xl text-center font-mono font-bold tracking-widest",
+                    "{display_code}"
+                }
+            }
+            Link {
+                to: Route::Settings {},
+                class: "bg-green-500 hover:bg-green-600 active:bg-green-700 transition-colors text-white rounded text-center font-semibold py-3 mt-auto",
+                "Done"
+            }
+        }
+    }
+}
@@ -1,11 +1,19 @@
 use dioxus::prelude::*;
 
-use crate::components::{FlagIcon, LockOpenIcon};
+use crate::{
+    components::{FlagIcon, LockOpenIcon, Spinner},
+    keystore::{self, KeystoreError},
+};
 
 use super::ExportKeyStep;
 
 #[component]
 pub fn ExportKeyWarning(step: Signal<ExportKeyStep>) -> Element {
+    let is_encrypted = use_signal(keystore::is_encrypted);
+    let mut passphrase = use_signal(|| "".to_string());
+    let mut err_msg = use_signal::<Option<String>>(|| None);
+    let mut is_busy = use_signal(|| false);
+
     rsx! {
         div {
             class: "flex flex-col gap-16 grow w-full h-full",
@@ -39,13 +47,57 @@ pub fn ExportKeyWarning(step: Signal<ExportKeyStep>) -> Element {
                     }
                 }
             }
+            if *is_encrypted.read() {
+                div {
+                    class: "flex flex-col gap-2 mt-auto",
+                    input {
+                        class: "mx-auto w-full py-2 text-center placeholder-gray-200 dark:placeholder-gray-700 bg-transparent",
+                        r#type: "password",
+                        placeholder: "Passphrase",
+                        value: "{passphrase}",
+                        oninput: move |e| passphrase.set(e.value()),
+                    }
+                    if let Some(err_msg) = err_msg.read().clone() {
+                        p {
+                            class: "text-red-500 text-sm text-center",
+                            "{err_msg}"
+                        }
+                    }
+                }
+            }
             button {
+                disabled: *is_busy.read() || (*is_encrypted.read() && passphrase.read().is_empty()),
                 onclick: move |_| {
                     let mut step = step.clone();
-                    step.set(ExportKeyStep::Secret)
+                    if !*is_encrypted.read() {
+                        step.set(ExportKeyStep::Secret);
+                        return;
+                    }
+                    is_busy.set(true);
+                    err_msg.set(None);
+                    spawn(async move {
+                        async_std::task::sleep(std::time::Duration::from_millis(50)).await;
+                        match keystore::verify_passphrase(&passphrase.read()) {
+                            Ok(()) => step.set(ExportKeyStep::Secret),
+                            Err(KeystoreError::InvalidPassphrase) => {
+                                err_msg.set(Some("Incorrect passphrase".to_string()));
+                            }
+                            Err(err) => {
+                                log::error!("Failed to verify passphrase: {:?}", err);
+                                err_msg.set(Some("Failed to read keypair from storage".to_string()));
+                            }
+                        }
+                        is_busy.set(false);
+                    });
                 },
-                class: "bg-green-500 hover:bg-green-600 active:bg-green-700 transition-colors text-white rounded text-center font-semibold py-3 mt-auto",
-                "I understand. Continue"
+                class: "bg-green-500 disabled:opacity-50 hover:bg-green-600 active:bg-green-700 transition-colors text-white rounded text-center font-semibold py-3 mt-auto",
+                if *is_busy.read() {
+                    Spinner {
+                        class: "mx-auto"
+                    }
+                } else {
+                    "I understand. Continue"
+                }
             }
         }
     }
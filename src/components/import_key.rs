@@ -1,99 +1,19 @@
 use dioxus::prelude::*;
-use solana_client_wasm::solana_sdk::{
-    bs58, native_token::lamports_to_sol, signature::Keypair, signer::Signer,
-};
+use solana_client_wasm::solana_sdk::{native_token::lamports_to_sol, signer::Signer};
 
 use crate::{
-    components::EyeSlashIcon,
-    hooks::{use_gateway, use_keypair_persistent, use_sol_balance},
+    components::{EyeSlashIcon, ImportMnemonic, ImportQr, RestoreBackup},
+    hooks::{use_gateway, use_session, SessionStatus},
+    keystore,
     route::Route,
 };
 
-#[derive(Copy, Clone)]
-pub enum ImportKeyStep {
-    Loading,
-    Warning,
-    Import,
-}
-
-pub fn ImportKey() -> Element {
-    let mut step = use_signal(|| ImportKeyStep::Loading);
-    let sol_balance = use_sol_balance();
-    // TODO investigate use_effect
-    // MI: this stmt is to avoid defect of next use_effect of dioxus-web
-    // if comment out this stmt, use_effect cannot handle sol_balance correctly
-    if let Some(Ok(bal)) = *sol_balance.read() {
-        log::info!("sol_balance: {}", bal);
-    } else {
-        log::info!("error occurred executing sol_balance = use_sol_balance()");
-    };
-
-    use_effect(move || {
-        let current_step = *step.read();
-        if let ImportKeyStep::Loading = current_step {
-            if let Some(Ok(sol_balance)) = *sol_balance.read() {
-                if sol_balance.gt(&0) {
-                    step.set(ImportKeyStep::Warning)
-                } else {
-                    step.set(ImportKeyStep::Import)
-                }
-            }
-        }
-    });
-
-    let e = match *step.read() {
-        ImportKeyStep::Loading => {
-            rsx! {
-                ImportKeyLoading {}
-            }
-        }
-        ImportKeyStep::Warning => {
-            if let Some(Ok(balance)) = *sol_balance.read() {
-                rsx! {
-                    ImportKeyWarning { step, balance }
-                }
-            } else {
-                // TODO This should never happen. Display error
-                rsx! {}
-            }
-        }
-        ImportKeyStep::Import => {
-            rsx! {
-                ImportKeyImport {}
-            }
-        }
-    };
-
-    e
-}
-
-fn ImportKeyLoading() -> Element {
-    rsx! {
-        div {
-            class: "flex flex-row h-64 w-full loading rounded",
-        }
-    }
-}
-
-#[component]
-fn ImportKeyWarning(step: Signal<ImportKeyStep>, balance: u64) -> Element {
-    rsx! {
-        div {
-            class: "flex flex-col gap-16 grow w-full h-full",
-            ImportKeyHeader {}
-            LossOfFundsWarning {
-                balance
-            }
-            button {
-                onclick: move |_| {
-                    log::info!("Handlerr....");
-                    step.set(ImportKeyStep::Import)
-                },
-                class: "text-red-500 hover:bg-red-500 active:bg-red-600 hover:text-white mt-auto py-3 w-full rounded text-center font-semibold transition-colors",
-                "I understand I will lose access to my funds if I have not backed up my keypair"
-            }
-        }
-    }
+#[derive(Copy, Clone, PartialEq)]
+pub enum ImportKeyMode {
+    PrivateKey,
+    Mnemonic,
+    Backup,
+    Qr,
 }
 
 fn ImportKeyHeader() -> Element {
@@ -105,21 +25,66 @@ fn ImportKeyHeader() -> Element {
             }
             p {
                 class: "text-lg",
-                "Recover a prior mining session from a backed up keypair. "
+                "Add an account from a backed up keypair or recovery phrase. "
             }
             p {
                 class: "text-sm text-gray-300 dark:text-gray-700",
-                "Never import a private key generated by another app or wallet."
+                "Imported keys are stored in this browser. Avoid importing a wallet that holds significant funds."
             }
         }
     }
 }
 
-const KEY_LENGTH: usize = 64;
+pub fn ImportKey() -> Element {
+    let mut mode = use_signal(|| ImportKeyMode::PrivateKey);
+    let tab_class = |m: ImportKeyMode| {
+        if mode.read().eq(&m) {
+            "font-semibold px-4 py-1 rounded bg-gray-100 dark:bg-gray-900"
+        } else {
+            "font-semibold px-4 py-1 rounded opacity-50 hover-100 active-200 transition-colors"
+        }
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-16 grow w-full h-full",
+            ImportKeyHeader {}
+            div {
+                class: "flex flex-row gap-2 mx-auto",
+                button {
+                    class: tab_class(ImportKeyMode::PrivateKey),
+                    onclick: move |_| mode.set(ImportKeyMode::PrivateKey),
+                    "Private key"
+                }
+                button {
+                    class: tab_class(ImportKeyMode::Mnemonic),
+                    onclick: move |_| mode.set(ImportKeyMode::Mnemonic),
+                    "Recovery phrase"
+                }
+                button {
+                    class: tab_class(ImportKeyMode::Backup),
+                    onclick: move |_| mode.set(ImportKeyMode::Backup),
+                    "Backup file"
+                }
+                button {
+                    class: tab_class(ImportKeyMode::Qr),
+                    onclick: move |_| mode.set(ImportKeyMode::Qr),
+                    "QR code"
+                }
+            }
+            match *mode.read() {
+                ImportKeyMode::PrivateKey => rsx! { ImportPrivateKey {} },
+                ImportKeyMode::Mnemonic => rsx! { ImportMnemonic {} },
+                ImportKeyMode::Backup => rsx! { RestoreBackup {} },
+                ImportKeyMode::Qr => rsx! { ImportQr {} },
+            }
+        }
+    }
+}
 
-fn ImportKeyImport() -> Element {
+fn ImportPrivateKey() -> Element {
     let mut sol_balance = use_signal::<Option<u64>>(|| None);
-    let mut keypair_persistent = use_keypair_persistent();
+    let mut session = use_session();
     let mut err_msg = use_signal::<Option<String>>(|| None);
     let mut enable_import_button = use_signal(|| false);
     let mut private_key_input = use_signal(|| "".to_string());
@@ -135,101 +100,99 @@ fn ImportKeyImport() -> Element {
         // let err_msg = err_msg.clone();
         let gateway = gateway.clone();
         async move {
-            if let Ok(bytes) = bs58::decode(private_key_input.read().clone()).into_vec() {
-                if bytes.len().eq(&KEY_LENGTH) {
-                    if let Ok(kp) = Keypair::from_bytes(&bytes) {
-                        enable_import_button.set(true);
-                        match gateway.rpc.get_balance(&kp.pubkey()).await {
-                            Ok(b) => sol_balance.set(Some(b)),
-                            Err(_err) => sol_balance.set(None),
-                        }
-                    }
-                } else if bytes.len().eq(&0) {
-                    sol_balance.set(None);
-                    enable_import_button.set(false);
+            let input = private_key_input.read().clone();
+            if input.trim().is_empty() {
+                sol_balance.set(None);
+                enable_import_button.set(false);
+                err_msg.set(None);
+                return;
+            }
+            // Accepts base58 text or a Solana CLI keypair file (JSON byte array)
+            match keystore::parse_secret_key(&input) {
+                Ok(kp) => {
                     err_msg.set(None);
-                } else {
+                    enable_import_button.set(true);
+                    match gateway.rpc.get_balance(&kp.pubkey()).await {
+                        Ok(b) => sol_balance.set(Some(b)),
+                        Err(_err) => sol_balance.set(None),
+                    }
+                }
+                Err(_) => {
                     sol_balance.set(None);
                     enable_import_button.set(false);
-                    err_msg.set(Some("Invalid length".to_string()));
+                    err_msg.set(Some("Invalid private key".to_string()));
                 }
-            } else {
-                sol_balance.set(None);
-                enable_import_button.set(false);
-                err_msg.set(Some("Invalid format".to_string()));
             }
         }
     });
 
     rsx! {
+        EyeSlashIcon {
+            class: "w-12 h-12 mx-auto opacity-50"
+        }
         div {
-            class: "flex flex-col gap-16 grow w-full h-full",
-            ImportKeyHeader {}
-            EyeSlashIcon {
-                class: "w-12 h-12 mx-auto opacity-50"
+            class: "flex flex-col gap-2",
+            input {
+                class: "mx-auto w-full py-2 text-center placeholder-gray-200 dark:placeholder-gray-700 bg-transparent",
+                autofocus: true,
+                placeholder: "Private key or keypair JSON",
+                value: "{*private_key_input.read()}",
+                oninput: move |e| {
+                    private_key_input.set(e.value());
+                },
             }
-            div {
-                class: "flex flex-col gap-2",
+            label {
+                class: "mx-auto text-sm font-semibold px-4 py-1 rounded hover-100 active-200 transition-colors hover:cursor-pointer",
+                "Upload keypair file (id.json)"
                 input {
-                    class: "mx-auto w-full py-2 text-center placeholder-gray-200 dark:placeholder-gray-700 bg-transparent",
-                    autofocus: true,
-                    placeholder: "Private key",
-                    value: "{*private_key_input.read()}",
-                    oninput: move |e| {
-                        private_key_input.set(e.value());
+                    class: "hidden",
+                    r#type: "file",
+                    accept: ".json,application/json",
+                    onchange: move |e| async move {
+                        if let Some(file_engine) = e.files() {
+                            if let Some(name) = file_engine.files().first() {
+                                match file_engine.read_file_to_string(name).await {
+                                    Some(contents) => private_key_input.set(contents.trim().to_string()),
+                                    None => err_msg.set(Some("Failed to read file".to_string())),
+                                }
+                            }
+                        }
                     },
                 }
-                if let Some(err_msg) = err_msg.read().clone() {
-                    p {
-                        class: "text-red-500 text-sm font-right",
-                        "{err_msg}"
-                    }
-                }
             }
-            if let Some(sol_balance) = *sol_balance.read() {
+            if let Some(err_msg) = err_msg.read().clone() {
                 p {
-                    class: "text-nowrap mx-auto text-center font-semibold",
-                    "Balance: {lamports_to_sol(sol_balance)} SOL"
+                    class: "text-red-500 text-sm font-right",
+                    "{err_msg}"
                 }
-            } else {
-                div {
-                    class: "flex flex-row w-24 h-16 loading rounded-full",
-                }
-            }
-            button {
-                disabled: !*enable_import_button.read(),
-                onclick: move |_| {
-                    keypair_persistent.set(private_key_input.read().clone());
-                    nav.push(Route::Settings {});
-                },
-                class: "bg-green-500 disabled:opacity-50 hover:bg-green-600 active:bg-green-700 transition-colors text-white rounded text-center font-semibold py-3 mt-auto",
-                "Import"
             }
         }
-    }
-}
-
-#[component]
-fn LossOfFundsWarning(balance: u64) -> Element {
-    rsx! {
-        div {
-            class: "flex flex-col gap-3 bg-red-500 w-full rounded px-4 py-5 mt-8 text-white",
+        if let Some(sol_balance) = *sol_balance.read() {
             p {
-                class: "font-bold text-xl",
-                "Your current keypair has funds on it!"
+                class: "text-nowrap mx-auto text-center font-semibold",
+                "Balance: {lamports_to_sol(sol_balance)} SOL"
             }
-            ul {
-                class: "list-disc list-outside pl-4 space-y-1.5",
-                li {
-                    "Your current keypair has a balance of {lamports_to_sol(balance)} SOL. "
-                }
-                li {
-                    "Importing a new keypair will replace your current one. "
-                }
-                li {
-                    "Please ensure you have safely backed up your keypair to avoid losing access to your funds."
-                }
+        } else {
+            div {
+                class: "flex flex-row w-24 h-16 loading rounded-full",
             }
         }
+        button {
+            disabled: !*enable_import_button.read(),
+            onclick: move |_| {
+                match keystore::parse_secret_key(&private_key_input.read()).and_then(keystore::add_profile) {
+                    Ok(pubkey) => {
+                        session.set(SessionStatus::Unlocked(pubkey));
+                        nav.push(Route::Settings {});
+                    }
+                    Err(err) => {
+                        log::error!("Failed to import keypair: {:?}", err);
+                        err_msg.set(Some("Failed to save keypair".to_string()));
+                    }
+                }
+            },
+            class: "bg-green-500 disabled:opacity-50 hover:bg-green-600 active:bg-green-700 transition-colors text-white rounded text-center font-semibold py-3 mt-auto",
+            "Import"
+        }
     }
 }
@@ -0,0 +1,198 @@
+use dioxus::prelude::*;
+use solana_client_wasm::solana_sdk::{
+    native_token::lamports_to_sol, pubkey::Pubkey, signer::Signer,
+};
+
+use crate::{
+    hooks::{use_gateway, use_session, SessionStatus},
+    keystore::{self, DerivationScheme, DERIVATION_SCHEMES},
+    route::Route,
+};
+
+/// Number of derived addresses previewed for each derivation scheme.
+const PREVIEW_ACCOUNTS: u32 = 5;
+
+#[derive(Clone, PartialEq)]
+struct DerivedAccount {
+    index: u32,
+    pubkey: Pubkey,
+    balance: Option<u64>,
+}
+
+pub fn ImportMnemonic() -> Element {
+    let mut session = use_session();
+    let mut phrase_input = use_signal(|| "".to_string());
+    let mut passphrase_input = use_signal(|| "".to_string());
+    let mut scheme = use_signal(|| DerivationScheme::Bip44Change);
+    let mut selected = use_signal::<Option<u32>>(|| None);
+    let mut err_msg = use_signal::<Option<String>>(|| None);
+    let gateway = use_gateway();
+    let nav = navigator();
+
+    let seed = use_memo(move || {
+        let phrase = keystore::normalize_mnemonic(&phrase_input.read());
+        keystore::mnemonic_seed(&phrase, &passphrase_input.read()).ok()
+    });
+    let is_invalid = !phrase_input.read().trim().is_empty() && seed.read().is_none();
+
+    let accounts = use_resource(move || {
+        let gateway = gateway.clone();
+        async move {
+            let seed = seed.read().clone()?;
+            let scheme = *scheme.read();
+            let count = if scheme.is_indexed() { PREVIEW_ACCOUNTS } else { 1 };
+            let mut accounts = vec![];
+            for index in 0..count {
+                let keypair = keystore::keypair_from_mnemonic_seed(&seed, scheme, index).ok()?;
+                let pubkey = keypair.pubkey();
+                let balance = gateway.rpc.get_balance(&pubkey).await.ok();
+                accounts.push(DerivedAccount {
+                    index,
+                    pubkey,
+                    balance,
+                });
+            }
+            Some(accounts)
+        }
+    });
+
+    // Reset the selection whenever the derived addresses change
+    use_effect(move || {
+        let _ = seed.read();
+        let _ = scheme.read();
+        selected.set(None);
+    });
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-4",
+            textarea {
+                class: "mx-auto w-full py-2 text-center placeholder-gray-200 dark:placeholder-gray-700 bg-transparent resize-none",
+                autofocus: true,
+                rows: 3,
+                placeholder: "Recovery phrase",
+                value: "{phrase_input}",
+                oninput: move |e| phrase_input.set(e.value()),
+            }
+            input {
+                class: "mx-auto w-full py-2 text-center placeholder-gray-200 dark:placeholder-gray-700 bg-transparent",
+                r#type: "password",
+                placeholder: "BIP39 passphrase (optional)",
+                value: "{passphrase_input}",
+                oninput: move |e| passphrase_input.set(e.value()),
+            }
+            div {
+                class: "flex flex-row gap-8 justify-between w-full",
+                p {
+                    class: "font-medium text-sm opacity-50 my-auto",
+                    "Derivation path"
+                }
+                select {
+                    class: "text-right bg-transparent dark:text-white hover:cursor-pointer py-1",
+                    onchange: move |e| {
+                        if let Some(s) = e.value().parse::<usize>().ok().and_then(|i| DERIVATION_SCHEMES.get(i)) {
+                            scheme.set(*s);
+                        }
+                    },
+                    for (i, s) in DERIVATION_SCHEMES.iter().enumerate() {
+                        option { initial_selected: scheme.read().eq(s), value: "{i}", "{s}" }
+                    }
+                }
+            }
+            if is_invalid {
+                p {
+                    class: "text-red-500 text-sm text-center",
+                    "Invalid recovery phrase"
+                }
+            }
+            if let Some(err_msg) = err_msg.read().clone() {
+                p {
+                    class: "text-red-500 text-sm text-center",
+                    "{err_msg}"
+                }
+            }
+        }
+        match accounts.read().clone() {
+            Some(Some(accounts)) => rsx! {
+                div {
+                    class: "flex flex-col gap-1",
+                    for account in accounts {
+                        DerivedAccountRow {
+                            account: account.clone(),
+                            path: scheme.read().path(account.index).unwrap_or_default(),
+                            selected,
+                        }
+                    }
+                }
+            },
+            Some(None) => rsx! {},
+            None => rsx! {
+                div {
+                    class: "flex flex-row h-32 w-full loading rounded",
+                }
+            },
+        }
+        button {
+            disabled: selected.read().is_none(),
+            onclick: move |_| {
+                let Some(index) = *selected.read() else {
+                    return;
+                };
+                let Some(seed) = seed.read().clone() else {
+                    return;
+                };
+                let result = keystore::keypair_from_mnemonic_seed(&seed, *scheme.read(), index)
+                    .and_then(keystore::add_profile);
+                match result {
+                    Ok(pubkey) => {
+                        session.set(SessionStatus::Unlocked(pubkey));
+                        nav.push(Route::Settings {});
+                    }
+                    Err(err) => {
+                        log::error!("Failed to import keypair: {:?}", err);
+                        err_msg.set(Some("Failed to save keypair".to_string()));
+                    }
+                }
+            },
+            class: "bg-green-500 disabled:opacity-50 hover:bg-green-600 active:bg-green-700 transition-colors text-white rounded text-center font-semibold py-3 mt-auto",
+            "Import"
+        }
+    }
+}
+
+#[component]
+fn DerivedAccountRow(account: DerivedAccount, path: String, selected: Signal<Option<u32>>) -> Element {
+    let is_selected = selected.read().eq(&Some(account.index));
+    let class = if is_selected {
+        "bg-green-500 text-white"
+    } else {
+        "hover-100 active-200"
+    };
+    rsx! {
+        button {
+            class: "flex flex-row gap-4 justify-between w-full px-3 py-2 rounded transition-colors {class}",
+            onclick: move |_| selected.set(Some(account.index)),
+            div {
+                class: "flex flex-col text-left min-w-0",
+                p {
+                    class: "font-mono font-medium truncate",
+                    "{account.pubkey}"
+                }
+                if !path.is_empty() {
+                    p {
+                        class: "text-xs opacity-50 font-mono",
+                        "{path}"
+                    }
+                }
+            }
+            p {
+                class: "my-auto text-nowrap font-semibold",
+                if let Some(balance) = account.balance {
+                    "{lamports_to_sol(balance)} SOL"
+                } else {
+                    "–"
+                }
+            }
+        }
+    }
+}
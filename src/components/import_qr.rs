@@ -0,0 +1,187 @@
+use dioxus::prelude::*;
+
+use crate::{
+    components::Spinner,
+    hooks::{use_session, SessionStatus},
+    keystore::{self, KeystoreError, TransferFrames},
+    qr_scanner,
+    route::Route,
+};
+
+pub fn ImportQr() -> Element {
+    let mut session = use_session();
+    let mut frames = use_signal(TransferFrames::default);
+    let mut pasted = use_signal(|| "".to_string());
+    let mut code = use_signal(|| "".to_string());
+    let mut err_msg = use_signal::<Option<String>>(|| None);
+    let mut is_busy = use_signal(|| false);
+    let nav = navigator();
+
+    let mut add_frames = move |values: Vec<String>| {
+        let mut added = 0;
+        let mut rejected = 0;
+        for value in values {
+            match frames.write().add(&value) {
+                Ok(()) => added += 1,
+                Err(err) => {
+                    log::error!("Ignoring invalid transfer frame: {:?}", err);
+                    rejected += 1;
+                }
+            }
+        }
+        if frames.read().total().gt(&0) && rejected.gt(&0) {
+            err_msg.set(Some("Ignored frames from a different export".to_string()));
+        } else if added.eq(&0) {
+            err_msg.set(Some("No transfer code found".to_string()));
+        } else {
+            err_msg.set(None);
+        }
+    };
+
+    let add_images = move |images: Vec<web_sys::File>| async move {
+        let mut values = vec![];
+        for image in images {
+            match qr_scanner::decode_qr_blob(&image).await {
+                Ok(mut decoded) => values.append(&mut decoded),
+                Err(err) => log::error!("Failed to decode QR image: {:?}", err),
+            }
+        }
+        add_frames(values);
+    };
+
+    let received = frames.read().received();
+    let total = frames.read().total();
+    let is_complete = frames.read().is_complete();
+    let is_code_valid = keystore::normalize_transfer_code(&code.read())
+        .len()
+        .eq(&keystore::CODE_LENGTH);
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2",
+            if qr_scanner::is_supported() {
+                label {
+                    class: "mx-auto text-sm font-semibold px-4 py-1 rounded hover-100 active-200 transition-colors hover:cursor-pointer",
+                    "Upload QR code images"
+                    input {
+                        class: "hidden",
+                        r#type: "file",
+                        accept: "image/*",
+                        multiple: true,
+                        onchange: move |e| async move {
+                            if let Some(file_engine) = e.files() {
+                                let mut values = vec![];
+                                for name in file_engine.files() {
+                                    let Some(bytes) = file_engine.read_file(&name).await else {
+                                        continue;
+                                    };
+                                    match qr_scanner::decode_qr_image(&bytes).await {
+                                        Ok(mut decoded) => values.append(&mut decoded),
+                                        Err(err) => log::error!("Failed to decode QR image: {:?}", err),
+                                    }
+                                }
+                                add_frames(values);
+                            }
+                        },
+                    }
+                }
+            } else {
+                p {
+                    class: "text-sm text-center text-gray-300 dark:text-gray-700",
+                    "This browser cannot read QR images. Scan the code with another app and paste the text below."
+                }
+            }
+            textarea {
+                class: "mx-auto w-full h-24 py-2 text-center placeholder-gray-200 dark:placeholder-gray-700 bg-transparent resize-none",
+                placeholder: if qr_scanner::is_supported() {
+                    "Or paste a QR image or the scanned text, one frame per line"
+                } else {
+                    "Or paste the scanned text, one frame per line"
+                },
+                value: "{pasted}",
+                oninput: move |e| pasted.set(e.value()),
+                onpaste: move |e| async move {
+                    if !qr_scanner::is_supported() {
+                        return;
+                    }
+                    let images = e
+                        .data()
+                        .downcast::<web_sys::Event>()
+                        .map(qr_scanner::clipboard_images)
+                        .unwrap_or_default();
+                    if !images.is_empty() {
+                        add_images(images).await;
+                    }
+                },
+            }
+            button {
+                class: "mx-auto text-sm font-semibold px-4 py-1 rounded hover-100 active-200 transition-colors",
+                disabled: pasted.read().trim().is_empty(),
+                onclick: move |_| {
+                    let values = pasted
+                        .read()
+                        .lines()
+                        .map(|line| line.trim().to_string())
+                        .filter(|line| !line.is_empty())
+                        .collect();
+                    add_frames(values);
+                    pasted.set("".to_string());
+                },
+                "Add"
+            }
+            if total.gt(&0) {
+                p {
+                    class: "text-sm text-center opacity-50",
+                    "Received {received} of {total} frames"
+                }
+            }
+            input {
+                class: "mx-auto w-full py-2 text-center placeholder-gray-200 dark:placeholder-gray-700 bg-transparent font-mono tracking-widest",
+                placeholder: "One-time code",
+                value: "{code}",
+                oninput: move |e| code.set(e.value()),
+            }
+            if let Some(err_msg) = err_msg.read().clone() {
+                p {
+                    class: "text-red-500 text-sm font-right",
+                    "{err_msg}"
+                }
+            }
+        }
+        if *is_busy.read() {
+            Spinner {
+                class: "mx-auto"
+            }
+        }
+        button {
+            disabled: !is_complete || !is_code_valid || *is_busy.read(),
+            onclick: move |_| {
+                is_busy.set(true);
+                spawn(async move {
+                    // Let the spinner render before deriving the key
+                    async_std::task::sleep(std::time::Duration::from_millis(50)).await;
+                    let result = frames
+                        .read()
+                        .decode(&code.read())
+                        .and_then(keystore::add_profile);
+                    match result {
+                        Ok(pubkey) => {
+                            session.set(SessionStatus::Unlocked(pubkey));
+                            nav.push(Route::Settings {});
+                        }
+                        Err(KeystoreError::InvalidPassphrase) => {
+                            err_msg.set(Some("Wrong one-time code".to_string()));
+                        }
+                        Err(err) => {
+                            log::error!("Failed to import transferred key: {:?}", err);
+                            err_msg.set(Some("Failed to import key".to_string()));
+                        }
+                    }
+                    is_busy.set(false);
+                });
+            },
+            class: "bg-green-500 disabled:opacity-50 hover:bg-green-600 active:bg-green-700 transition-colors text-white rounded text-center font-semibold py-3 mt-auto",
+            "Import"
+        }
+    }
+}
@@ -1,16 +1,18 @@
 use dioxus::prelude::*;
+use is_url::is_url;
 // use ore_relayer_api::state::Escrow;
 use solana_extra_wasm::program::spl_token::amount_to_ui_amount;
 use std::str::FromStr;
 
 use crate::{
     components::{
-        BackButton, MinerToolbarTopUpOpen, OreIcon, PriorityFeeStrategy, Spinner, MIN_BALANCE
+        BackButton, MinerToolbarTopUpOpen, MiningHistory, OreIcon, PowerBenchmark, Spinner, MIN_BALANCE
     },
-    // gateway,
+    gateway::PoolClient,
     hooks::{
-        use_gateway, use_miner_toolbar_state, use_power_level, use_priority_fee, use_priority_fee_strategy, use_proof, use_sol_balance, MinerStatus, MinerStatusMessage, PowerLevel, PriorityFee, ReadMinerToolbarState
+        format_hashrate, format_minutes, parse_minutes, use_gateway, use_hashrate, use_miner_toolbar_state, use_mining_schedule, use_pool_url, use_power_level, use_pubkey, use_priority_fee, use_priority_fee_strategy, use_proof, use_remote_agents, use_sol_balance, use_submit_policy, MinerStatus, MinerStatusMessage, PoolUrl, PowerLevel, PriorityFee, PriorityFeeStrategy, ReadMinerToolbarState, RemoteAgentUrls, ScheduleWindow, SubmitMode, BusStrategy, ResetPolicy, MAX_SAFETY_MARGIN, WEEKDAYS
     },
+    idle,
     miner::WEB_WORKERS,
 };
 
@@ -21,10 +23,11 @@ use crate::{
 pub fn Mine() -> Element {
     let sol_balance = use_sol_balance();
     let toolbar_state = use_miner_toolbar_state();
+    let pool_url = use_pool_url();
     let nav = use_navigator();
 
     if let Some(Ok(balance)) = *sol_balance.read() {
-        if balance.lt(&MIN_BALANCE) {
+        if balance.lt(&MIN_BALANCE) && pool_url.read().is_solo() {
             return rsx! {
                 MinerToolbarTopUpOpen {}
             };
@@ -111,9 +114,16 @@ pub fn Mine() -> Element {
             }
             StakeBalanceDisplay {}
             MultiplierDisplay {}
+            HashrateDisplay {}
             PowerLevelConfig {}
+            PowerBenchmark {}
+            SubmitPolicyConfig {}
+            ScheduleConfig {}
+            PoolConfig {}
+            RemoteAgentsConfig {}
             PriorityFeeStrategyConfig {}
             PriorityFeeConfig {}
+            MiningHistory {}
             // DownloadLink {}
 
         }
@@ -209,6 +219,113 @@ pub fn MultiplierDisplay() -> Element {
     }
 }
 
+pub fn HashrateDisplay() -> Element {
+    let hashrate = use_hashrate();
+    let hashrate = hashrate.read();
+    let max_count = hashrate.histogram.values().max().copied().unwrap_or(1).max(1);
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-4",
+            div {
+                class: "flex flex-row gap-8 justify-between",
+                p {
+                    class: "text-gray-300 font-medium text-sm my-auto",
+                    "Hashrate"
+                }
+                p {
+                    class: "font-semibold",
+                    "{format_hashrate(hashrate.hashrate())}"
+                }
+            }
+            div {
+                class: "flex flex-row gap-8 justify-between",
+                p {
+                    class: "text-gray-300 font-medium text-sm my-auto",
+                    "Best difficulty"
+                }
+                p {
+                    class: "font-semibold",
+                    "{hashrate.best_difficulty}"
+                }
+            }
+            if let Some(last_bus) = hashrate.last_bus {
+                div {
+                    class: "flex flex-row gap-8 justify-between",
+                    p {
+                        class: "text-gray-300 font-medium text-sm my-auto",
+                        "Last bus"
+                    }
+                    div {
+                        class: "flex flex-col text-right",
+                        p {
+                            class: "font-semibold",
+                            "#{last_bus}"
+                        }
+                        p {
+                            class: "text-xs text-gray-300",
+                            {hashrate.buses.iter().map(|(bus, count)| format!("#{bus}: {count}")).collect::<Vec<_>>().join(", ")}
+                        }
+                    }
+                }
+            }
+            if !hashrate.workers.is_empty() {
+                div {
+                    class: "flex flex-col gap-1",
+                    p {
+                        class: "text-gray-300 font-medium text-sm",
+                        "Workers"
+                    }
+                    for worker in hashrate.workers.iter() {
+                        div {
+                            key: "{worker.worker}",
+                            class: "flex flex-row gap-8 justify-between text-sm",
+                            p {
+                                class: "text-gray-300",
+                                "#{worker.worker + 1}"
+                            }
+                            p {
+                                class: "font-mono",
+                                "{format_hashrate(worker.hashrate())}"
+                            }
+                        }
+                    }
+                }
+            }
+            if !hashrate.histogram.is_empty() {
+                div {
+                    class: "flex flex-col gap-1",
+                    p {
+                        class: "text-gray-300 font-medium text-sm",
+                        "Difficulty histogram ({hashrate.total_hashes} hashes this session)"
+                    }
+                    for (difficulty, count) in hashrate.histogram.iter() {
+                        div {
+                            key: "{difficulty}",
+                            class: "flex flex-row gap-2 text-sm",
+                            p {
+                                class: "font-mono w-8 shrink-0 text-right",
+                                "{difficulty}"
+                            }
+                            div {
+                                class: "flex flex-row grow my-auto",
+                                div {
+                                    class: "h-2 rounded bg-green-500",
+                                    style: "width: {count * 100 / max_count}%",
+                                }
+                            }
+                            p {
+                                class: "font-mono w-12 shrink-0 text-right",
+                                "{count}"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
 pub fn PowerLevelConfig() -> Element {
     let mut power_level = use_power_level();
     let max = *WEB_WORKERS as i64;
@@ -252,6 +369,384 @@ pub fn PowerLevelConfig() -> Element {
     }
 }
 
+pub fn SubmitPolicyConfig() -> Element {
+    let mut submit_policy = use_submit_policy();
+    let policy = *submit_policy.read();
+
+    rsx! {
+        div {
+            class: "flex flex-row gap-8 justify-between",
+            div {
+                class: "flex flex-col gap-1",
+                p {
+                    class: "text-gray-300 font-medium text-sm my-auto",
+                    "Submit policy"
+                }
+                p {
+                    class: "text-gray-300 text-xs opacity-80 max-w-96",
+                    "When to stop hashing and submit. The program rejects solutions sent too early, so a target found before the deadline is held until then. Solutions below the minimum are never submitted. If a bus runs out of rewards, the solution is sent to another one. When the epoch ends, one miner must pay to reset it; choose whether you volunteer."
+                }
+            }
+            div {
+                class: "flex flex-col gap-2 shrink mb-auto items-end",
+                select {
+                    class: "bg-transparent dark:text-white text-right px-1 rounded font-semibold hover:bg-green-600 transition-colors",
+                    onchange: move |e| {
+                        if let Ok(mode) = SubmitMode::from_str(&e.value()) {
+                            submit_policy.write().mode = mode;
+                        }
+                    },
+                    option { initial_selected: policy.mode.eq(&SubmitMode::Deadline), value: "{SubmitMode::Deadline}", "{SubmitMode::Deadline}" }
+                    option { initial_selected: policy.mode.eq(&SubmitMode::Target), value: "{SubmitMode::Target}", "{SubmitMode::Target}" }
+                    option { initial_selected: policy.mode.eq(&SubmitMode::FullMinute), value: "{SubmitMode::FullMinute}", "{SubmitMode::FullMinute}" }
+                }
+                if policy.mode.eq(&SubmitMode::Target) {
+                    PolicyNumberInput {
+                        label: "Target difficulty",
+                        value: policy.target_difficulty as u64,
+                        oninput: move |v: u64| submit_policy.write().target_difficulty = v as u32,
+                    }
+                }
+                if policy.mode.ne(&SubmitMode::FullMinute) {
+                    PolicyNumberInput {
+                        label: "Seconds before deadline",
+                        value: policy.safety_margin,
+                        oninput: move |v: u64| submit_policy.write().safety_margin = v.min(MAX_SAFETY_MARGIN),
+                    }
+                }
+                PolicyNumberInput {
+                    label: "Min difficulty",
+                    value: policy.min_difficulty as u64,
+                    oninput: move |v: u64| submit_policy.write().min_difficulty = v as u32,
+                }
+                select {
+                    class: "bg-transparent dark:text-white text-right px-1 rounded font-semibold hover:bg-green-600 transition-colors",
+                    onchange: move |e| {
+                        if let Ok(strategy) = BusStrategy::from_str(&e.value()) {
+                            submit_policy.write().bus_strategy = strategy;
+                        }
+                    },
+                    for strategy in [BusStrategy::Random, BusStrategy::Richest, BusStrategy::LeastContended, BusStrategy::RoundRobin] {
+                        option { initial_selected: policy.bus_strategy.eq(&strategy), value: "{strategy}", "{strategy}" }
+                    }
+                }
+                select {
+                    class: "bg-transparent dark:text-white text-right px-1 rounded font-semibold hover:bg-green-600 transition-colors",
+                    onchange: move |e| {
+                        if let Ok(reset_policy) = ResetPolicy::from_str(&e.value()) {
+                            submit_policy.write().reset_policy = reset_policy;
+                        }
+                    },
+                    for reset_policy in [ResetPolicy::Include, ResetPolicy::Standalone, ResetPolicy::Wait] {
+                        option { initial_selected: policy.reset_policy.eq(&reset_policy), value: "{reset_policy}", "{reset_policy}" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn PolicyNumberInput(label: &'static str, value: u64, oninput: EventHandler<u64>) -> Element {
+    rsx! {
+        div {
+            class: "flex flex-row gap-1",
+            p {
+                class: "text-gray-300 text-sm my-auto",
+                "{label}"
+            }
+            input {
+                class: "bg-transparent dark:text-white text-right px-1 w-16 rounded font-semibold hover:bg-green-600 transition-colors",
+                dir: "rtl",
+                step: 1,
+                min: 0,
+                r#type: "number",
+                value: "{value}",
+                oninput: move |e| {
+                    if let Ok(v) = e.value().parse::<u64>() {
+                        oninput.call(v);
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn ScheduleConfig() -> Element {
+    let mut schedule = use_mining_schedule();
+    let current = schedule.read().clone();
+    let mut detector_running = use_signal(idle::is_detector_running);
+
+    rsx! {
+        div {
+            class: "flex flex-row gap-8 justify-between",
+            div {
+                class: "flex flex-col gap-1",
+                p {
+                    class: "text-gray-300 font-medium text-sm my-auto",
+                    "Schedule"
+                }
+                p {
+                    class: "text-gray-300 text-xs opacity-80 max-w-96",
+                    "Start and pause the miner at set times in your local timezone. A window may set its own power level, and one that starts and ends at the same time runs all day. Idle rules pause the miner while you use the computer and resume it when you are away."
+                }
+            }
+            div {
+                class: "flex flex-col gap-2 shrink mb-auto items-end",
+                label {
+                    class: "flex flex-row gap-2 text-sm",
+                    "Mine on schedule"
+                    input {
+                        r#type: "checkbox",
+                        checked: current.enabled,
+                        onchange: move |e| schedule.write().enabled = e.checked(),
+                    }
+                }
+                for (i, window) in current.windows.iter().cloned().enumerate() {
+                    div {
+                        key: "{i}",
+                        class: "flex flex-col gap-1 items-end",
+                        div {
+                            class: "flex flex-row gap-1 text-xs",
+                            for (d, day) in WEEKDAYS.iter().enumerate() {
+                                button {
+                                    class: if window.days[d] { "font-semibold dark:text-white" } else { "text-gray-300 hover:text-white transition-colors" },
+                                    onclick: move |_| {
+                                        if let Some(window) = schedule.write().windows.get_mut(i) {
+                                            window.days[d] = !window.days[d];
+                                        }
+                                    },
+                                    "{day}"
+                                }
+                            }
+                        }
+                        div {
+                            class: "flex flex-row gap-1 text-sm",
+                            input {
+                                class: "bg-transparent dark:text-white px-1 rounded font-semibold hover:bg-green-600 transition-colors",
+                                r#type: "time",
+                                value: "{format_minutes(window.start)}",
+                                oninput: move |e| {
+                                    if let (Some(start), Some(window)) = (parse_minutes(&e.value()), schedule.write().windows.get_mut(i)) {
+                                        window.start = start;
+                                    }
+                                }
+                            }
+                            p { class: "my-auto", "to" }
+                            input {
+                                class: "bg-transparent dark:text-white px-1 rounded font-semibold hover:bg-green-600 transition-colors",
+                                r#type: "time",
+                                value: "{format_minutes(window.end)}",
+                                oninput: move |e| {
+                                    if let (Some(end), Some(window)) = (parse_minutes(&e.value()), schedule.write().windows.get_mut(i)) {
+                                        window.end = end;
+                                    }
+                                }
+                            }
+                        }
+                        div {
+                            class: "flex flex-row gap-2 text-sm",
+                            p {
+                                class: "text-gray-300 my-auto",
+                                "Power level"
+                            }
+                            input {
+                                class: "bg-transparent dark:text-white text-right px-1 w-12 rounded font-semibold hover:bg-green-600 transition-colors placeholder-gray-300 dark:placeholder-gray-800",
+                                r#type: "number",
+                                min: 1,
+                                max: *WEB_WORKERS as i64,
+                                placeholder: "—",
+                                value: window.power_level.map(|p| p.to_string()).unwrap_or_default(),
+                                oninput: move |e| {
+                                    if let Some(window) = schedule.write().windows.get_mut(i) {
+                                        window.power_level = e.value().parse::<u64>().ok().map(|p| p.clamp(1, *WEB_WORKERS as u64));
+                                    }
+                                }
+                            }
+                            button {
+                                class: "text-gray-300 hover:text-white transition-colors",
+                                onclick: move |_| {
+                                    schedule.write().windows.remove(i);
+                                },
+                                "Remove"
+                            }
+                        }
+                    }
+                }
+                button {
+                    class: "bg-green-500 hover:bg-green-600 active:bg-green-700 text-white rounded transition-colors px-2 py-1 text-sm",
+                    onclick: move |_| schedule.write().windows.push(ScheduleWindow::default()),
+                    "Add window"
+                }
+                label {
+                    class: "flex flex-row gap-2 text-sm",
+                    "Pause while this page is visible"
+                    input {
+                        r#type: "checkbox",
+                        checked: current.pause_when_visible,
+                        onchange: move |e| schedule.write().pause_when_visible = e.checked(),
+                    }
+                }
+                label {
+                    class: "flex flex-row gap-2 text-sm",
+                    "Pause while I am active"
+                    input {
+                        r#type: "checkbox",
+                        checked: current.pause_when_active,
+                        onchange: move |e| schedule.write().pause_when_active = e.checked(),
+                    }
+                }
+                if current.pause_when_active {
+                    PolicyNumberInput {
+                        label: "Idle after minutes",
+                        value: current.idle_minutes,
+                        oninput: move |v: u64| schedule.write().idle_minutes = v.max(1),
+                    }
+                    if idle::is_detector_supported() && !*detector_running.read() {
+                        button {
+                            class: "text-gray-300 hover:text-white text-sm transition-colors",
+                            onclick: move |_| {
+                                let threshold_ms = schedule.read().idle_minutes.saturating_mul(60_000);
+                                spawn(async move {
+                                    match idle::start_detector(threshold_ms).await {
+                                        Ok(_) => detector_running.set(true),
+                                        Err(err) => log::error!("Failed to start idle detection: {:?}", err),
+                                    }
+                                });
+                            },
+                            "Use system idle detection"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn PoolConfig() -> Element {
+    let mut pool_url = use_pool_url();
+    let mut pool_url_input = use_signal(|| pool_url.read().0.clone());
+    let is_edited = pool_url.read().0.ne(&*pool_url_input.read());
+    let toolbar_state = use_miner_toolbar_state();
+    let is_mining = matches!(
+        toolbar_state.status(),
+        MinerStatus::Active | MinerStatus::Activating
+    );
+    let pubkey = use_pubkey();
+    let stats = use_resource(move || async move {
+        let pool_url = pool_url.read().clone();
+        if pool_url.is_solo() {
+            return None;
+        }
+        PoolClient::new(&pool_url.0).get_member(pubkey).await.ok()
+    });
+
+    rsx! {
+        div {
+            class: "flex flex-row gap-8 justify-between",
+            div {
+                class: "flex flex-col gap-1",
+                p {
+                    class: "text-gray-300 font-medium text-sm my-auto",
+                    "Pool"
+                }
+                p {
+                    class: "text-gray-300 text-xs opacity-80 max-w-96",
+                    "Mine with a pool to get credited for shares instead of submitting alone. The pool pays the transaction fees. Leave empty to mine solo."
+                }
+                if let Some(Some(stats)) = stats.read().clone() {
+                    p {
+                        class: "text-xs font-semibold",
+                        "{stats.shares} shares, {stats.contribution} credits"
+                    }
+                }
+            }
+            div {
+                class: "flex flex-col gap-2 shrink mb-auto",
+                input {
+                    class: "bg-transparent dark:text-white text-right px-1 rounded font-semibold placeholder-gray-300 dark:placeholder-gray-800",
+                    disabled: is_mining,
+                    placeholder: "https://pool.example.com",
+                    value: "{pool_url_input}",
+                    oninput: move |e| pool_url_input.set(e.value().trim().to_string()),
+                }
+                if is_edited && !is_mining && (pool_url_input.read().is_empty() || is_url(&pool_url_input.read())) {
+                    button {
+                        class: "bg-green-500 hover:bg-green-600 active:bg-green-700 text-white rounded ml-auto transition-colors px-2 py-1",
+                        onclick: move |_| pool_url.set(PoolUrl(pool_url_input.read().clone())),
+                        "Save"
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn RemoteAgentsConfig() -> Element {
+    let mut remote_agents = use_remote_agents();
+    let mut url_input = use_signal(|| "".to_string());
+    let is_valid = {
+        let url = url_input.read();
+        (url.starts_with("ws://") || url.starts_with("wss://"))
+            && !remote_agents.read().0.contains(&*url)
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-row gap-8 justify-between",
+            div {
+                class: "flex flex-col gap-1",
+                p {
+                    class: "text-gray-300 font-medium text-sm my-auto",
+                    "Agents"
+                }
+                p {
+                    class: "text-gray-300 text-xs opacity-80 max-w-96",
+                    "Hash on other machines by running the ore-agent binary there and adding its WebSocket url. Agent cores join the next batch."
+                }
+            }
+            div {
+                class: "flex flex-col gap-2 shrink mb-auto",
+                for url in remote_agents.read().0.clone() {
+                    div {
+                        key: "{url}",
+                        class: "flex flex-row gap-2 justify-end",
+                        p {
+                            class: "font-mono text-sm my-auto truncate",
+                            "{url}"
+                        }
+                        button {
+                            class: "text-gray-300 hover:text-white text-sm transition-colors",
+                            onclick: move |_| {
+                                remote_agents.write().0.retain(|u| u.ne(&url));
+                            },
+                            "Remove"
+                        }
+                    }
+                }
+                input {
+                    class: "bg-transparent dark:text-white text-right px-1 rounded font-semibold placeholder-gray-300 dark:placeholder-gray-800",
+                    placeholder: "ws://192.168.1.20:9100",
+                    value: "{url_input}",
+                    oninput: move |e| url_input.set(e.value().trim().to_string()),
+                }
+                if is_valid {
+                    button {
+                        class: "bg-green-500 hover:bg-green-600 active:bg-green-700 text-white rounded ml-auto transition-colors px-2 py-1",
+                        onclick: move |_| {
+                            let mut urls = remote_agents.read().0.clone();
+                            urls.push(url_input.read().clone());
+                            remote_agents.set(RemoteAgentUrls(urls));
+                            url_input.set("".to_string());
+                        },
+                        "Add"
+                    }
+                }
+            }
+        }
+    }
+}
+
 pub fn PriorityFeeStrategyConfig() -> Element {
     let mut priority_fee_strategy = use_priority_fee_strategy();
 
@@ -33,17 +33,17 @@ pub fn MinerToolbarActivating(miner: Signal<Miner>) -> Element {
     let _ = use_resource(move || {
         let gateway = gateway.clone();
         async move {
-            if let Some(Ok(balance)) = *sol_balance.read() {
-                if balance.ge(&MIN_BALANCE) {
-                    match try_start_mining(gateway, miner, &mut toolbar_state).await {
-                        Ok(()) => {
-                            toolbar_state.set_status(MinerStatus::Active);
-                        }
-                        Err(err) => {
-                            log::error!("Failed to start mining: {:?}", err);
-                            toolbar_state.set_status(MinerStatus::Error);
-                            toolbar_state.set_status_message(MinerStatusMessage::Error);
-                        }
+            // Pool members do not pay transaction fees
+            let is_funded = matches!(*sol_balance.read(), Some(Ok(balance)) if balance.ge(&MIN_BALANCE));
+            if is_funded || miner.peek().pool().is_some() {
+                match try_start_mining(gateway, miner, &mut toolbar_state).await {
+                    Ok(()) => {
+                        toolbar_state.set_status(MinerStatus::Active);
+                    }
+                    Err(err) => {
+                        log::error!("Failed to start mining: {:?}", err);
+                        toolbar_state.set_status(MinerStatus::Error);
+                        toolbar_state.set_status_message(MinerStatusMessage::Error);
                     }
                 }
             }
@@ -51,7 +51,7 @@ pub fn MinerToolbarActivating(miner: Signal<Miner>) -> Element {
     });
 
     if let Some(Ok(balance)) = *sol_balance.read() {
-        if balance.lt(&MIN_BALANCE) {
+        if balance.lt(&MIN_BALANCE) && miner.peek().pool().is_none() {
             nav.push(Route::Mine {});
         }
     }
@@ -1,27 +1,23 @@
 use dioxus::prelude::*;
-use solana_client_wasm::solana_sdk::keccak::Hash as KeccakHash;
 
 use crate::{
     components::StopButton,
     hooks::{
-        use_miner_toolbar_state, MinerStatusMessage, ReadMinerToolbarState, UpdateMinerToolbarState,
+        format_hashrate, use_hashrate, use_miner_toolbar_state, use_submit_policy,
+        MinerStatusMessage, ReadMinerToolbarState,
     },
     miner::Miner,
 };
 
 #[component]
 pub fn MinerToolbarActive(miner: Signal<Miner>) -> Element {
-    let mut toolbar_state = use_miner_toolbar_state();
-
-    // Animate the hash in the miner toolbar to visualize mining.
-    use_future(move || async move {
-        loop {
-            async_std::task::sleep(std::time::Duration::from_millis(125)).await;
-            if let MinerStatusMessage::Searching = toolbar_state.status_message() {
-                toolbar_state.set_display_hash(KeccakHash::new_unique());
-            }
-        }
-    });
+    let toolbar_state = use_miner_toolbar_state();
+    let hashrate = use_hashrate();
+    let submit_policy = use_submit_policy();
+    let policy = match toolbar_state.submit_outcome() {
+        Some(outcome) => format!("{}: {}", submit_policy.read(), outcome),
+        None => submit_policy.read().to_string(),
+    };
 
     rsx! {
         div {
@@ -31,9 +27,28 @@ pub fn MinerToolbarActive(miner: Signal<Miner>) -> Element {
                 match toolbar_state.status_message() {
                     MinerStatusMessage::Searching => {
                         rsx! {
-                            p {
-                                class: "font-mono text-sm truncate flex-shrink flex-auto opacity-80 my-auto ml-2",
-                                "{toolbar_state.display_hash()}"
+                            if hashrate.read().workers.is_empty() {
+                                p {
+                                    class: "text-sm truncate flex-shrink flex-auto dark:text-white font-medium opacity-80 my-auto ml-2",
+                                    "Searching for valid hashes..."
+                                }
+                            } else {
+                                p {
+                                    class: "font-semibold text-sm flex-shrink-0 flex-none dark:text-white my-auto ml-2",
+                                    "{format_hashrate(hashrate.read().hashrate())}"
+                                }
+                                p {
+                                    class: "text-sm flex-shrink-0 flex-none opacity-80 my-auto",
+                                    "best {hashrate.read().best_difficulty}"
+                                }
+                                p {
+                                    class: "text-sm truncate flex-shrink opacity-80 my-auto",
+                                    "{policy}"
+                                }
+                                p {
+                                    class: "font-mono text-sm truncate flex-shrink flex-auto opacity-80 my-auto",
+                                    "{toolbar_state.display_hash()}"
+                                }
                             }
                         }
                     }
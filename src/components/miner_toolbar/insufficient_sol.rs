@@ -1,10 +1,11 @@
 use dioxus::prelude::*;
-use solana_client_wasm::solana_sdk::pubkey::Pubkey;
+use solana_client_wasm::solana_sdk::{native_token::sol_to_lamports, pubkey::Pubkey};
 
 use crate::{
-    components::{BackButton, Copyable},
+    components::{BackButton, Copyable, Spinner, WalletAdapterButton},
     hooks::{
-        use_is_onboarded, use_pubkey, use_sol_balance, IsOnboarded,
+        use_gateway, use_is_onboarded, use_priority_fee, use_pubkey, use_sol_balance,
+        use_wallet_adapter, use_wallet_sol_balance, IsOnboarded, WalletAdapter,
     },
 };
 
@@ -89,6 +90,7 @@ pub fn MinerToolbarInsufficientBalanceOpen() -> Element {
                         "{pubkey}"
                     }
                 }
+                WalletTopUp {}
                 a {
                     // TODO Get referal code
                     href: "https://www.coinbase.com/price/solana",
@@ -101,6 +103,97 @@ pub fn MinerToolbarInsufficientBalanceOpen() -> Element {
     }
 }
 
+pub fn WalletTopUp() -> Element {
+    let wallet_adapter = use_wallet_adapter();
+    let mut wallet_sol_balance = use_wallet_sol_balance();
+    let mut sol_balance = use_sol_balance();
+    let priority_fee = use_priority_fee();
+    let gateway = use_gateway();
+    let mut amount_input = use_signal(|| TOP_UP_AMOUNT.to_string());
+    let mut is_busy = use_signal(|| false);
+    let mut err_msg = use_signal::<Option<String>>(|| None);
+
+    let amount = amount_input.read().parse::<f64>().map(sol_to_lamports).unwrap_or(0);
+    let insufficient_funds = match *wallet_sol_balance.read() {
+        Some(Some(balance)) => balance.lt(&amount),
+        _ => false,
+    };
+    let is_disabled = *is_busy.read() || amount.eq(&0) || insufficient_funds;
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-4 mx-auto",
+            p {
+                class: "text-sm text-center text-gray-300",
+                "Or top up directly from a browser wallet."
+            }
+            div {
+                class: "mx-auto",
+                WalletAdapterButton {}
+            }
+            if let WalletAdapter::Connected(wallet) = *wallet_adapter.read() {
+                div {
+                    class: "flex flex-row gap-2 mx-auto",
+                    input {
+                        class: "bg-transparent w-24 text-right px-1 font-semibold",
+                        disabled: *is_busy.read(),
+                        r#type: "number",
+                        step: 0.01,
+                        min: 0,
+                        value: "{amount_input}",
+                        oninput: move |e| amount_input.set(e.value()),
+                    }
+                    p {
+                        class: "my-auto font-semibold",
+                        "SOL"
+                    }
+                    button {
+                        class: "flex flex-row gap-2 px-4 py-2 rounded font-semibold text-white bg-green-500 hover:bg-green-600 active:bg-green-700 transition-colors disabled:opacity-50",
+                        disabled: is_disabled,
+                        onclick: move |_| {
+                            is_busy.set(true);
+                            err_msg.set(None);
+                            let gateway = gateway.clone();
+                            spawn(async move {
+                                match gateway.fund_miner_from_wallet(wallet, amount, priority_fee.read().0).await {
+                                    Ok(_sig) => {
+                                        sol_balance.restart();
+                                        wallet_sol_balance.restart();
+                                    }
+                                    Err(err) => {
+                                        log::error!("Failed to top up from wallet: {:?}", err);
+                                        err_msg.set(Some("Top up failed".to_string()));
+                                    }
+                                }
+                                is_busy.set(false);
+                            });
+                        },
+                        if *is_busy.read() {
+                            Spinner {
+                                class: "mx-auto"
+                            }
+                        } else {
+                            "Top up from wallet"
+                        }
+                    }
+                }
+                if insufficient_funds {
+                    p {
+                        class: "text-red-500 text-sm text-center",
+                        "Insufficient wallet balance"
+                    }
+                }
+                if let Some(err_msg) = err_msg.read().clone() {
+                    p {
+                        class: "text-red-500 text-sm text-center",
+                        "{err_msg}"
+                    }
+                }
+            }
+        }
+    }
+}
+
 fn solana_pay_sol_request(pubkey: Pubkey, amount: f64) -> String {
     format!(
         "solana:{}?amount={}&label=Ore&message=Topping%20up%20Ore%20miner",
@@ -2,12 +2,14 @@ use dioxus::prelude::*;
 
 use crate::{
     components::MinerToolbar,
-    hooks::use_miner_toolbar_state_provider,
+    hooks::{use_hashrate_provider, use_miner_toolbar_state_provider, use_mining_scheduler},
     Route,
 };
 
 pub fn MinerToolbarLayout() -> Element {
     use_miner_toolbar_state_provider();
+    use_hashrate_provider();
+    use_mining_scheduler();
     let route = use_route::<Route>();
     let hidden = !matches!(
         route,
@@ -18,16 +18,35 @@ use dioxus::prelude::*;
 
 use crate::{
     hooks::{
-    use_miner, use_miner_toolbar_state, MinerStatus, ReadMinerToolbarState,
+    use_miner, use_miner_toolbar_state, use_session, MinerStatus, ReadMinerToolbarState,
+    UpdateMinerToolbarState,
     },
     route::Route,
 };
 
 #[component]
 pub fn MinerToolbar(hidden: bool) -> Element {
-    let toolbar_state = use_miner_toolbar_state();
+    let mut toolbar_state = use_miner_toolbar_state();
     let miner = use_miner();
     let nav = use_navigator();
+    let session = use_session();
+    let mut miner_pubkey = use_signal(|| session.peek().pubkey());
+
+    // Stop mining when switching to another account
+    use_effect(move || {
+        let pubkey = session.read().pubkey();
+        if miner_pubkey.peek().ne(&pubkey) {
+            miner_pubkey.set(pubkey);
+            toolbar_state.pause();
+        }
+    });
+
+    // Abandon the running batch once mining is paused
+    use_effect(move || {
+        if let MinerStatus::NotStarted | MinerStatus::Error = toolbar_state.status() {
+            miner.peek().stop();
+        }
+    });
 
     let class =
         "fixed transition-height transition-colors flex flex-row justify-between inset-x-0 bottom-0 drop-shadow-md";
@@ -2,7 +2,7 @@ use dioxus::prelude::*;
 
 use crate::{
     components::PlayIcon,
-    hooks::{use_miner_toolbar_state, UpdateMinerToolbarState},
+    hooks::{use_miner_toolbar_state, use_running_benchmark, UpdateMinerToolbarState},
 };
 
 pub fn MinerToolbarNotStarted() -> Element {
@@ -19,9 +19,12 @@ pub fn MinerToolbarNotStarted() -> Element {
 
 pub fn StartButton() -> Element {
     let mut toolbar_state = use_miner_toolbar_state();
+    let is_benchmarking = use_running_benchmark().read().0.is_some();
     rsx! {
         button {
             class: "transition transition-colors flex flex-row gap-2 w-full h-10 px-4 justify-center rounded-full bg-green-500 text-white hover:bg-green-600 active:bg-green-700 text-nowrap disabled:opacity-50",
+            disabled: is_benchmarking,
+            title: if is_benchmarking { "Wait for the benchmark to finish" } else { "" },
             onclick: move |e| {
                 toolbar_state.start();
                 e.stop_propagation();
@@ -16,6 +16,16 @@ pub async fn try_start_mining(
     miner: Signal<Miner>,
     toolbar_state: &mut Signal<MinerToolbarState>,
 ) -> GatewayResult<()> {
+    // Pool members hash the pool's challenge and need no proof of their own
+    let pool = miner.read().pool();
+    if let Some(pool) = pool {
+        toolbar_state.set_status_message(MinerStatusMessage::Searching);
+        return miner
+            .read()
+            .start_pool_mining(&pool, signer().pubkey())
+            .await;
+    }
+
     loop {
         if gateway.open_ore().await.is_ok() {
             break;
@@ -28,15 +38,11 @@ pub async fn try_start_mining(
     let clock = gateway.get_clock().await?;
     // if let Ok(proof) = gateway.get_proof(signer.pubkey()).await {
     //     if let Ok(clock) = gateway.get_clock().await {
-    let cutoff_time = proof
-        .last_hash_at
-        .saturating_add(60)
-        .saturating_sub(clock.unix_timestamp)
-        .max(0) as u64;
+    let cutoff_time = miner.read().cutoff_time(&proof, &clock);
     toolbar_state.set_status_message(MinerStatusMessage::Searching);
     miner
         .read()
-        .start_mining(proof.challenge.into(), 0, cutoff_time)
+        .start_mining(proof.challenge.into(), cutoff_time)
         .await;
     //     } else {
     //         log::error!("Failed to get clock");
@@ -0,0 +1,204 @@
+use chrono::{Local, TimeZone};
+use dioxus::prelude::*;
+use solana_extra_wasm::program::spl_token::amount_to_ui_amount;
+
+use crate::{
+    history::{self, SubmissionRecord},
+    hooks::{
+        use_explorer_transaction_url, use_miner_toolbar_state, use_pubkey, ReadMinerToolbarState,
+    },
+    utils,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum HistoryFilter {
+    All,
+    Landed,
+    Failed,
+}
+
+pub fn MiningHistory() -> Element {
+    let pubkey = use_pubkey();
+    let toolbar_state = use_miner_toolbar_state();
+    let mut filter = use_signal(|| HistoryFilter::All);
+    let mut min_difficulty = use_signal(|| 0u32);
+
+    // Reload whenever the miner changes status, which is when submissions are recorded
+    let records = use_resource(move || async move {
+        let _ = toolbar_state.status_message();
+        history::load(&pubkey.to_string()).await
+    });
+
+    let min = *min_difficulty.read();
+    let records: Vec<SubmissionRecord> = records
+        .read()
+        .clone()
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|r| match *filter.read() {
+            HistoryFilter::All => true,
+            HistoryFilter::Landed => r.landed(),
+            HistoryFilter::Failed => !r.landed(),
+        })
+        .filter(|r| r.difficulty.ge(&min))
+        .collect();
+    let landed = records.iter().filter(|r| r.landed()).count();
+    let total_reward: u64 = records.iter().map(|r| r.reward).sum();
+    let total_tip: u64 = records.iter().map(|r| r.tip).sum();
+    let avg_latency = records
+        .iter()
+        .filter(|r| r.landed())
+        .map(|r| r.latency_ms)
+        .sum::<u64>()
+        .checked_div(landed as u64)
+        .unwrap_or(0);
+
+    let tab_class = move |f: HistoryFilter| {
+        if filter.read().eq(&f) {
+            "font-semibold dark:text-white"
+        } else {
+            "text-gray-300 hover:text-white transition-colors"
+        }
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-4",
+            div {
+                class: "flex flex-row gap-8 justify-between",
+                p {
+                    class: "text-gray-300 font-medium text-sm my-auto",
+                    "History"
+                }
+                div {
+                    class: "flex flex-row gap-4 text-sm",
+                    button {
+                        class: "text-gray-300 hover:text-white transition-colors",
+                        onclick: {
+                            let records = records.clone();
+                            move |_| utils::download_file("ore-history.csv", &history::to_csv(&records), "text/csv")
+                        },
+                        "Export CSV"
+                    }
+                    button {
+                        class: "text-gray-300 hover:text-white transition-colors",
+                        onclick: {
+                            let records = records.clone();
+                            move |_| {
+                                if let Ok(json) = serde_json::to_string_pretty(&records) {
+                                    utils::download_file("ore-history.json", &json, "application/json");
+                                }
+                            }
+                        },
+                        "Export JSON"
+                    }
+                }
+            }
+            div {
+                class: "flex flex-row gap-4 text-sm",
+                button {
+                    class: tab_class(HistoryFilter::All),
+                    onclick: move |_| filter.set(HistoryFilter::All),
+                    "All"
+                }
+                button {
+                    class: tab_class(HistoryFilter::Landed),
+                    onclick: move |_| filter.set(HistoryFilter::Landed),
+                    "Landed"
+                }
+                button {
+                    class: tab_class(HistoryFilter::Failed),
+                    onclick: move |_| filter.set(HistoryFilter::Failed),
+                    "Failed"
+                }
+                div {
+                    class: "flex flex-row gap-1 ml-auto",
+                    p {
+                        class: "text-gray-300 my-auto",
+                        "Min difficulty"
+                    }
+                    input {
+                        class: "bg-transparent dark:text-white text-right px-1 w-12 rounded font-semibold hover:bg-green-600 transition-colors",
+                        r#type: "number",
+                        min: 0,
+                        value: "{min_difficulty}",
+                        oninput: move |e| {
+                            if let Ok(v) = e.value().parse::<u32>() {
+                                min_difficulty.set(v);
+                            }
+                        }
+                    }
+                }
+            }
+            div {
+                class: "flex flex-row gap-8 justify-between text-sm",
+                p { "{records.len()} submissions, {landed} landed" }
+                p {
+                    class: "font-semibold",
+                    "{amount_to_ui_amount(total_reward, ore_api::consts::TOKEN_DECIMALS)} ORE"
+                }
+            }
+            div {
+                class: "flex flex-row gap-8 justify-between text-sm text-gray-300",
+                p { "Tips {amount_to_ui_amount(total_tip, 9)} SOL" }
+                p { "Avg latency {avg_latency / 1000}s" }
+            }
+            div {
+                class: "flex flex-col gap-2",
+                for record in records.iter().take(100) {
+                    HistoryRow {
+                        key: "{record.timestamp}-{record.signature}",
+                        record: record.clone()
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn HistoryRow(record: SubmissionRecord) -> Element {
+    let url = use_explorer_transaction_url(record.signature.clone());
+    let time = Local
+        .timestamp_opt(record.timestamp, 0)
+        .single()
+        .map(|t| t.format("%b %d, %H:%M:%S").to_string())
+        .unwrap_or_default();
+
+    rsx! {
+        div {
+            class: "flex flex-row gap-4 justify-between text-sm",
+            div {
+                class: "flex flex-col",
+                p {
+                    class: "font-semibold",
+                    "{time}"
+                }
+                p {
+                    class: "text-xs text-gray-300",
+                    "Difficulty {record.difficulty}, bus {record.bus}, {record.attempts} attempts, fee {record.priority_fee}"
+                }
+            }
+            if let Some(err) = record.error.clone() {
+                p {
+                    class: "text-red-500 my-auto truncate",
+                    "{err}"
+                }
+            } else {
+                div {
+                    class: "flex flex-col text-right",
+                    p {
+                        class: "font-semibold",
+                        "+{amount_to_ui_amount(record.reward, ore_api::consts::TOKEN_DECIMALS)} ORE"
+                    }
+                    a {
+                        class: "text-xs text-gray-300 hover:text-white font-mono",
+                        href: "{url}",
+                        target: "_blank",
+                        "{record.latency_ms / 1000}s"
+                    }
+                }
+            }
+        }
+    }
+}
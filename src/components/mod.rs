@@ -2,70 +2,94 @@
 // mod activity_indicator;
 mod appearance;
 mod back_button;
+mod backup;
 mod backup_keypair_warning;
 mod balance;
 mod banner;
 mod claim;
 mod code_block;
 mod copyable;
+mod dashboard;
 mod download;
 mod export_key;
 mod footer;
 mod home;
 mod icons;
 mod import_key;
+mod import_mnemonic;
+mod import_qr;
 mod landing;
 mod mine;
 mod miner_toolbar;
+mod mining_history;
 mod navbar;
+mod new_key;
 mod ore_tokenomics;
 mod page_not_found;
+mod passphrase;
 mod pay;
-mod priority_fee_strategy;
+mod power_benchmark;
+mod reauthenticate;
 mod send;
 mod settings;
+mod sign_message;
 mod spinner;
 mod stake;
 mod stop_button;
 // mod tutorial;
 // mod tx;
+mod unlock;
 mod upgrade;
 mod user;
 // mod user_bubble;
+mod vanity;
+mod wallet_adapter;
 mod what_is_mining;
 
 // pub use activity::*;
 // pub use activity_indicator::*;
 pub use appearance::*;
 pub use back_button::*;
+pub use backup::*;
 pub use backup_keypair_warning::*;
 pub use balance::*;
 pub use banner::*;
 pub use claim::*;
 pub use code_block::*;
 pub use copyable::*;
+pub use dashboard::*;
 pub use download::*;
 pub use export_key::*;
 pub use footer::*;
 pub use home::*;
 pub use icons::*;
 pub use import_key::*;
+pub use import_mnemonic::*;
+pub use import_qr::*;
 pub use landing::*;
 pub use mine::*;
 pub use miner_toolbar::*;
+pub use mining_history::*;
 pub use navbar::*;
+pub use new_key::*;
 pub use ore_tokenomics::*;
 pub use page_not_found::*;
+pub use passphrase::*;
 pub use pay::*;
-pub use priority_fee_strategy::*;
+pub use power_benchmark::*;
+pub use reauthenticate::*;
 pub use send::*;
 pub use settings::*;
+pub use sign_message::*;
 pub use spinner::*;
 pub use stake::*;
 pub use stop_button::*;
 // pub use tutorial::*;
 // pub use tx::*;
+pub use unlock::*;
 pub use upgrade::*;
 pub use user::*;
 // pub use user_bubble::*;
+pub use vanity::*;
+pub use wallet_adapter::*;
 pub use what_is_mining::*;
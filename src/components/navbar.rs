@@ -2,7 +2,8 @@ use dioxus::prelude::*;
 
 use crate::{
     components::{Appearance, Banner, BannerStyle, CogIcon, Footer, OreLogoIcon, OreWordmarkIcon},
-    hooks::use_appearance,
+    hooks::{use_appearance, use_profiles, use_session, SessionStatus},
+    keystore,
     route::Route,
 };
 
@@ -33,6 +34,12 @@ pub fn Navbar() -> Element {
                     }
                     div {
                         class: "flex flex-row gap-4",
+                        ProfileSwitcher {}
+                        Link {
+                            to: Route::Dashboard {},
+                            class: "my-auto font-semibold text-sm sm:text-base hover-100 active-200 transition-colors rounded px-2 py-1",
+                            "Dashboard"
+                        }
                         SettingsButton {}
                     }
                 }
@@ -62,6 +69,37 @@ pub fn SettingsButton() -> Element {
     }
 }
 
+pub fn ProfileSwitcher() -> Element {
+    let mut session = use_session();
+    let profiles = use_profiles();
+    let active_id = keystore::active_profile_id();
+
+    // Nothing to switch between until a second account is added
+    if profiles.read().len().le(&1) {
+        return rsx! {};
+    }
+
+    rsx! {
+        select {
+            class: "my-auto max-w-40 truncate bg-transparent dark:text-white font-semibold hover:cursor-pointer hover-100 active-200 transition-colors rounded px-2 py-1",
+            onchange: move |e| {
+                match keystore::switch_profile(&e.value()) {
+                    Ok(pubkey) => session.set(SessionStatus::Unlocked(pubkey)),
+                    Err(err) => log::error!("Failed to switch profile: {:?}", err),
+                }
+            },
+            for profile in profiles.read().iter() {
+                option {
+                    key: "{profile.id}",
+                    selected: profile.id.eq(&active_id),
+                    value: "{profile.id}",
+                    "{profile.name}"
+                }
+            }
+        }
+    }
+}
+
 pub fn SimpleNavbar() -> Element {
     rsx! {
         div {
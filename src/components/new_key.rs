@@ -0,0 +1,124 @@
+use dioxus::prelude::*;
+
+use crate::{
+    components::BackButton,
+    hooks::{use_session, SessionStatus},
+    keystore::{self, DerivationScheme, WordCount},
+    route::Route,
+};
+
+pub fn NewKey() -> Element {
+    let nav = navigator();
+    let mut session = use_session();
+    let mut word_count = use_signal(|| WordCount::Twelve);
+    let mut phrase = use_signal(|| keystore::generate_mnemonic(WordCount::Twelve));
+    let mut is_acknowledged = use_signal(|| false);
+    let mut err_msg = use_signal::<Option<String>>(|| None);
+
+    let words: Vec<String> = phrase.read().split(' ').map(String::from).collect();
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-16 grow w-full h-full",
+            div {
+                class: "flex flex-col gap-4 -mt-3.5",
+                BackButton {
+                    onclick: move |_| {
+                        nav.go_back()
+                    }
+                }
+                div {
+                    class: "flex flex-col gap-3",
+                    h2 {
+                        "New key"
+                    }
+                    p {
+                        class: "text-lg",
+                        "Create a new account from a recovery phrase."
+                    }
+                    p {
+                        class: "text-sm text-gray-300 dark:text-gray-700",
+                        "Write these words down in order and keep them somewhere safe. "
+                        "They restore this key here or in any wallet that uses the path m/44'/501'/0'/0'."
+                    }
+                    Link {
+                        to: Route::Vanity {},
+                        class: "font-semibold hover-100 active-200 transition-colors px-4 py-1 rounded -ml-4 mr-auto",
+                        "Generate a vanity address instead"
+                    }
+                }
+            }
+            div {
+                class: "flex flex-col gap-4",
+                div {
+                    class: "flex flex-row gap-8 justify-between w-full",
+                    p {
+                        class: "font-medium text-sm opacity-50 my-auto",
+                        "Words"
+                    }
+                    select {
+                        class: "text-right bg-transparent dark:text-white hover:cursor-pointer py-1",
+                        onchange: move |e| {
+                            let count = if e.value().eq("24") { WordCount::TwentyFour } else { WordCount::Twelve };
+                            word_count.set(count);
+                            phrase.set(keystore::generate_mnemonic(count));
+                            is_acknowledged.set(false);
+                        },
+                        option { initial_selected: word_count.read().eq(&WordCount::Twelve), value: "12", "12" }
+                        option { initial_selected: word_count.read().eq(&WordCount::TwentyFour), value: "24", "24" }
+                    }
+                }
+                div {
+                    class: "grid grid-cols-3 gap-2 font-mono",
+                    for (i, word) in words.iter().enumerate() {
+                        p {
+                            class: "px-2 py-1 rounded bg-gray-100 dark:bg-gray-900",
+                            span {
+                                class: "opacity-50 mr-2",
+                                "{i + 1}."
+                            }
+                            "{word}"
+                        }
+                    }
+                }
+                label {
+                    class: "flex flex-row gap-2 mx-auto text-sm hover:cursor-pointer",
+                    input {
+                        r#type: "checkbox",
+                        checked: *is_acknowledged.read(),
+                        onchange: move |e| is_acknowledged.set(e.checked()),
+                    }
+                    "I have written down my recovery phrase"
+                }
+                if let Some(err_msg) = err_msg.read().clone() {
+                    p {
+                        class: "text-red-500 text-sm text-center",
+                        "{err_msg}"
+                    }
+                }
+            }
+            button {
+                class: "bg-green-500 disabled:opacity-50 hover:bg-green-600 active:bg-green-700 transition-colors text-white rounded text-center font-semibold py-3 mt-auto",
+                disabled: !*is_acknowledged.read(),
+                onclick: move |_| {
+                    let result = keystore::mnemonic_seed(&phrase.read(), "")
+                        .and_then(|seed| {
+                            keystore::keypair_from_mnemonic_seed(&seed, DerivationScheme::Bip44Change, 0)
+                        })
+                        .and_then(keystore::add_profile);
+                    match result {
+                        Ok(pubkey) => {
+                            session.set(SessionStatus::Unlocked(pubkey));
+                            nav.push(Route::Settings {});
+                        }
+                        Err(err) => {
+                            log::error!("Failed to create keypair: {:?}", err);
+                            err_msg.set(Some("Failed to save keypair".to_string()));
+                        }
+                    }
+                },
+                "Use this key"
+            }
+        }
+    }
+}
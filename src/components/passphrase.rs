@@ -0,0 +1,127 @@
+use dioxus::prelude::*;
+
+use crate::{
+    components::{BackButton, Spinner},
+    keystore::{self, KeystoreError},
+    route::Route,
+};
+
+pub const MIN_PASSPHRASE_LENGTH: usize = 8;
+
+pub fn SetPassphrase() -> Element {
+    let nav = navigator();
+    let is_encrypted = use_signal(keystore::is_encrypted);
+    let mut current_input = use_signal(|| "".to_string());
+    let mut passphrase_input = use_signal(|| "".to_string());
+    let mut confirm_input = use_signal(|| "".to_string());
+    let mut err_msg = use_signal::<Option<String>>(|| None);
+    let mut is_busy = use_signal(|| false);
+
+    let error_text = if passphrase_input.read().is_empty() {
+        None
+    } else if passphrase_input.read().len().lt(&MIN_PASSPHRASE_LENGTH) {
+        Some(format!("Use at least {} characters", MIN_PASSPHRASE_LENGTH))
+    } else if !confirm_input.read().is_empty() && passphrase_input.read().ne(&*confirm_input.read()) {
+        Some("Passphrases do not match".to_string())
+    } else {
+        None
+    };
+    let is_disabled = *is_busy.read()
+        || error_text.is_some()
+        || passphrase_input.read().ne(&*confirm_input.read())
+        || passphrase_input.read().is_empty()
+        || (*is_encrypted.read() && current_input.read().is_empty());
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-16 grow w-full h-full",
+            div {
+                class: "flex flex-col gap-4 -mt-3.5",
+                BackButton {
+                    onclick: move |_| {
+                        nav.go_back()
+                    }
+                }
+                div {
+                    class: "flex flex-col gap-3",
+                    h2 {
+                        if *is_encrypted.read() { "Change passphrase" } else { "Set passphrase" }
+                    }
+                    p {
+                        class: "text-lg",
+                        "Encrypt the keypair stored in your browser with a passphrase."
+                    }
+                    p {
+                        class: "text-sm text-gray-300 dark:text-gray-700",
+                        "You will need it every time you open the app and to export your key. "
+                        "A forgotten passphrase cannot be recovered, so back up your keypair first."
+                    }
+                }
+            }
+            div {
+                class: "flex flex-col gap-4",
+                if *is_encrypted.read() {
+                    input {
+                        class: "mx-auto w-full py-2 text-center placeholder-gray-200 dark:placeholder-gray-700 bg-transparent",
+                        r#type: "password",
+                        placeholder: "Current passphrase",
+                        value: "{current_input}",
+                        oninput: move |e| current_input.set(e.value()),
+                    }
+                }
+                input {
+                    class: "mx-auto w-full py-2 text-center placeholder-gray-200 dark:placeholder-gray-700 bg-transparent",
+                    r#type: "password",
+                    placeholder: "New passphrase",
+                    value: "{passphrase_input}",
+                    oninput: move |e| passphrase_input.set(e.value()),
+                }
+                input {
+                    class: "mx-auto w-full py-2 text-center placeholder-gray-200 dark:placeholder-gray-700 bg-transparent",
+                    r#type: "password",
+                    placeholder: "Confirm passphrase",
+                    value: "{confirm_input}",
+                    oninput: move |e| confirm_input.set(e.value()),
+                }
+                if let Some(error_text) = error_text.or(err_msg.read().clone()) {
+                    p {
+                        class: "text-red-500 text-sm text-center",
+                        "{error_text}"
+                    }
+                }
+            }
+            button {
+                class: "bg-green-500 disabled:opacity-50 hover:bg-green-600 active:bg-green-700 transition-colors text-white rounded text-center font-semibold py-3 mt-auto",
+                disabled: is_disabled,
+                onclick: move |_| {
+                    is_busy.set(true);
+                    err_msg.set(None);
+                    spawn(async move {
+                        async_std::task::sleep(std::time::Duration::from_millis(50)).await;
+                        let current = current_input.read().clone();
+                        match keystore::set_passphrase(Some(&current), &passphrase_input.read()) {
+                            Ok(()) => {
+                                nav.push(Route::Settings {});
+                            }
+                            Err(KeystoreError::InvalidPassphrase) => {
+                                err_msg.set(Some("Incorrect current passphrase".to_string()));
+                            }
+                            Err(err) => {
+                                log::error!("Failed to set passphrase: {:?}", err);
+                                err_msg.set(Some("Failed to encrypt keypair".to_string()));
+                            }
+                        }
+                        is_busy.set(false);
+                    });
+                },
+                if *is_busy.read() {
+                    Spinner {
+                        class: "mx-auto"
+                    }
+                } else {
+                    "Save"
+                }
+            }
+        }
+    }
+}
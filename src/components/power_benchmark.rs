@@ -0,0 +1,203 @@
+use chrono::{Local, TimeZone};
+use dioxus::prelude::*;
+use dioxus_sdk::utils::channel::use_channel;
+
+use crate::{
+    hooks::{
+        format_hashrate, use_benchmarks, use_miner_toolbar_state, use_power_level,
+        use_running_benchmark, MinerStatus, PowerLevel, ReadMinerToolbarState, RunningBenchmark,
+    },
+    miner::{
+        benchmark_step, hash_backend, BenchmarkRun, WebWorkerResponse, BENCHMARK_SECS,
+        WEB_WORKERS,
+    },
+    utils,
+};
+
+pub fn PowerBenchmark() -> Element {
+    let mut benchmarks = use_benchmarks();
+    let mut power_level = use_power_level();
+    let toolbar_state = use_miner_toolbar_state();
+    let is_mining = matches!(
+        toolbar_state.status(),
+        MinerStatus::Active | MinerStatus::Activating
+    );
+    let cx = use_channel::<WebWorkerResponse>(*WEB_WORKERS);
+    let mut running = use_running_benchmark();
+    let is_running = running.read().0.is_some();
+    let run = running
+        .read()
+        .0
+        .clone()
+        .or_else(|| benchmarks.read().0.first().cloned());
+    let recommended = run.as_ref().and_then(|run| run.recommended());
+    let best_hashrate = run.as_ref().map(|run| run.best_hashrate()).unwrap_or(0).max(1);
+    let max = *WEB_WORKERS;
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-4",
+            div {
+                class: "flex flex-row gap-8 justify-between",
+                div {
+                    class: "flex flex-col gap-1",
+                    p {
+                        class: "text-gray-300 font-medium text-sm my-auto",
+                        "Benchmark"
+                    }
+                    p {
+                        class: "text-gray-300 text-xs opacity-80 max-w-96",
+                        "Hashes for {BENCHMARK_SECS} seconds at each core count to find where more cores stop helping, for example because of hyperthreads or browser throttling."
+                    }
+                }
+                if is_running {
+                    p {
+                        class: "text-sm font-semibold my-auto shrink-0",
+                        "Testing {run.as_ref().map(|run| run.steps.len() + 1).unwrap_or(1)} of {max}"
+                    }
+                } else {
+                    button {
+                        class: "bg-green-500 hover:bg-green-600 active:bg-green-700 text-white rounded mb-auto transition-colors px-2 py-1 text-sm shrink-0 disabled:opacity-50",
+                        disabled: is_mining,
+                        title: if is_mining { "Stop mining to run a benchmark" } else { "" },
+                        onclick: move |_| {
+                            let cx = cx.clone();
+                            // Finish the run even if the page is left, so mining is not blocked
+                            spawn_forever(async move {
+                                let backend = hash_backend(cx, 1);
+                                let mut run = BenchmarkRun::new();
+                                running.set(RunningBenchmark(Some(run.clone())));
+                                for workers in 1..=max {
+                                    run.steps.push(benchmark_step(backend.as_ref(), workers).await);
+                                    running.set(RunningBenchmark(Some(run.clone())));
+                                }
+                                backend.resize(0);
+                                benchmarks.write().push(run);
+                                running.set(RunningBenchmark(None));
+                            });
+                        },
+                        "Run"
+                    }
+                }
+            }
+            if let Some(run) = run.clone() {
+                div {
+                    class: "flex flex-col gap-1",
+                    for step in run.steps.iter() {
+                        div {
+                            key: "{step.workers}",
+                            class: "flex flex-row gap-2 text-sm",
+                            p {
+                                class: if recommended.eq(&Some(step.workers)) { "font-mono w-8 shrink-0 text-right font-semibold dark:text-white" } else { "font-mono w-8 shrink-0 text-right text-gray-300" },
+                                "{step.workers}"
+                            }
+                            div {
+                                class: "flex flex-row grow my-auto",
+                                div {
+                                    class: "h-2 rounded bg-green-500",
+                                    style: "width: {step.hashrate * 100 / best_hashrate}%",
+                                }
+                            }
+                            p {
+                                class: "font-mono w-24 shrink-0 text-right",
+                                "{format_hashrate(step.hashrate)}"
+                            }
+                            p {
+                                class: "font-mono w-8 shrink-0 text-right text-gray-300",
+                                "{step.best_difficulty}"
+                            }
+                        }
+                    }
+                }
+            }
+            if let (Some(recommended), false) = (recommended, is_running) {
+                div {
+                    class: "flex flex-row gap-8 justify-between",
+                    p {
+                        class: "text-sm my-auto",
+                        "Recommended power: {recommended} of {max} cores"
+                    }
+                    if power_level.read().0.ne(&(recommended as u64)) {
+                        button {
+                            class: "bg-green-500 hover:bg-green-600 active:bg-green-700 text-white rounded transition-colors px-2 py-1 text-sm",
+                            onclick: move |_| power_level.set(PowerLevel(recommended as u64)),
+                            "Apply"
+                        }
+                    }
+                }
+            }
+            if benchmarks.read().0.len().gt(&1) {
+                BenchmarkHistory {}
+            }
+        }
+    }
+}
+
+/// Earlier runs, to compare machines and browsers.
+fn BenchmarkHistory() -> Element {
+    let mut benchmarks = use_benchmarks();
+    let runs = benchmarks.read().0.clone();
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2",
+            div {
+                class: "flex flex-row gap-8 justify-between",
+                p {
+                    class: "text-gray-300 font-medium text-sm my-auto",
+                    "Past benchmarks"
+                }
+                div {
+                    class: "flex flex-row gap-4 text-sm",
+                    button {
+                        class: "text-gray-300 hover:text-white transition-colors",
+                        onclick: {
+                            let runs = runs.clone();
+                            move |_| {
+                                if let Ok(json) = serde_json::to_string_pretty(&runs) {
+                                    utils::download_file("ore-benchmarks.json", &json, "application/json");
+                                }
+                            }
+                        },
+                        "Export JSON"
+                    }
+                    button {
+                        class: "text-gray-300 hover:text-white transition-colors",
+                        onclick: move |_| benchmarks.write().0.clear(),
+                        "Clear"
+                    }
+                }
+            }
+            for run in runs.iter() {
+                div {
+                    key: "{run.timestamp}",
+                    class: "flex flex-row gap-4 justify-between text-sm",
+                    div {
+                        class: "flex flex-col min-w-0",
+                        p {
+                            class: "font-semibold",
+                            {Local.timestamp_opt(run.timestamp, 0).single().map(|t| t.format("%b %d, %H:%M").to_string()).unwrap_or_default()}
+                        }
+                        p {
+                            class: "text-xs text-gray-300 truncate",
+                            "{run.cores} cores, {run.machine}"
+                        }
+                    }
+                    div {
+                        class: "flex flex-col text-right shrink-0",
+                        p {
+                            class: "font-mono",
+                            "{format_hashrate(run.best_hashrate())}"
+                        }
+                        if let Some(recommended) = run.recommended() {
+                            p {
+                                class: "text-xs text-gray-300",
+                                "best at {recommended} cores"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use dioxus::prelude::*;
+
+use crate::{
+    components::{LockOpenIcon, Spinner},
+    hooks::{use_idle_lock, IdleLock},
+    keystore::{self, KeystoreError},
+    route::Route,
+};
+
+/// Layout for pages that expose the key or move funds. After the auto-lock idle period,
+/// they ask for the passphrase again before rendering.
+pub fn Reauthenticate() -> Element {
+    let idle_lock = use_idle_lock();
+    match *idle_lock.read() {
+        IdleLock::Unlocked => rsx! { Outlet::<Route> {} },
+        IdleLock::Locked => rsx! { ReauthenticatePrompt {} },
+    }
+}
+
+fn ReauthenticatePrompt() -> Element {
+    let mut idle_lock = use_idle_lock();
+    let is_encrypted = use_signal(keystore::is_encrypted);
+    let mut passphrase = use_signal(|| "".to_string());
+    let mut err_msg = use_signal::<Option<String>>(|| None);
+    let mut is_busy = use_signal(|| false);
+
+    let mut submit = move || {
+        if !*is_encrypted.read() {
+            idle_lock.set(IdleLock::Unlocked);
+            return;
+        }
+        is_busy.set(true);
+        err_msg.set(None);
+        spawn(async move {
+            async_std::task::sleep(std::time::Duration::from_millis(50)).await;
+            match keystore::verify_passphrase(&passphrase.read()) {
+                Ok(()) => idle_lock.set(IdleLock::Unlocked),
+                Err(KeystoreError::InvalidPassphrase) => {
+                    err_msg.set(Some("Incorrect passphrase".to_string()));
+                }
+                Err(err) => {
+                    log::error!("Failed to verify passphrase: {:?}", err);
+                    err_msg.set(Some("Failed to read keypair from storage".to_string()));
+                }
+            }
+            is_busy.set(false);
+        });
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-16 grow w-full h-full",
+            div {
+                class: "flex flex-col gap-3",
+                h2 {
+                    "Locked"
+                }
+                p {
+                    class: "text-lg",
+                    "This page was locked after a period of inactivity. Mining continues in the background."
+                }
+                if !*is_encrypted.read() {
+                    p {
+                        class: "text-sm text-gray-300 dark:text-gray-700",
+                        "Set a passphrase in settings to require it here."
+                    }
+                }
+            }
+            LockOpenIcon {
+                class: "w-12 h-12 mx-auto opacity-50"
+            }
+            if *is_encrypted.read() {
+                div {
+                    class: "flex flex-col gap-2",
+                    input {
+                        class: "mx-auto w-full py-2 text-center placeholder-gray-200 dark:placeholder-gray-700 bg-transparent",
+                        autofocus: true,
+                        r#type: "password",
+                        placeholder: "Passphrase",
+                        value: "{passphrase}",
+                        oninput: move |e| passphrase.set(e.value()),
+                        onkeydown: move |e| {
+                            if e.key() == Key::Enter && !*is_busy.read() {
+                                submit();
+                            }
+                        },
+                    }
+                    if let Some(err_msg) = err_msg.read().clone() {
+                        p {
+                            class: "text-red-500 text-sm text-center",
+                            "{err_msg}"
+                        }
+                    }
+                }
+            }
+            button {
+                class: "bg-green-500 disabled:opacity-50 hover:bg-green-600 active:bg-green-700 transition-colors text-white rounded text-center font-semibold py-3 mt-auto",
+                disabled: *is_busy.read() || (*is_encrypted.read() && passphrase.read().is_empty()),
+                onclick: move |_| submit(),
+                if *is_busy.read() {
+                    Spinner {
+                        class: "mx-auto"
+                    }
+                } else {
+                    "Unlock"
+                }
+            }
+        }
+    }
+}
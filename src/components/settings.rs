@@ -7,9 +7,10 @@ use solana_client_wasm::solana_sdk::native_token::lamports_to_sol;
 use crate::{
     components::{Appearance, BackupKeypairWarning, Copyable},
     gateway::{FEE_URL, PRIORITY_FEE_CAP, RPC_URL},
+    keystore,
     hooks::{
-        use_appearance, use_explorer, use_pubkey, use_fee_url, use_rpc_url, use_priority_fee_cap,
-        use_show_backup_warning, use_sol_balance, Explorer, FeeUrl, PriorityFeeCap, RpcUrl,
+        use_appearance, use_auto_lock, use_explorer, use_pubkey, use_fee_url, use_profiles, use_rpc_url, use_priority_fee_cap,
+        use_show_backup_warning, use_sol_balance, AutoLock, Explorer, FeeUrl, PriorityFeeCap, RpcUrl, AUTO_LOCK_OPTIONS,
     },
     route::Route,
 };
@@ -20,6 +21,12 @@ pub fn Settings() -> Element {
     let show_backup_warning = use_show_backup_warning();
     let pubkey = use_pubkey();
     let sol_balance = use_sol_balance();
+    let is_encrypted = keystore::is_encrypted();
+    let mut profiles = use_profiles();
+    let mut auto_lock = use_auto_lock();
+    let profile_name = keystore::active_profile()
+        .map(|profile| profile.name)
+        .unwrap_or_default();
 
     let mut rpc_url = use_rpc_url();
     let mut rpc_url_input = use_signal(|| rpc_url.read().0.clone());
@@ -60,6 +67,27 @@ pub fn Settings() -> Element {
                     class: "{section_title_class} mt-8",
                     "Account"
                 }
+                div {
+                    class: "{container_class}",
+                    p {
+                        class: "{data_title_class}",
+                        "Name"
+                    }
+                    input {
+                        class: "text-right bg-transparent placeholder-gray-200 dark:placeholder-gray-700 font-medium py-1 sm:px-2 rounded hover-100 transition-colors",
+                        value: "{profile_name}",
+                        onchange: move |e| {
+                            let name = e.value();
+                            if name.trim().is_empty() {
+                                return;
+                            }
+                            match keystore::rename_profile(&keystore::active_profile_id(), &name) {
+                                Ok(()) => profiles.set(keystore::profiles()),
+                                Err(err) => log::error!("Failed to rename profile: {:?}", err),
+                            }
+                        },
+                    }
+                }
                 div {
                     class: "{container_class}",
                     p {
@@ -101,6 +129,11 @@ pub fn Settings() -> Element {
                     }
                     div {
                         class: "flex flex-row gap-2 -mr-2",
+                        Link {
+                            to: Route::NewKey {},
+                            class: "font-semibold hover-100 active-200 transition-colors px-4 py-1 rounded",
+                            "New"
+                        }
                         Link {
                             to: Route::ImportKey {},
                             class: "font-semibold hover-100 active-200 transition-colors px-4 py-1 rounded",
@@ -111,6 +144,80 @@ pub fn Settings() -> Element {
                             class: "font-semibold hover-100 active-200 transition-colors px-4 py-1 rounded",
                             "Export"
                         }
+                        Link {
+                            to: Route::Backup {},
+                            class: "font-semibold hover-100 active-200 transition-colors px-4 py-1 rounded",
+                            "Backup"
+                        }
+                    }
+                }
+                div {
+                    class: "{container_class}",
+                    p {
+                        class: "{data_title_class}",
+                        "Message"
+                    }
+                    div {
+                        class: "flex flex-row gap-2 -mr-2",
+                        Link {
+                            to: Route::SignMessage {},
+                            class: "font-semibold hover-100 active-200 transition-colors px-4 py-1 rounded",
+                            "Sign"
+                        }
+                        Link {
+                            to: Route::VerifyMessage { pubkey: "".to_string() },
+                            class: "font-semibold hover-100 active-200 transition-colors px-4 py-1 rounded",
+                            "Verify"
+                        }
+                    }
+                }
+                div {
+                    class: "{container_class}",
+                    p {
+                        class: "{data_title_class}",
+                        "Passphrase"
+                    }
+                    div {
+                        class: "flex flex-row gap-2 -mr-2",
+                        if is_encrypted {
+                            p {
+                                class: "my-auto text-sm text-green-500",
+                                "Encrypted"
+                            }
+                        }
+                        Link {
+                            to: Route::SetPassphrase {},
+                            class: "font-semibold hover-100 active-200 transition-colors px-4 py-1 rounded",
+                            if is_encrypted { "Change" } else { "Set" }
+                        }
+                    }
+                }
+                div {
+                    class: "{container_class}",
+                    p {
+                        class: "{data_title_class}",
+                        "Auto-lock"
+                    }
+                    select {
+                        class: "text-right bg-transparent dark:text-white hover:cursor-pointer py-1",
+                        onchange: move |e| {
+                            if let Ok(minutes) = e.value().parse::<u64>() {
+                                auto_lock.set(AutoLock(minutes));
+                            }
+                        },
+                        for minutes in AUTO_LOCK_OPTIONS {
+                            option {
+                                initial_selected: auto_lock.read().0.eq(&minutes),
+                                value: "{minutes}",
+                                if minutes.eq(&0) {
+                                    "Never"
+                                } else if minutes.eq(&1) {
+                                    "After 1 minute"
+                                } else {
+                                    "After {minutes} minutes"
+                                }
+                            }
+                        }
                     }
                 }
             }
@@ -0,0 +1,321 @@
+use std::str::FromStr;
+
+use base64::{prelude::BASE64_STANDARD, Engine};
+use dioxus::prelude::*;
+use solana_client_wasm::solana_sdk::{
+    bs58,
+    pubkey::Pubkey,
+    signature::{Signature, SIGNATURE_BYTES},
+    signer::Signer,
+};
+use solana_sdk::offchain_message::OffchainMessage;
+
+use crate::{
+    components::{BackButton, Copyable},
+    hooks::{use_keypair, use_pubkey},
+    route::Route,
+};
+
+#[derive(Clone, Copy, PartialEq)]
+enum SignatureEncoding {
+    Base58,
+    Base64,
+}
+
+pub fn SignMessage() -> Element {
+    let nav = navigator();
+    let keypair = use_keypair();
+    let pubkey = use_pubkey();
+    let mut message_input = use_signal(|| "".to_string());
+    let mut encoding = use_signal(|| SignatureEncoding::Base58);
+    let mut signature = use_signal::<Option<Signature>>(|| None);
+    let payload = offchain_message(&message_input.read());
+
+    // Clear a stale signature when the message changes
+    use_effect(move || {
+        let _ = message_input.read();
+        signature.set(None);
+    });
+
+    let encoded = signature.read().map(|sig| match *encoding.read() {
+        SignatureEncoding::Base58 => sig.to_string(),
+        SignatureEncoding::Base64 => BASE64_STANDARD.encode(sig.as_ref()),
+    });
+    let container_class = "flex flex-row gap-8 justify-between w-full sm:px-1";
+    let data_title_class = "font-medium text-sm opacity-50 my-auto";
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-16 grow w-full h-full",
+            div {
+                class: "flex flex-col gap-4 -mt-3.5",
+                BackButton {
+                    onclick: move |_| {
+                        nav.go_back()
+                    }
+                }
+                div {
+                    class: "flex flex-col gap-3",
+                    h2 {
+                        "Sign message"
+                    }
+                    p {
+                        class: "text-lg",
+                        "Prove you control this miner address by signing a message with its key."
+                    }
+                    p {
+                        class: "text-sm text-gray-300 dark:text-gray-700",
+                        "Only sign messages you have read and understand. Messages are signed in the Solana off-chain format, so a signature cannot be used to move funds."
+                    }
+                }
+            }
+            div {
+                class: "flex flex-col gap-4",
+                div {
+                    class: "{container_class}",
+                    p {
+                        class: "{data_title_class}",
+                        "Address"
+                    }
+                    Copyable {
+                        value: pubkey.to_string(),
+                        p {
+                            class: "font-mono truncate my-auto",
+                            "{pubkey}"
+                        }
+                    }
+                }
+                textarea {
+                    class: "w-full p-2 rounded bg-gray-100 dark:bg-gray-900 placeholder-gray-200 dark:placeholder-gray-700 resize-none",
+                    rows: 5,
+                    placeholder: "Message",
+                    value: "{message_input}",
+                    oninput: move |e| message_input.set(e.value()),
+                }
+                if !message_input.read().is_empty() && payload.is_none() {
+                    p {
+                        class: "text-red-500 text-sm",
+                        "Message is too long"
+                    }
+                }
+                div {
+                    class: "{container_class}",
+                    p {
+                        class: "{data_title_class}",
+                        "Encoding"
+                    }
+                    select {
+                        class: "text-right bg-transparent dark:text-white hover:cursor-pointer py-1",
+                        onchange: move |e| {
+                            if e.value().eq("base64") {
+                                encoding.set(SignatureEncoding::Base64);
+                            } else {
+                                encoding.set(SignatureEncoding::Base58);
+                            }
+                        },
+                        option { initial_selected: encoding.read().eq(&SignatureEncoding::Base58), value: "base58", "Base58" }
+                        option { initial_selected: encoding.read().eq(&SignatureEncoding::Base64), value: "base64", "Base64" }
+                    }
+                }
+                if let Some(encoded) = encoded {
+                    div {
+                        class: "{container_class}",
+                        p {
+                            class: "{data_title_class}",
+                            "Signature"
+                        }
+                        Copyable {
+                            class: "break-all",
+                            value: encoded.clone(),
+                            p {
+                                class: "font-mono text-sm break-all my-auto",
+                                "{encoded}"
+                            }
+                        }
+                    }
+                }
+            }
+            button {
+                class: "bg-green-500 disabled:opacity-50 hover:bg-green-600 active:bg-green-700 transition-colors text-white rounded text-center font-semibold py-3 mt-auto",
+                disabled: payload.is_none(),
+                onclick: move |_| {
+                    if let Some(payload) = offchain_message(&message_input.read()) {
+                        signature.set(Some(keypair.sign_message(&payload)));
+                    }
+                },
+                "Sign"
+            }
+        }
+    }
+}
+
+#[component]
+pub fn VerifyMessage(pubkey: String) -> Element {
+    let nav = navigator();
+    let mut pubkey_input = use_signal(|| pubkey.clone());
+    let mut message_input = use_signal(|| "".to_string());
+    let mut signature_input = use_signal(|| "".to_string());
+
+    let parsed_pubkey = Pubkey::from_str(pubkey_input.read().trim()).ok();
+    let parsed_signature = parse_signature(signature_input.read().trim());
+    let payload = offchain_message(&message_input.read());
+    let result = match (parsed_pubkey, parsed_signature, payload) {
+        (Some(pubkey), Some(signature), Some(payload)) => {
+            Some(signature.verify(pubkey.as_ref(), &payload))
+        }
+        _ => None,
+    };
+    let input_class = "w-full p-2 rounded bg-gray-100 dark:bg-gray-900 placeholder-gray-200 dark:placeholder-gray-700";
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-16 grow w-full h-full",
+            div {
+                class: "flex flex-col gap-4 -mt-3.5",
+                BackButton {
+                    onclick: move |_| {
+                        nav.go_back()
+                    }
+                }
+                div {
+                    class: "flex flex-col gap-3",
+                    h2 {
+                        "Verify message"
+                    }
+                    p {
+                        class: "text-lg",
+                        "Check that a message was signed by the key of an address."
+                    }
+                }
+            }
+            div {
+                class: "flex flex-col gap-4",
+                input {
+                    class: "{input_class} font-mono",
+                    placeholder: "Address",
+                    value: "{pubkey_input}",
+                    oninput: move |e| pubkey_input.set(e.value()),
+                }
+                if !pubkey_input.read().is_empty() && parsed_pubkey.is_none() {
+                    p {
+                        class: "text-red-500 text-sm",
+                        "Invalid address"
+                    }
+                }
+                textarea {
+                    class: "{input_class} resize-none",
+                    rows: 5,
+                    placeholder: "Message",
+                    value: "{message_input}",
+                    oninput: move |e| message_input.set(e.value()),
+                }
+                input {
+                    class: "{input_class} font-mono",
+                    placeholder: "Signature (base58 or base64)",
+                    value: "{signature_input}",
+                    oninput: move |e| signature_input.set(e.value()),
+                }
+                if !signature_input.read().is_empty() && parsed_signature.is_none() {
+                    p {
+                        class: "text-red-500 text-sm",
+                        "Invalid signature"
+                    }
+                }
+            }
+            match result {
+                Some(true) => rsx! {
+                    p {
+                        class: "text-center text-lg font-semibold text-green-500",
+                        "Valid signature"
+                    }
+                    if let Some(pubkey) = parsed_pubkey {
+                        Link {
+                            class: "mx-auto font-mono text-sm hover-100 active-200 transition-colors rounded px-2 py-1 truncate max-w-full",
+                            to: Route::User { id: pubkey.to_string() },
+                            "Signed by {pubkey}"
+                        }
+                    }
+                },
+                Some(false) => rsx! {
+                    p {
+                        class: "text-center text-lg font-semibold text-red-500",
+                        "Signature does not match this address and message"
+                    }
+                },
+                None => rsx! {},
+            }
+        }
+    }
+}
+
+/// Wraps a message in the version 0 Solana off-chain message format, as signed by
+/// `solana sign-offchain-message`. The `0xff` domain prefix means no signed message can be
+/// replayed as a transaction. Returns `None` for empty or too long messages.
+fn offchain_message(message: &str) -> Option<Vec<u8>> {
+    OffchainMessage::new(0, message.as_bytes())
+        .and_then(|message| message.serialize())
+        .ok()
+}
+
+/// Accepts a signature in base58, as printed by Solana tooling, or in base64.
+fn parse_signature(input: &str) -> Option<Signature> {
+    if input.is_empty() {
+        return None;
+    }
+    let bytes = bs58::decode(input)
+        .into_vec()
+        .ok()
+        .filter(|bytes| bytes.len().eq(&SIGNATURE_BYTES))
+        .or_else(|| BASE64_STANDARD.decode(input).ok())?;
+    Signature::try_from(bytes.as_slice()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The payload laid out by hand from the v0 spec: signing domain, header version, message
+    /// format and little-endian length, then the message.
+    fn expected(message: &str, format: u8) -> Vec<u8> {
+        let mut payload = b"\xffsolana offchain".to_vec();
+        payload.extend_from_slice(&[0, format]);
+        payload.extend_from_slice(&(message.len() as u16).to_le_bytes());
+        payload.extend_from_slice(message.as_bytes());
+        payload
+    }
+
+    fn sdk_payload(message: &str) -> Vec<u8> {
+        OffchainMessage::new(0, message.as_bytes())
+            .unwrap()
+            .serialize()
+            .unwrap()
+    }
+
+    #[test]
+    fn short_ascii_is_restricted_ascii() {
+        let message = "I control this address";
+        assert_eq!(offchain_message(message), Some(expected(message, 0)));
+        assert_eq!(offchain_message(message), Some(sdk_payload(message)));
+    }
+
+    #[test]
+    fn long_ascii_is_extended_utf8() {
+        let message = "a".repeat(1213);
+        assert_eq!(offchain_message(&message), Some(expected(&message, 2)));
+        assert_eq!(offchain_message(&message), Some(sdk_payload(&message)));
+    }
+
+    #[test]
+    fn non_ascii_is_limited_utf8() {
+        let message = "Signé ⛏";
+        assert_eq!(offchain_message(message), Some(expected(message, 1)));
+        assert_eq!(offchain_message(message), Some(sdk_payload(message)));
+    }
+
+    #[test]
+    fn rejects_empty_and_too_long() {
+        assert_eq!(offchain_message(""), None);
+        assert!(offchain_message(&"a".repeat(65515)).is_some());
+        assert_eq!(offchain_message(&"a".repeat(65516)), None);
+    }
+}
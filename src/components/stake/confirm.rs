@@ -6,13 +6,13 @@ use solana_extra_wasm::program::spl_token::amount_to_ui_amount;
 use crate::{
     components::{BackButton, OreIcon, Spinner},
     gateway,
-    hooks::{use_gateway, use_ore_balance, use_priority_fee, use_proof, PriorityFee},
+    hooks::{use_gateway, use_ore_balance, use_priority_fee, use_proof, FundingSource, PriorityFee},
 };
 
 use super::StakeStep;
 
 #[component]
-pub fn StakeConfirm(amount: u64, stake_step: Signal<StakeStep>) -> Element {
+pub fn StakeConfirm(amount: u64, source: FundingSource, stake_step: Signal<StakeStep>) -> Element {
     let mut is_busy = use_signal(|| false);
     let mut priority_fee = use_priority_fee();
     let mut balance = use_ore_balance();
@@ -119,7 +119,12 @@ pub fn StakeConfirm(amount: u64, stake_step: Signal<StakeStep>) -> Element {
                                     // }
 
                                     // Stake
-                                    match gateway.stake_ore(amount, priority_fee.read().0).await {
+                                    let priority_fee = priority_fee.read().0;
+                                    let res = match source {
+                                        FundingSource::Miner => gateway.stake_ore(amount, priority_fee).await,
+                                        FundingSource::Wallet(wallet) => gateway.stake_ore_from_wallet(wallet, amount, priority_fee).await,
+                                    };
+                                    match res {
                                         Ok(_sig) => {
                                             balance.restart();
                                             proof.restart();
@@ -1,8 +1,8 @@
 use dioxus::prelude::*;
 
 use crate::{
-    components::{BackButton, WarningIcon},
-    hooks::{use_ore_balance, UiTokenAmountBalance},
+    components::{BackButton, FundingSourceSelector, WarningIcon},
+    hooks::{use_ore_balance, use_wallet_ore_balances, FundingSource, UiTokenAmountBalance},
 };
 
 use super::StakeStep;
@@ -10,17 +10,20 @@ use super::StakeStep;
 #[component]
 pub fn StakeEdit(
     stake_step: Signal<StakeStep>,
+    source: Signal<FundingSource>,
     amount_input: Signal<String>,
     parsed_amount: u64,
 ) -> Element {
     let nav = navigator();
 
     let balance = use_ore_balance();
-    let (max_amount, max_amount_str) = balance
-        .cloned()
-        .and_then(|b| b.ok())
-        .map(|b| (b.balance(), b.ui_amount_string))
-        .unwrap_or_else(|| (0, "0".to_owned()));
+    let wallet_balances = use_wallet_ore_balances();
+    let (max_amount, max_amount_str) = match *source.read() {
+        FundingSource::Miner => balance.cloned().and_then(|b| b.ok()),
+        FundingSource::Wallet(_) => wallet_balances.cloned().flatten().map(|b| b.v2),
+    }
+    .map(|b| (b.balance(), b.ui_amount_string))
+    .unwrap_or_else(|| (0, "0".to_owned()));
 
     let error_text = if parsed_amount.gt(&max_amount) {
         Some("Amount too large".to_string())
@@ -59,6 +62,9 @@ pub fn StakeEdit(
             }
             div {
                 class: "flex flex-col gap-8",
+                FundingSourceSelector {
+                    source
+                }
                 if let Some(error_text) = error_text {
                     p {
                         class: "flex flex-row flex-nowrap gap-2 text-white w-min mx-auto text-nowrap bg-red-500 text-center font-semibold text-sm rounded py-1 px-2",
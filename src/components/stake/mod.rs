@@ -8,6 +8,8 @@ use edit::*;
 
 use dioxus::prelude::*;
 
+use crate::hooks::FundingSource;
+
 pub enum StakeStep {
     Edit,
     Confirm,
@@ -16,6 +18,7 @@ pub enum StakeStep {
 
 pub fn Stake() -> Element {
     let stake_step = use_signal(|| StakeStep::Edit);
+    let source = use_signal(|| FundingSource::Miner);
     let amount_input = use_signal(|| "".to_string());
     let parsed_amount: u64 = match amount_input.read().parse::<f64>() {
         Ok(n) => (n * 10f64.powf(ore_api::consts::TOKEN_DECIMALS.into())) as u64,
@@ -27,6 +30,7 @@ pub fn Stake() -> Element {
             rsx! {
                 StakeEdit {
                     stake_step,
+                    source,
                     amount_input,
                     parsed_amount,
                 }
@@ -36,6 +40,7 @@ pub fn Stake() -> Element {
             rsx! {
                 StakeConfirm {
                     stake_step,
+                    source: *source.read(),
                     amount: parsed_amount,
                 }
             }
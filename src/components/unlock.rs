@@ -0,0 +1,99 @@
+use dioxus::prelude::*;
+
+use crate::{
+    components::{Appearance, LockOpenIcon, OreWordmarkIcon, Spinner},
+    hooks::{use_appearance, use_session, SessionStatus},
+    keystore::{self, KeystoreError},
+};
+
+/// Shown at startup when the keypair is encrypted with a passphrase.
+pub fn Unlock() -> Element {
+    let appearance = use_appearance();
+    let mut session = use_session();
+    let mut passphrase = use_signal(|| "".to_string());
+    let mut err_msg = use_signal::<Option<String>>(|| None);
+    let mut is_busy = use_signal(|| false);
+    let dark = match *appearance.read() {
+        Appearance::Dark => "dark",
+        Appearance::Light => "",
+    };
+
+    let mut submit = move || {
+        is_busy.set(true);
+        err_msg.set(None);
+        spawn(async move {
+            // Yield so the spinner renders before the key derivation blocks the thread
+            async_std::task::sleep(std::time::Duration::from_millis(50)).await;
+            match keystore::unlock(&passphrase.read()) {
+                Ok(pubkey) => session.set(SessionStatus::Unlocked(pubkey)),
+                Err(KeystoreError::InvalidPassphrase) => {
+                    err_msg.set(Some("Incorrect passphrase".to_string()));
+                }
+                Err(err) => {
+                    log::error!("Failed to unlock keystore: {:?}", err);
+                    err_msg.set(Some("Failed to read keypair from storage".to_string()));
+                }
+            }
+            is_busy.set(false);
+        });
+    };
+
+    rsx! {
+        div {
+            class: "{dark}",
+            div {
+                class: "flex flex-col gap-16 min-h-screen w-full max-w-md mx-auto px-4 py-16 text-black dark:bg-black dark:text-white",
+                OreWordmarkIcon {
+                    class: "h-4 mx-auto"
+                }
+                div {
+                    class: "flex flex-col gap-3",
+                    h2 {
+                        "Unlock"
+                    }
+                    p {
+                        class: "text-lg",
+                        "Enter your passphrase to unlock your miner keypair."
+                    }
+                }
+                LockOpenIcon {
+                    class: "w-12 h-12 mx-auto opacity-50"
+                }
+                div {
+                    class: "flex flex-col gap-2",
+                    input {
+                        class: "mx-auto w-full py-2 text-center placeholder-gray-200 dark:placeholder-gray-700 bg-transparent",
+                        autofocus: true,
+                        r#type: "password",
+                        placeholder: "Passphrase",
+                        value: "{passphrase}",
+                        oninput: move |e| passphrase.set(e.value()),
+                        onkeydown: move |e| {
+                            if e.key() == Key::Enter && !*is_busy.read() {
+                                submit();
+                            }
+                        },
+                    }
+                    if let Some(err_msg) = err_msg.read().clone() {
+                        p {
+                            class: "text-red-500 text-sm text-center",
+                            "{err_msg}"
+                        }
+                    }
+                }
+                button {
+                    class: "bg-green-500 disabled:opacity-50 hover:bg-green-600 active:bg-green-700 transition-colors text-white rounded text-center font-semibold py-3",
+                    disabled: *is_busy.read() || passphrase.read().is_empty(),
+                    onclick: move |_| submit(),
+                    if *is_busy.read() {
+                        Spinner {
+                            class: "mx-auto"
+                        }
+                    } else {
+                        "Unlock"
+                    }
+                }
+            }
+        }
+    }
+}
@@ -6,13 +6,13 @@ use solana_extra_wasm::program::spl_token::amount_to_ui_amount;
 use crate::{
     components::{BackButton, OreIcon, Spinner},
     gateway,
-    hooks::{use_gateway, use_ore_balance, use_priority_fee, use_proof, PriorityFee},
+    hooks::{use_gateway, use_ore_balance, use_priority_fee, use_proof, FundingSource, PriorityFee},
 };
 
 use super::UpgradeStep;
 
 #[component]
-pub fn UpgradeConfirm(amount: u64, upgrade_step: Signal<UpgradeStep>) -> Element {
+pub fn UpgradeConfirm(amount: u64, source: FundingSource, upgrade_step: Signal<UpgradeStep>) -> Element {
     let mut is_busy = use_signal(|| false);
     let mut priority_fee = use_priority_fee();
     let mut balance = use_ore_balance();
@@ -127,7 +127,12 @@ pub fn UpgradeConfirm(amount: u64, upgrade_step: Signal<UpgradeStep>) -> Element
                                 async move {
 
                                     // Upgrade
-                                    match gateway.upgrade_ore(amount, priority_fee.read().0).await {
+                                    let priority_fee = priority_fee.read().0;
+                                    let res = match source {
+                                        FundingSource::Miner => gateway.upgrade_ore(amount, priority_fee).await,
+                                        FundingSource::Wallet(wallet) => gateway.upgrade_ore_from_wallet(wallet, amount, priority_fee).await,
+                                    };
+                                    match res {
                                         Ok(sig) => {
                                             balance.restart();
                                             proof.restart();
@@ -1,8 +1,10 @@
 use dioxus::prelude::*;
 
 use crate::{
-    components::{BackButton, WarningIcon},
-    hooks::{use_ore_v1_v2_balances, UiTokenAmountBalance},
+    components::{BackButton, FundingSourceSelector, WarningIcon},
+    hooks::{
+        use_ore_v1_v2_balances, use_wallet_ore_balances, FundingSource, UiTokenAmountBalance,
+    },
 };
 
 use super::UpgradeStep;
@@ -10,14 +12,20 @@ use super::UpgradeStep;
 #[component]
 pub fn UpgradeEdit(
     upgrade_step: Signal<UpgradeStep>,
+    source: Signal<FundingSource>,
     amount_input: Signal<String>,
     parsed_amount: u64,
 ) -> Element {
     let nav = navigator();
 
     let balances_resource = use_ore_v1_v2_balances();
+    let wallet_balances_resource = use_wallet_ore_balances();
 
-    let (max_amount, max_amount_str) = match balances_resource.cloned() {
+    let balances = match *source.read() {
+        FundingSource::Miner => balances_resource.cloned(),
+        FundingSource::Wallet(_) => wallet_balances_resource.cloned(),
+    };
+    let (max_amount, max_amount_str) = match balances {
         Some(balances) => balances
             .map(|b| (b.v1.balance(), b.v1.ui_amount_string))
             .unwrap_or((0, "0".to_owned())),
@@ -66,6 +74,9 @@ pub fn UpgradeEdit(
             }
             div {
                 class: "flex flex-col gap-8",
+                FundingSourceSelector {
+                    source
+                }
                 if let Some(error_text) = error_text {
                     p {
                         class: "flex flex-row flex-nowrap gap-2 text-white w-min mx-auto text-nowrap bg-red-500 text-center font-semibold text-sm rounded py-1 px-2",
@@ -9,6 +9,8 @@ use edit::*;
 use dioxus::prelude::*;
 use solana_client_wasm::solana_sdk::signature::Signature;
 
+use crate::hooks::FundingSource;
+
 pub enum UpgradeStep {
     Edit,
     Confirm,
@@ -18,6 +20,7 @@ pub enum UpgradeStep {
 #[component]
 pub fn Upgrade() -> Element {
     let upgrade_step = use_signal(|| UpgradeStep::Edit);
+    let source = use_signal(|| FundingSource::Miner);
     let amount_input = use_signal(|| "".to_string());
     let parsed_amount: u64 = match amount_input.read().parse::<f64>() {
         Ok(n) => (n * 10f64.powf(ore_api::consts::TOKEN_DECIMALS_V1.into())) as u64,
@@ -29,6 +32,7 @@ pub fn Upgrade() -> Element {
             rsx! {
                 UpgradeEdit {
                     upgrade_step,
+                    source,
                     amount_input,
                     parsed_amount,
                 }
@@ -38,6 +42,7 @@ pub fn Upgrade() -> Element {
             rsx! {
                 UpgradeConfirm {
                     amount: parsed_amount,
+                    source: *source.read(),
                     upgrade_step,
                 }
             }
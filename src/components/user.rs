@@ -10,8 +10,10 @@ use crate::{
         BackButton, Copyable, OreIcon, SendButton,
     },
     hooks::{
-        use_explorer_account_url, use_ore_balance_user, use_user_proof,
+        use_explorer_account_url, use_ore_balance_user, use_user_proof, use_watchlist,
+        WatchedAccount,
     },
+    route::Route,
 };
 
 // TODO Not found
@@ -30,6 +32,8 @@ pub fn User(id: String) -> Element {
     }
 
     let user_id = user_id.unwrap();
+    let mut watchlist = use_watchlist();
+    let is_watched = watchlist.read().contains(&user_id);
     let balance = use_ore_balance_user(user_id);
     let explorer_url = use_explorer_account_url(id.clone());
     let proof = use_user_proof(user_id);
@@ -78,6 +82,31 @@ pub fn User(id: String) -> Element {
                     if show_send_button {
                         SendButton { to: id.clone() }
                     }
+                    if title.eq("User") {
+                        div {
+                            class: "flex flex-row gap-2 my-auto",
+                            Link {
+                                to: Route::VerifyMessage { pubkey: id.clone() },
+                                class: "font-semibold hover-100 active-200 transition-colors px-4 py-1 rounded",
+                                "Verify message"
+                            }
+                            button {
+                                class: "font-semibold hover-100 active-200 transition-colors px-4 py-1 rounded",
+                                onclick: move |_| {
+                                    if is_watched {
+                                        watchlist.write().accounts.retain(|a| a.pubkey.ne(&user_id));
+                                    } else {
+                                        watchlist.write().accounts.push(WatchedAccount {
+                                            pubkey: user_id,
+                                            label: user_id.to_string(),
+                                            group: "".to_string(),
+                                        });
+                                    }
+                                },
+                                if is_watched { "Unwatch" } else { "Watch" }
+                            }
+                        }
+                    }
                 }
                 if let Some(description) = description {
                     p {
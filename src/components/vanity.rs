@@ -0,0 +1,332 @@
+use dioxus::prelude::*;
+use dioxus_sdk::utils::channel::use_channel;
+use serde_wasm_bindgen::to_value;
+use solana_client_wasm::solana_sdk::signature::{Keypair, Signer};
+use web_sys::Worker;
+
+use crate::{
+    components::{BackButton, Spinner},
+    hooks::{
+        use_miner_toolbar_state, use_power_level, use_profiles, use_session, MinerStatus,
+        ReadMinerToolbarState, SessionStatus,
+    },
+    keystore,
+    miner::{
+        create_web_worker, expected_vanity_attempts, is_valid_vanity_pattern, VanityRequest,
+        VanityResponse, WorkerMessage, MAX_VANITY_LENGTH, WEB_WORKERS,
+    },
+    route::Route,
+};
+
+/// How long each worker searches before reporting progress.
+const VANITY_BATCH_MS: u64 = 500;
+
+#[derive(Clone, PartialEq)]
+enum VanityStatus {
+    Idle,
+    Searching { attempts: u64, started_at: f64 },
+    Found(Vec<u8>),
+}
+
+pub fn Vanity() -> Element {
+    let nav = navigator();
+    let mut session = use_session();
+    let mut profiles = use_profiles();
+    let toolbar_state = use_miner_toolbar_state();
+    let power_level = use_power_level();
+    let mut prefix = use_signal(|| "".to_string());
+    let mut suffix = use_signal(|| "".to_string());
+    let mut case_sensitive = use_signal(|| true);
+    let mut status = use_signal(|| VanityStatus::Idle);
+    let mut workers = use_signal::<Vec<Worker>>(Vec::new);
+    let mut err_msg = use_signal::<Option<String>>(|| None);
+    let cx = use_channel::<VanityResponse>(*WEB_WORKERS);
+
+    let is_mining = matches!(
+        toolbar_state.status(),
+        MinerStatus::Active | MinerStatus::Activating
+    );
+
+    let request = move |worker: usize| VanityRequest {
+        worker,
+        prefix: prefix.peek().clone(),
+        suffix: suffix.peek().clone(),
+        case_sensitive: *case_sensitive.peek(),
+        duration_ms: VANITY_BATCH_MS,
+    };
+
+    let mut stop = move || {
+        for worker in workers.peek().iter() {
+            worker.terminate();
+        }
+        workers.set(vec![]);
+    };
+
+    // Collect worker results and hand out the next batch
+    let results = cx.clone();
+    use_future(move || {
+        let mut rx = results.receiver();
+        async move {
+            while let Ok(res) = rx.recv().await {
+                let VanityStatus::Searching { attempts, started_at } = *status.peek() else {
+                    continue;
+                };
+                if let Some(keypair) = res.keypair {
+                    stop();
+                    status.set(VanityStatus::Found(keypair));
+                    continue;
+                }
+                status.set(VanityStatus::Searching {
+                    attempts: attempts.saturating_add(res.attempts),
+                    started_at,
+                });
+                if let Some(worker) = workers.peek().get(res.worker) {
+                    worker
+                        .post_message(
+                            &to_value(&WorkerMessage::Vanity(request(res.worker))).unwrap(),
+                        )
+                        .ok();
+                }
+            }
+        }
+    });
+
+    // Mining takes priority over the search
+    use_effect(move || {
+        if matches!(
+            toolbar_state.status(),
+            MinerStatus::Active | MinerStatus::Activating
+        ) && matches!(*status.peek(), VanityStatus::Searching { .. })
+        {
+            stop();
+            status.set(VanityStatus::Idle);
+        }
+    });
+
+    use_drop(move || stop());
+
+    let pattern_len = prefix.read().len() + suffix.read().len();
+    let pattern_err = if !is_valid_vanity_pattern(&prefix.read(), *case_sensitive.read())
+        || !is_valid_vanity_pattern(&suffix.read(), *case_sensitive.read())
+    {
+        if *case_sensitive.read() {
+            Some("Addresses cannot contain 0, O, I or l".to_string())
+        } else {
+            Some("Addresses cannot contain 0".to_string())
+        }
+    } else if pattern_len.gt(&MAX_VANITY_LENGTH) {
+        Some(format!("Use at most {} characters", MAX_VANITY_LENGTH))
+    } else {
+        None
+    };
+    let expected = expected_vanity_attempts(&prefix.read(), &suffix.read(), *case_sensitive.read());
+    let worker_count = (power_level.read().0 as usize).clamp(1, *WEB_WORKERS);
+    let is_searching = matches!(*status.read(), VanityStatus::Searching { .. });
+    let progress = match *status.read() {
+        VanityStatus::Searching { attempts, started_at } => {
+            let elapsed = (js_sys::Date::now() - started_at) / 1000.0;
+            let rate = if elapsed.gt(&0.0) { attempts as f64 / elapsed } else { 0.0 };
+            let chance = (1.0 - (-(attempts as f64) / expected).exp()) * 100.0;
+            let eta = if rate.gt(&0.0) {
+                format_duration((expected - attempts as f64).max(0.0) / rate)
+            } else {
+                "estimating...".to_string()
+            };
+            Some((attempts, rate, chance, eta))
+        }
+        _ => None,
+    };
+    let found = match &*status.read() {
+        VanityStatus::Found(bytes) => Keypair::from_bytes(bytes).ok().map(|kp| kp.pubkey()),
+        _ => None,
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-16 grow w-full h-full",
+            div {
+                class: "flex flex-col gap-4 -mt-3.5",
+                BackButton {
+                    onclick: move |_| {
+                        nav.go_back()
+                    }
+                }
+                div {
+                    class: "flex flex-col gap-3",
+                    h2 {
+                        "Vanity address"
+                    }
+                    p {
+                        class: "text-lg",
+                        "Generate a key whose address starts or ends with characters of your choice."
+                    }
+                    p {
+                        class: "text-sm text-gray-300 dark:text-gray-700",
+                        "The search runs on {worker_count} of your cores, set by the power level on the Mine page. Every extra character makes it up to 58 times longer."
+                    }
+                }
+            }
+            if let Some(pubkey) = found {
+                div {
+                    class: "flex flex-col gap-4",
+                    p {
+                        class: "text-sm opacity-50 text-center",
+                        "Found"
+                    }
+                    p {
+                        class: "font-mono font-semibold text-center break-all",
+                        "{pubkey}"
+                    }
+                    if let Some(err_msg) = err_msg.read().clone() {
+                        p {
+                            class: "text-red-500 text-sm text-center",
+                            "{err_msg}"
+                        }
+                    }
+                }
+                div {
+                    class: "flex flex-col gap-4 mt-auto",
+                    button {
+                        class: "font-semibold hover-100 active-200 transition-colors px-4 py-2 rounded",
+                        onclick: move |_| {
+                            let VanityStatus::Found(bytes) = status.read().clone() else {
+                                return;
+                            };
+                            let result = Keypair::from_bytes(&bytes)
+                                .or(Err(keystore::KeystoreError::Corrupted))
+                                .and_then(|kp| keystore::save_profile(&kp));
+                            match result {
+                                Ok(_) => {
+                                    profiles.set(keystore::profiles());
+                                    nav.push(Route::Settings {});
+                                }
+                                Err(err) => {
+                                    log::error!("Failed to save vanity keypair: {:?}", err);
+                                    err_msg.set(Some("Failed to save keypair".to_string()));
+                                }
+                            }
+                        },
+                        "Save as new profile"
+                    }
+                    button {
+                        class: "bg-green-500 hover:bg-green-600 active:bg-green-700 transition-colors text-white rounded text-center font-semibold py-3",
+                        onclick: move |_| {
+                            let VanityStatus::Found(bytes) = status.read().clone() else {
+                                return;
+                            };
+                            let result = Keypair::from_bytes(&bytes)
+                                .or(Err(keystore::KeystoreError::Corrupted))
+                                .and_then(keystore::add_profile);
+                            match result {
+                                Ok(pubkey) => {
+                                    session.set(SessionStatus::Unlocked(pubkey));
+                                    nav.push(Route::Settings {});
+                                }
+                                Err(err) => {
+                                    log::error!("Failed to import vanity keypair: {:?}", err);
+                                    err_msg.set(Some("Failed to save keypair".to_string()));
+                                }
+                            }
+                        },
+                        "Use as miner key"
+                    }
+                }
+            } else {
+                div {
+                    class: "flex flex-col gap-4",
+                    input {
+                        class: "mx-auto w-full py-2 text-center placeholder-gray-200 dark:placeholder-gray-700 bg-transparent font-mono",
+                        disabled: is_searching,
+                        placeholder: "Prefix",
+                        value: "{prefix}",
+                        oninput: move |e| prefix.set(e.value().trim().to_string()),
+                    }
+                    input {
+                        class: "mx-auto w-full py-2 text-center placeholder-gray-200 dark:placeholder-gray-700 bg-transparent font-mono",
+                        disabled: is_searching,
+                        placeholder: "Suffix",
+                        value: "{suffix}",
+                        oninput: move |e| suffix.set(e.value().trim().to_string()),
+                    }
+                    label {
+                        class: "flex flex-row gap-2 mx-auto text-sm hover:cursor-pointer",
+                        input {
+                            r#type: "checkbox",
+                            disabled: is_searching,
+                            checked: *case_sensitive.read(),
+                            onchange: move |e| case_sensitive.set(e.checked()),
+                        }
+                        "Case sensitive"
+                    }
+                    if let Some((attempts, rate, chance, eta)) = progress {
+                        div {
+                            class: "flex flex-col gap-1 text-sm text-center",
+                            Spinner {
+                                class: "mx-auto mb-2"
+                            }
+                            p { "{attempts} keys tried ({rate:.0}/s)" }
+                            p { class: "opacity-50", "{chance:.1}% chance found by now, about {eta} to go" }
+                        }
+                    } else if let Some(pattern_err) = pattern_err.clone() {
+                        p {
+                            class: "text-red-500 text-sm text-center",
+                            "{pattern_err}"
+                        }
+                    } else if pattern_len.gt(&0) {
+                        p {
+                            class: "text-sm text-center opacity-50",
+                            "About {expected:.0} keys to try on average"
+                        }
+                    }
+                    if is_mining {
+                        p {
+                            class: "text-sm text-center text-red-500",
+                            "Stop the miner to generate a vanity address."
+                        }
+                    }
+                }
+                if is_searching {
+                    button {
+                        class: "font-semibold hover-100 active-200 transition-colors rounded text-center py-3 mt-auto",
+                        onclick: move |_| {
+                            stop();
+                            status.set(VanityStatus::Idle);
+                        },
+                        "Cancel"
+                    }
+                } else {
+                    button {
+                        class: "bg-green-500 disabled:opacity-50 hover:bg-green-600 active:bg-green-700 transition-colors text-white rounded text-center font-semibold py-3 mt-auto",
+                        disabled: is_mining || pattern_err.is_some() || pattern_len.eq(&0),
+                        onclick: move |_| {
+                            let pool: Vec<Worker> = (0..worker_count)
+                                .map(|_| create_web_worker(cx.clone()))
+                                .collect();
+                            for (i, worker) in pool.iter().enumerate() {
+                                worker.post_message(&to_value(&WorkerMessage::Vanity(request(i))).unwrap()).ok();
+                            }
+                            workers.set(pool);
+                            status.set(VanityStatus::Searching {
+                                attempts: 0,
+                                started_at: js_sys::Date::now(),
+                            });
+                        },
+                        "Generate"
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn format_duration(secs: f64) -> String {
+    if secs.lt(&60.0) {
+        format!("{:.0} seconds", secs)
+    } else if secs.lt(&3600.0) {
+        format!("{:.0} minutes", secs / 60.0)
+    } else if secs.lt(&86400.0) {
+        format!("{:.1} hours", secs / 3600.0)
+    } else {
+        format!("{:.1} days", secs / 86400.0)
+    }
+}
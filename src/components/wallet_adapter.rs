@@ -0,0 +1,55 @@
+use dioxus::prelude::*;
+
+use crate::{
+    hooks::{use_wallet_adapter, FundingSource, WalletAdapter},
+    wallet_adapter::{mount_wallet_adapter, WALLET_ADAPTER_ELEMENT_ID},
+};
+
+/// Connect button for Phantom, Backpack, Solflare and other Wallet Standard wallets.
+pub fn WalletAdapterButton() -> Element {
+    use_effect(move || {
+        mount_wallet_adapter();
+    });
+
+    rsx! {
+        div {
+            class: "flex my-auto",
+            id: WALLET_ADAPTER_ELEMENT_ID,
+        }
+    }
+}
+
+/// Lets the user pick between the miner keypair and the connected wallet as the source of funds.
+#[component]
+pub fn FundingSourceSelector(source: Signal<FundingSource>) -> Element {
+    let wallet_adapter = use_wallet_adapter();
+
+    // Fall back to the miner if the wallet disconnects
+    use_effect(move || {
+        if let WalletAdapter::Disconnected = *wallet_adapter.read() {
+            if let FundingSource::Wallet(_) = *source.peek() {
+                source.set(FundingSource::Miner);
+            }
+        }
+    });
+
+    rsx! {
+        div {
+            class: "flex flex-row gap-4 mx-auto",
+            WalletAdapterButton {}
+            if let WalletAdapter::Connected(wallet) = *wallet_adapter.read() {
+                select {
+                    class: "bg-transparent dark:text-white font-semibold hover:cursor-pointer py-1",
+                    onchange: move |e| {
+                        match e.value().as_str() {
+                            "wallet" => source.set(FundingSource::Wallet(wallet)),
+                            _ => source.set(FundingSource::Miner),
+                        }
+                    },
+                    option { initial_selected: source.read().eq(&FundingSource::Miner), value: "miner", "From miner" }
+                    option { initial_selected: source.read().eq(&FundingSource::Wallet(wallet)), value: "wallet", "From wallet" }
+                }
+            }
+        }
+    }
+}
@@ -17,6 +17,8 @@ pub enum GatewayError {
     // SimulationFailed,
     RequestFailed,
     ProgramBuilderFailed,
+    WalletUnavailable,
+    WalletRejected,
     Unknown,
 }
 
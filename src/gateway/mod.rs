@@ -1,17 +1,16 @@
 mod error;
 mod pfee;
+#[cfg(feature = "web")]
+mod pool;
 mod pubkey;
 
 // MI
-use crate::{
-    components::PriorityFeeStrategy,
-    hooks::{MinerStatusMessage, MinerToolbarState, UpdateMinerToolbarState},
-};
+#[cfg(feature = "web")]
+use crate::wallet_adapter;
+use crate::{hooks::PriorityFeeStrategy, keystore};
 use async_std::future::{timeout, Future};
 use cached::proc_macro::cached;
-use dioxus::prelude::*;
 pub use error::*;
-use gloo_storage::{LocalStorage, Storage};
 use ore_api::{
     consts::{BUS_ADDRESSES, CONFIG_ADDRESS},
     state::{Bus, Config, Proof},
@@ -19,6 +18,8 @@ use ore_api::{
 // use ore_types::{response::ListTransfersResponse};
 use ore_utils::AccountDeserialize;
 pub use pfee::*;
+#[cfg(feature = "web")]
+pub use pool::*;
 pub use pubkey::*;
 use rand::Rng;
 use solana_client_wasm::{
@@ -63,10 +64,16 @@ pub const CU_LIMIT_STAKE: u32 = 12_000; // MI added
 pub const CU_LIMIT_TRANSFER: u32 = 30_000; // MI added, incl. memo
 pub const CU_LIMIT_MINE: u32 = 1_400_000; // MI vanilla: 500_000;
 pub const CU_LIMIT_UPGRADE: u32 = 30_000; // MI
+pub const CU_LIMIT_FUND: u32 = 1_000;
+pub const CU_LIMIT_RESET: u32 = 100_000;
+pub const CU_LIMIT_UPGRADE_WALLET: u32 = CU_LIMIT_UPGRADE + CU_LIMIT_CREATE_ATA;
+pub const CU_LIMIT_STAKE_WALLET: u32 = CU_LIMIT_STAKE + CU_LIMIT_TRANSFER + CU_LIMIT_CREATE_ATA;
 
 const RPC_RETRIES: usize = 0;
 const GATEWAY_RETRIES: usize = 64;
 const CONFIRM_RETRIES: usize = 8;
+#[cfg(feature = "web")]
+const WALLET_RETRIES: usize = 8;
 
 const CONFIRM_DELAY: u64 = 500;
 const GATEWAY_DELAY: u64 = 0; //300;
@@ -83,6 +90,11 @@ pub enum ComputeBudget {
     FixedLimitStaticPrice(u32, u64),
 }
 
+/// Receives progress while a transaction is being submitted.
+pub trait SubmitStatus {
+    fn submitting(&mut self, attempt: u64, priority_fee: u64);
+}
+
 pub const CB: ComputeBudget =
     ComputeBudget::FixedLimitStaticPrice(DEFAULT_CU_LIMIT, DEFAULT_CU_PRICE);
 
@@ -104,6 +116,15 @@ impl Gateway {
         }
     }
 
+    /// Lamports tipped with every transaction. Custom RPC urls pay half.
+    pub fn tip_amount(&self) -> u64 {
+        if self.rpc_url.eq(RPC_URL) {
+            TIP_AMOUNT
+        } else {
+            TIP_AMOUNT / 2
+        }
+    }
+
     pub async fn get_clock(&self) -> GatewayResult<Clock> {
         retry(|| self.try_get_clock()).await
     }
@@ -161,7 +182,7 @@ impl Gateway {
         Ok(*Proof::try_from_bytes(&data).expect("Failed to parse proof"))
     }
 
-    pub async fn _get_bus(&self, id: usize) -> GatewayResult<Bus> {
+    pub async fn get_bus(&self, id: usize) -> GatewayResult<Bus> {
         let bus_address = BUS_ADDRESSES.get(id).unwrap();
         let data = self
             .rpc
@@ -171,6 +192,14 @@ impl Gateway {
         Ok(*Bus::try_from_bytes(&data).expect("Failed to parse bus"))
     }
 
+    /// Fetches every bus, ordered by id.
+    pub async fn get_buses(&self) -> GatewayResult<Vec<Bus>> {
+        futures::future::join_all((0..BUS_ADDRESSES.len()).map(|id| self.get_bus(id)))
+            .await
+            .into_iter()
+            .collect()
+    }
+
     pub async fn get_token_account(
         &self,
         pubkey: &Pubkey,
@@ -193,7 +222,7 @@ impl Gateway {
         ixs: &[Instruction],
         compute_budget: ComputeBudget,
         skip_confirm: bool,
-        mut toolbar_state: Option<&mut Signal<MinerToolbarState>>,
+        mut status: Option<&mut dyn SubmitStatus>,
     ) -> GatewayResult<Signature> {
         let signer = signer();
 
@@ -231,34 +260,17 @@ impl Gateway {
         final_ixs.extend_from_slice(ixs);
 
         // Add tip collection instructions
-        if self.rpc_url.eq(RPC_URL) {
-            let mut rng = rand::thread_rng();
-            let tip_accounts = &[
-                // Miraland donation account only
-                Pubkey::from_str("9h9TXFtSsDAiL5kpCRZuKUxPE4Nv3W56fcSyUC3zmQip").unwrap(),
-            ];
-            let i = rng.gen_range(0..tip_accounts.len());
-            let ix = solana_sdk::system_instruction::transfer(
-                &signer.pubkey(),
-                &tip_accounts[i],
-                TIP_AMOUNT,
-            );
-            final_ixs.push(ix);
-        } else {
-            // half tip
-            let mut rng = rand::thread_rng();
-            let tip_accounts = &[
-                // Miraland donation account only
-                Pubkey::from_str("9h9TXFtSsDAiL5kpCRZuKUxPE4Nv3W56fcSyUC3zmQip").unwrap(),
-            ];
-            let i = rng.gen_range(0..tip_accounts.len());
-            let ix = solana_sdk::system_instruction::transfer(
-                &signer.pubkey(),
-                &tip_accounts[i],
-                TIP_AMOUNT / 2,
-            );
-            final_ixs.push(ix);
-        }
+        let tip_accounts = &[
+            // Miraland donation account only
+            Pubkey::from_str("9h9TXFtSsDAiL5kpCRZuKUxPE4Nv3W56fcSyUC3zmQip").unwrap(),
+        ];
+        let i = rand::thread_rng().gen_range(0..tip_accounts.len());
+        let ix = solana_sdk::system_instruction::transfer(
+            &signer.pubkey(),
+            &tip_accounts[i],
+            self.tip_amount(),
+        );
+        final_ixs.push(ix);
 
         // Build tx
         let send_cfg = RpcSendTransactionConfig {
@@ -275,8 +287,8 @@ impl Gateway {
         let mut attempts = 0;
         loop {
             log::info!("Attempt: {:?}", attempts);
-            if toolbar_state.is_some() {
-                toolbar_state.as_mut().unwrap().set_status_message(MinerStatusMessage::Submitting(attempts as u64, fee));
+            if let Some(status) = status.as_mut() {
+                status.submitting(attempts as u64, fee);
             }
 
             // Sign tx with a new blockhash (after approximately ~45 sec)
@@ -293,8 +305,8 @@ impl Gateway {
                     fee
                 };
 
-                if toolbar_state.is_some() {
-                    toolbar_state.as_mut().unwrap().set_status_message(MinerStatusMessage::Submitting(attempts as u64, fee));
+                if let Some(status) = status.as_mut() {
+                    status.submitting(attempts as u64, fee);
                 }
 
                 final_ixs.remove(1);
@@ -322,41 +334,8 @@ impl Gateway {
                     }
 
                     // Confirm transaction
-                    for _ in 0..CONFIRM_RETRIES {
-                        async_std::task::sleep(Duration::from_millis(CONFIRM_DELAY)).await;
-
-                        // Fetch transaction status
-                        match self.rpc.get_signature_statuses(&[sig]).await {
-                            Ok(signature_statuses) => {
-                                for signature_status in signature_statuses {
-                                    if let Some(signature_status) = signature_status { // .as_ref()
-                                        if let Some(err) = signature_status.err {
-                                            log::error!("Error: {err}");
-                                            return Err(GatewayError::Unknown);
-                                        } else if let Some(confirmation) = signature_status.confirmation_status {
-                                            match confirmation {
-                                                TransactionConfirmationStatus::Processed => {}
-                                                TransactionConfirmationStatus::Confirmed
-                                                | TransactionConfirmationStatus::Finalized => {
-                                                    log::info!("Tx sig confirmed: true");
-                                                    return Ok(sig);
-                                                }
-                                            }
-                                        } else {
-                                            log::info!("No confirmation status available for current signature status.");
-                                        }
-                                    } else {
-                                        // MI
-                                        log::info!("No status available for current signature.");
-                                    }
-                                }
-                            }
-
-                            // Handle confirmation errors
-                            Err(err) => {
-                                log::error!("Error confirming tx: {:?}", err);
-                            }
-                        }
+                    if self.confirm_signature(sig).await? {
+                        return Ok(sig);
                     }
 
                     // Failed to confirm tx
@@ -378,6 +357,105 @@ impl Gateway {
         }
     }
 
+    /// Polls the signature status. Returns `Ok(true)` once the tx is confirmed and `Ok(false)` if it never was.
+    async fn confirm_signature(&self, sig: Signature) -> GatewayResult<bool> {
+        for _ in 0..CONFIRM_RETRIES {
+            async_std::task::sleep(Duration::from_millis(CONFIRM_DELAY)).await;
+
+            // Fetch transaction status
+            match self.rpc.get_signature_statuses(&[sig]).await {
+                Ok(signature_statuses) => {
+                    for signature_status in signature_statuses {
+                        if let Some(signature_status) = signature_status { // .as_ref()
+                            if let Some(err) = signature_status.err {
+                                log::error!("Error: {err}");
+                                return Err(GatewayError::Unknown);
+                            } else if let Some(confirmation) = signature_status.confirmation_status {
+                                match confirmation {
+                                    TransactionConfirmationStatus::Processed => {}
+                                    TransactionConfirmationStatus::Confirmed
+                                    | TransactionConfirmationStatus::Finalized => {
+                                        log::info!("Tx sig confirmed: true");
+                                        return Ok(true);
+                                    }
+                                }
+                            } else {
+                                log::info!("No confirmation status available for current signature status.");
+                            }
+                        } else {
+                            // MI
+                            log::info!("No status available for current signature.");
+                        }
+                    }
+                }
+
+                // Handle confirmation errors
+                Err(err) => {
+                    log::error!("Error confirming tx: {:?}", err);
+                }
+            }
+        }
+        Ok(false)
+    }
+
+    /// Sends a tx paid for and signed by the connected external wallet.
+    /// If `co_sign` is set, the miner keypair partially signs the tx before it is handed to the wallet.
+    #[cfg(feature = "web")]
+    pub async fn send_and_confirm_with_wallet(
+        &self,
+        ixs: &[Instruction],
+        wallet: Pubkey,
+        priority_fee: u64,
+        cu_limit: u32,
+        co_sign: bool,
+    ) -> GatewayResult<Signature> {
+        let mut final_ixs = vec![
+            ComputeBudgetInstruction::set_compute_unit_limit(cu_limit),
+            ComputeBudgetInstruction::set_compute_unit_price(priority_fee),
+        ];
+        final_ixs.extend_from_slice(ixs);
+
+        // Build and sign tx
+        let (hash, _slot) = self
+            .rpc
+            .get_latest_blockhash_with_commitment(CommitmentConfig {
+                commitment: self.rpc.commitment(),
+            })
+            .await
+            .map_err(GatewayError::from)?;
+        let mut tx = Transaction::new_with_payer(final_ixs.as_slice(), Some(&wallet));
+        if co_sign {
+            tx.partial_sign(&[&signer()], hash);
+        } else {
+            tx.message.recent_blockhash = hash;
+        }
+        let tx = wallet_adapter::sign_transaction(&tx).await?;
+
+        // Submit tx. The wallet signature is bound to the blockhash, so resend the same tx until it lands.
+        let send_cfg = RpcSendTransactionConfig {
+            skip_preflight: false,
+            preflight_commitment: Some(CommitmentLevel::Confirmed),
+            encoding: Some(UiTransactionEncoding::Base64),
+            max_retries: Some(RPC_RETRIES),
+            min_context_slot: None,
+        };
+        for attempt in 0..WALLET_RETRIES {
+            log::info!("Wallet tx attempt: {:?}", attempt);
+            match self.rpc.send_transaction_with_config(&tx, send_cfg).await {
+                Ok(sig) => {
+                    log::info!("Sig: {:?}", sig);
+                    if self.confirm_signature(sig).await? {
+                        return Ok(sig);
+                    }
+                }
+                Err(err) => {
+                    log::error!("Error {:?}", err);
+                }
+            }
+        }
+        Err(GatewayError::TransactionTimeout)
+    }
+
     // Ore
     pub async fn open_ore(&self) -> GatewayResult<()> {
         // Return early, if account is already initialized
@@ -472,6 +550,90 @@ impl Gateway {
         self.send_and_confirm(&ixs, CB, false, None).await
     }
 
+    /// Tops up the miner keypair with SOL from the connected wallet.
+    #[cfg(feature = "web")]
+    pub async fn fund_miner_from_wallet(
+        &self,
+        wallet: Pubkey,
+        lamports: u64,
+        priority_fee: u64,
+    ) -> GatewayResult<Signature> {
+        let ix = solana_sdk::system_instruction::transfer(&wallet, &signer().pubkey(), lamports);
+        self.send_and_confirm_with_wallet(&[ix], wallet, priority_fee, CU_LIMIT_FUND, false)
+            .await
+    }
+
+    /// Upgrades OREv1 held by the connected wallet. The upgraded ORE is minted back to the wallet.
+    #[cfg(feature = "web")]
+    pub async fn upgrade_ore_from_wallet(
+        &self,
+        wallet: Pubkey,
+        amount: u64,
+        priority_fee: u64,
+    ) -> GatewayResult<Signature> {
+        let mut ixs = vec![];
+        if self.get_token_account_ore_from_pubkey(wallet).await.is_err() {
+            ixs.push(create_associated_token_account(
+                &wallet,
+                &wallet,
+                &ore_api::consts::MINT_ADDRESS,
+                &spl_token::id(),
+            ));
+        }
+        ixs.push(ore_api::instruction::upgrade(
+            wallet,
+            ore_token_account_address(wallet),
+            ore_token_account_address_v1(wallet),
+            amount,
+        ));
+        self.send_and_confirm_with_wallet(&ixs, wallet, priority_fee, CU_LIMIT_UPGRADE_WALLET, false)
+            .await
+    }
+
+    /// Moves ORE from the connected wallet into the miner's proof.
+    /// The proof authority must sign the stake, so the miner keypair co-signs the tx.
+    #[cfg(feature = "web")]
+    pub async fn stake_ore_from_wallet(
+        &self,
+        wallet: Pubkey,
+        amount: u64,
+        priority_fee: u64,
+    ) -> GatewayResult<Signature> {
+        let signer = signer();
+        let miner_token_account = ore_token_account_address(signer.pubkey());
+        let mut ixs = vec![];
+        if self
+            .get_token_account_ore_from_pubkey(signer.pubkey())
+            .await
+            .is_err()
+        {
+            ixs.push(create_associated_token_account(
+                &wallet,
+                &signer.pubkey(),
+                &ore_api::consts::MINT_ADDRESS,
+                &spl_token::id(),
+            ));
+        }
+        ixs.push(
+            spl_token::instruction::transfer(
+                &spl_token::ID,
+                &ore_token_account_address(wallet),
+                &miner_token_account,
+                &wallet,
+                &[&wallet],
+                amount,
+            )
+            .map_err(GatewayError::from)?,
+        );
+        ixs.push(ore_api::instruction::stake(
+            signer.pubkey(),
+            miner_token_account,
+            amount,
+        ));
+        self.send_and_confirm_with_wallet(&ixs, wallet, priority_fee, CU_LIMIT_STAKE_WALLET, true)
+            .await
+    }
+
     pub async fn transfer_ore(
         &self,
         amount: u64,
@@ -633,13 +795,7 @@ impl Gateway {
 }
 
 pub fn signer() -> Keypair {
-    let key = "keypair";
-    let value = LocalStorage::get(key).ok().unwrap_or_else(|| {
-        let x = Keypair::new().to_base58_string();
-        LocalStorage::set(key, &x).ok();
-        x
-    });
-    Keypair::from_base58_string(&value)
+    keystore::signer()
 }
 
 pub async fn retry<F, Fut, T>(f: F) -> GatewayResult<T>
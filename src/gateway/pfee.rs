@@ -1,5 +1,7 @@
-use crate::hooks::{use_fee_url, use_priority_fee, use_priority_fee_cap};
-use dioxus::signals::Readable;
+use std::sync::RwLock;
+
+use crate::hooks::DEFAULT_PRIORITY_FEE;
+use lazy_static::lazy_static;
 use ore_api::consts::BUS_ADDRESSES;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
@@ -8,6 +10,8 @@ use solana_client_wasm::solana_sdk::clock::Slot;
 
 use url::Url;
 
+use super::{FEE_URL, PRIORITY_FEE_CAP};
+
 enum FeeStrategy {
     Helius,
     Triton,
@@ -21,14 +25,37 @@ pub struct RpcPrioritizationFee {
     pub prioritization_fee: u64,
 }
 
+/// Settings the fee estimate works from.
+/// The app mirrors them from its settings; the headless miner sets them from its config.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FeeSettings {
+    pub fee_url: String,
+    pub priority_fee: u64,
+    pub priority_fee_cap: u64,
+}
+
+lazy_static! {
+    static ref FEE_SETTINGS: RwLock<FeeSettings> = RwLock::new(FeeSettings {
+        fee_url: FEE_URL.to_string(),
+        priority_fee: DEFAULT_PRIORITY_FEE,
+        priority_fee_cap: PRIORITY_FEE_CAP,
+    });
+}
+
+pub fn set_fee_settings(settings: FeeSettings) {
+    *FEE_SETTINGS.write().unwrap() = settings;
+}
+
 pub async fn get_recent_priority_fee_estimate() -> Result<u64, String> {
-    // Get url
-    let fee_url = use_fee_url();
-    let priority_fee = use_priority_fee();
-    let priority_fee_cap = use_priority_fee_cap();
+    // Get settings
+    let FeeSettings {
+        fee_url,
+        priority_fee,
+        priority_fee_cap,
+    } = FEE_SETTINGS.read().unwrap().clone();
 
     // Select fee estiamte strategy
-    let host = Url::parse(&fee_url.read().0)
+    let host = Url::parse(&fee_url)
         .unwrap()
         .host_str()
         .unwrap()
@@ -115,19 +142,19 @@ pub async fn get_recent_priority_fee_estimate() -> Result<u64, String> {
     // split json from send
     // 1) handle response
     let Ok(resp) = client
-        .post(fee_url.read().0.clone())
+        .post(fee_url)
         .json(&body)
         .send()
         .await
     else {
         eprintln!("didn't get dynamic fee estimate, use default instead.");
-        return Ok(priority_fee.read().0);
+        return Ok(priority_fee);
     };
 
     // 2) handle json
     let Ok(response) = resp.json::<Value>().await else {
         eprintln!("didn't get json data from fee estimate response, use default instead.");
-        return Ok(priority_fee.read().0);
+        return Ok(priority_fee);
     };
 
     // Parse response
@@ -173,7 +200,7 @@ pub async fn get_recent_priority_fee_estimate() -> Result<u64, String> {
     // Check if the calculated fee is higher than max
     match calculated_fee {
         Err(err) => Err(err),
-        Ok(fee) => Ok(fee.min(priority_fee_cap.read().0)),
+        Ok(fee) => Ok(fee.min(priority_fee_cap)),
     }
 }
 
@@ -200,3 +227,58 @@ pub fn estimate_prioritization_fee_micro_lamports(
 
     prioritization_fee
 }
+
+/// Average recent priority fee of transactions that wrote to each bus, ordered by bus id.
+///
+/// Uses the standard `getRecentPrioritizationFees` method, one request per bus in a single batch.
+pub async fn get_bus_fees() -> Result<Vec<u64>, String> {
+    let fee_url = FEE_SETTINGS.read().unwrap().fee_url.clone();
+    let body: Vec<Value> = BUS_ADDRESSES
+        .iter()
+        .enumerate()
+        .map(|(id, pubkey)| {
+            json!({
+                "jsonrpc": "2.0",
+                "id": id,
+                "method": "getRecentPrioritizationFees",
+                "params": [[pubkey.to_string()]]
+            })
+        })
+        .collect();
+    let response: Vec<Value> = Client::new()
+        .post(fee_url)
+        .json(&body)
+        .send()
+        .await
+        .map_err(|err| err.to_string())?
+        .json()
+        .await
+        .map_err(|err| err.to_string())?;
+
+    // Batch responses may come back in any order
+    let mut fees = vec![0; BUS_ADDRESSES.len()];
+    for res in response {
+        let id = res["id"].as_u64().unwrap_or(u64::MAX) as usize;
+        let recent = serde_json::from_value::<Vec<RpcPrioritizationFee>>(res["result"].clone())
+            .map_err(|err| format!("Failed to parse bus fees: {:?}, error: {}", res, err))?;
+        if let Some(fee) = fees.get_mut(id) {
+            *fee = average_recent_fee(&recent);
+        }
+    }
+    Ok(fees)
+}
+
+/// Average fee over the last 20 slots, or zero if there were none.
+fn average_recent_fee(prioritization_fees: &[RpcPrioritizationFee]) -> u64 {
+    let recent: Vec<u64> = prioritization_fees
+        .iter()
+        .rev()
+        .take(20)
+        .map(|fee| fee.prioritization_fee)
+        .collect();
+    recent
+        .iter()
+        .sum::<u64>()
+        .checked_div(recent.len() as u64)
+        .unwrap_or(0)
+}
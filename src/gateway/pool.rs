@@ -0,0 +1,88 @@
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use solana_client_wasm::solana_sdk::pubkey::Pubkey;
+
+use super::{GatewayError, GatewayResult};
+
+// Wire types of the pool protocol. The reference server lives in `serve/src/pool.rs`.
+
+/// Work handed out by the pool: a challenge and a nonce range to search.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PoolJob {
+    pub round: u64,
+    pub challenge: [u8; 32],
+    pub nonce_start: u64,
+    pub nonce_end: u64,
+    pub share_difficulty: u32,
+    pub cutoff_time: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PoolShare {
+    pub member: String,
+    pub round: u64,
+    pub digest: [u8; 16],
+    pub nonce: [u8; 8],
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PoolShareResult {
+    pub accepted: bool,
+    pub difficulty: u32,
+    pub reason: Option<String>,
+}
+
+/// A member's credit with the pool.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PoolMemberStats {
+    pub member: String,
+    pub shares: u64,
+    pub contribution: u64,
+}
+
+pub struct PoolClient {
+    url: String,
+    client: Client,
+}
+
+impl PoolClient {
+    pub fn new(url: &str) -> Self {
+        Self {
+            url: url.trim_end_matches('/').to_string(),
+            client: Client::new(),
+        }
+    }
+
+    pub async fn get_job(&self, member: Pubkey) -> GatewayResult<PoolJob> {
+        self.client
+            .get(format!("{}/pool/job", self.url))
+            .query(&[("member", member.to_string())])
+            .send()
+            .await?
+            .json()
+            .await
+            .or(Err(GatewayError::FailedDeserialization))
+    }
+
+    /// Posts a solution above the share difficulty. Rejected shares are returned, not errors.
+    pub async fn submit_share(&self, share: &PoolShare) -> GatewayResult<PoolShareResult> {
+        self.client
+            .post(format!("{}/pool/share", self.url))
+            .json(share)
+            .send()
+            .await?
+            .json()
+            .await
+            .or(Err(GatewayError::FailedDeserialization))
+    }
+
+    pub async fn get_member(&self, member: Pubkey) -> GatewayResult<PoolMemberStats> {
+        self.client
+            .get(format!("{}/pool/members/{}", self.url, member))
+            .send()
+            .await?
+            .json()
+            .await
+            .or(Err(GatewayError::FailedDeserialization))
+    }
+}
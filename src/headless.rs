@@ -0,0 +1,268 @@
+//! Headless miner for servers. Reuses the app's gateway, submission pipeline and native hashing.
+//!
+//! ```text
+//! ore-app-classic --config miner.toml [--keypair ~/.config/solana/id.json]
+//! ```
+
+use std::{
+    rc::Rc,
+    sync::{atomic::AtomicBool, Arc},
+    time::Duration,
+};
+
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use serde::Deserialize;
+use serde_json::json;
+use solana_client_wasm::solana_sdk::signer::Signer;
+
+use crate::{
+    gateway::{self, FeeSettings, Gateway, SubmitStatus, API_URL, FEE_URL, PRIORITY_FEE_CAP, RPC_URL},
+    hooks::{
+        format_hashrate, BusStrategy, Hashrate, PriorityFeeStrategy, ResetPolicy,
+        DEFAULT_PRIORITY_FEE,
+    },
+    keystore,
+    miner::{
+        best_batch_result, cutoff_time, find_next_hash_native, mining_priority_fee,
+        native_threads, submit_solution, NonceProgress, WebWorkerRequest,
+    },
+};
+
+/// Pause before retrying after an RPC or submission error.
+const ERROR_DELAY: Duration = Duration::from_secs(5);
+
+/// Miner configuration, read from a TOML file.
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+struct Config {
+    rpc_url: String,
+    fee_url: String,
+    keypair: Option<String>,
+    priority_fee_strategy: PriorityFeeStrategy,
+    priority_fee: u64,
+    priority_fee_cap: u64,
+    threads: usize,
+    bus_strategy: BusStrategy,
+    reset_policy: ResetPolicy,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            rpc_url: RPC_URL.to_string(),
+            fee_url: FEE_URL.to_string(),
+            keypair: None,
+            priority_fee_strategy: PriorityFeeStrategy::Estimate,
+            priority_fee: DEFAULT_PRIORITY_FEE,
+            priority_fee_cap: PRIORITY_FEE_CAP,
+            threads: native_threads(),
+            bus_strategy: BusStrategy::Random,
+            reset_policy: ResetPolicy::Include,
+        }
+    }
+}
+
+pub fn run() {
+    log::set_logger(&JsonLogger).ok();
+    log::set_max_level(LevelFilter::Info);
+
+    let config = match load_config() {
+        Ok(config) => config,
+        Err(err) => {
+            log::error!("{}", err);
+            std::process::exit(1);
+        }
+    };
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("failed to start runtime");
+    runtime.block_on(mine(config));
+}
+
+fn load_config() -> Result<Config, String> {
+    let mut config_path = None;
+    let mut keypair_path = None;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--config" => config_path = args.next(),
+            "--keypair" => keypair_path = args.next(),
+            _ => return Err(format!("Unknown argument: {}", arg)),
+        }
+    }
+
+    let mut config = match config_path {
+        Some(path) => {
+            let contents = std::fs::read_to_string(&path)
+                .map_err(|err| format!("Failed to read config {}: {}", path, err))?;
+            toml::from_str(&contents).map_err(|err| format!("Invalid config {}: {}", path, err))?
+        }
+        None => Config::default(),
+    };
+    if keypair_path.is_some() {
+        config.keypair = keypair_path;
+    }
+    config.threads = config.threads.clamp(1, native_threads());
+
+    // Open a session with the keypair file so the gateway can sign
+    let path = config
+        .keypair
+        .clone()
+        .ok_or("No keypair given. Set `keypair` in the config or pass --keypair.")?;
+    let path = match path.strip_prefix("~/") {
+        Some(rest) => format!("{}/{}", std::env::var("HOME").unwrap_or_default(), rest),
+        None => path,
+    };
+    let contents = std::fs::read_to_string(&path)
+        .map_err(|err| format!("Failed to read keypair {}: {}", path, err))?;
+    let keypair = keystore::parse_secret_key(&contents)
+        .map_err(|err| format!("Invalid keypair {}: {:?}", path, err))?;
+    keystore::open_with_keypair(keypair);
+
+    gateway::set_fee_settings(FeeSettings {
+        fee_url: config.fee_url.clone(),
+        priority_fee: config.priority_fee,
+        priority_fee_cap: config.priority_fee_cap,
+    });
+    Ok(config)
+}
+
+async fn mine(config: Config) {
+    let gateway = Rc::new(Gateway::new(
+        API_URL.to_string(),
+        config.rpc_url.clone(),
+        config.fee_url.clone(),
+    ));
+    let pubkey = gateway::signer().pubkey();
+    log::info!(
+        "Mining as {} on {} threads with {:?} priority fees",
+        pubkey,
+        config.threads,
+        config.priority_fee_strategy
+    );
+
+    while let Err(err) = gateway.open_ore().await {
+        log::error!("Failed to open proof account: {:?}", err);
+        tokio::time::sleep(ERROR_DELAY).await;
+    }
+
+    loop {
+        let (proof, clock) = match (gateway.get_proof(pubkey).await, gateway.get_clock().await) {
+            (Ok(proof), Ok(clock)) => (proof, clock),
+            (Err(err), _) | (_, Err(err)) => {
+                log::error!("Failed to fetch proof: {:?}", err);
+                tokio::time::sleep(ERROR_DELAY).await;
+                continue;
+            }
+        };
+
+        // Hash until a solution clears the minimum difficulty
+        let challenge: [u8; 32] = proof.challenge.into();
+        let mut progress = NonceProgress::new(challenge);
+        let mut cutoff = cutoff_time(&proof, &clock);
+        let best = loop {
+            let threads = config.threads.max(1);
+            let reqs = (0..threads)
+                .map(|worker| {
+                    let nonce = progress.next(worker);
+                    WebWorkerRequest {
+                        challenge,
+                        nonce: nonce.to_le_bytes(),
+                        offset: nonce,
+                        cutoff_time: cutoff,
+                        power_level: threads - 1,
+                        worker,
+                        target: None,
+                        share_difficulty: None,
+                    }
+                })
+                .collect();
+            let results = tokio::task::spawn_blocking(move || {
+                find_next_hash_native(
+                    reqs,
+                    Arc::new(AtomicBool::new(false)),
+                    Arc::default(),
+                    |_| {},
+                )
+            })
+            .await
+            .unwrap_or_default();
+            progress.record(&results);
+            let best = best_batch_result(&results);
+            let mut hashrate = Hashrate::default();
+            hashrate.record(&results);
+            log::info!(
+                "Hashrate: {}, best difficulty {}",
+                format_hashrate(hashrate.hashrate()),
+                hashrate.best_difficulty
+            );
+            let min_difficulty = gateway
+                .get_config()
+                .await
+                .map(|config| config.min_difficulty as u32)
+                .unwrap_or_default();
+            if best.difficulty.ge(&min_difficulty) {
+                break best;
+            }
+            cutoff = 0;
+        };
+        log::info!("Best difficulty: {}", best.difficulty);
+
+        // Submit with the same fee, tip, reset and bus logic as the app
+        let priority_fee =
+            mining_priority_fee(config.priority_fee_strategy, config.priority_fee).await;
+        let submission = submit_solution(
+            &gateway,
+            best.solution,
+            config.priority_fee_strategy,
+            priority_fee,
+            config.bus_strategy,
+            config.reset_policy,
+            &mut LogStatus,
+        )
+        .await;
+        match submission.result {
+            Ok(sig) => log::info!("Submitted to bus {}: {}", submission.bus, sig),
+            Err(err) => {
+                log::error!("Failed to submit hash: {:?}", err);
+                tokio::time::sleep(ERROR_DELAY).await;
+            }
+        }
+    }
+}
+
+/// Reports submission attempts to the log.
+struct LogStatus;
+
+impl SubmitStatus for LogStatus {
+    fn submitting(&mut self, attempt: u64, priority_fee: u64) {
+        log::info!("Submitting: attempt {} at {} microlamports", attempt, priority_fee);
+    }
+}
+
+/// Writes one JSON object per log line, for log collectors.
+struct JsonLogger;
+
+impl Log for JsonLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level().le(&Level::Info)
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            println!(
+                "{}",
+                json!({
+                    "ts": chrono::Utc::now().to_rfc3339(),
+                    "level": record.level().as_str(),
+                    "target": record.target(),
+                    "msg": record.args().to_string(),
+                })
+            );
+        }
+    }
+
+    fn flush(&self) {}
+}
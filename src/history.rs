@@ -0,0 +1,176 @@
+use js_sys::{Function, Promise};
+use serde::{Deserialize, Serialize};
+use serde_wasm_bindgen::{from_value, to_value};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{IdbDatabase, IdbObjectStoreParameters, IdbRequest, IdbTransactionMode};
+
+const DB_NAME: &str = "ore-history";
+const DB_VERSION: u32 = 1;
+const STORE: &str = "submissions";
+const AUTHORITY_INDEX: &str = "authority";
+
+/// One mine transaction submitted by this browser.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct SubmissionRecord {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<u32>,
+    pub authority: String,
+    /// Unix timestamp in seconds.
+    pub timestamp: i64,
+    pub challenge: String,
+    pub difficulty: u32,
+    pub bus: usize,
+    /// Compute unit price of the last attempt, in microlamports.
+    pub priority_fee: u64,
+    /// Tip in lamports.
+    pub tip: u64,
+    pub attempts: u64,
+    /// Empty when the submission failed.
+    pub signature: String,
+    /// Time from the first send to confirmation.
+    pub latency_ms: u64,
+    /// Change in the proof's stake balance, in grains.
+    pub reward: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl SubmissionRecord {
+    pub fn landed(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// Stores a submission. Failures are logged, since history must never interrupt mining.
+pub async fn add(record: &SubmissionRecord) {
+    if let Err(err) = try_add(record).await {
+        log::error!("Failed to record submission: {:?}", err);
+    }
+}
+
+/// Returns every submission of `authority`, newest first.
+pub async fn load(authority: &str) -> Vec<SubmissionRecord> {
+    match try_load(authority).await {
+        Ok(records) => records,
+        Err(err) => {
+            log::error!("Failed to load submission history: {:?}", err);
+            vec![]
+        }
+    }
+}
+
+/// Deletes every submission of `authority`.
+pub async fn clear(authority: &str) {
+    let records = load(authority).await;
+    if let Err(err) = try_delete(&records).await {
+        log::error!("Failed to clear submission history: {:?}", err);
+    }
+}
+
+/// Formats records as CSV with a header row.
+pub fn to_csv(records: &[SubmissionRecord]) -> String {
+    let mut csv = String::from(
+        "timestamp,authority,challenge,difficulty,bus,priority_fee,tip,attempts,signature,latency_ms,reward,error\n",
+    );
+    for r in records {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{},{},{},{},{},{},{}\n",
+            r.timestamp,
+            r.authority,
+            r.challenge,
+            r.difficulty,
+            r.bus,
+            r.priority_fee,
+            r.tip,
+            r.attempts,
+            r.signature,
+            r.latency_ms,
+            r.reward,
+            r.error.as_deref().unwrap_or("").replace([',', '\n'], " "),
+        ));
+    }
+    csv
+}
+
+async fn try_add(record: &SubmissionRecord) -> Result<(), JsValue> {
+    let db = open().await?;
+    let tx = db.transaction_with_str_and_mode(STORE, IdbTransactionMode::Readwrite)?;
+    let store = tx.object_store(STORE)?;
+    request(&store.add(&to_value(record)?)?).await?;
+    Ok(())
+}
+
+async fn try_load(authority: &str) -> Result<Vec<SubmissionRecord>, JsValue> {
+    let db = open().await?;
+    let tx = db.transaction_with_str(STORE)?;
+    let index = tx.object_store(STORE)?.index(AUTHORITY_INDEX)?;
+    let values = request(&index.get_all_with_key(&authority.into())?).await?;
+    let mut records: Vec<SubmissionRecord> = from_value(values)?;
+    records.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+    Ok(records)
+}
+
+async fn try_delete(records: &[SubmissionRecord]) -> Result<(), JsValue> {
+    let db = open().await?;
+    let tx = db.transaction_with_str_and_mode(STORE, IdbTransactionMode::Readwrite)?;
+    let store = tx.object_store(STORE)?;
+    for id in records.iter().filter_map(|r| r.id) {
+        request(&store.delete(&id.into())?).await?;
+    }
+    Ok(())
+}
+
+async fn open() -> Result<IdbDatabase, JsValue> {
+    let factory = web_sys::window()
+        .and_then(|window| window.indexed_db().ok().flatten())
+        .ok_or_else(|| JsValue::from_str("IndexedDB unavailable"))?;
+    let req = factory.open_with_u32(DB_NAME, DB_VERSION)?;
+
+    // Create the store on first open
+    let req_ = req.clone();
+    req.set_onupgradeneeded(Some(
+        Closure::once_into_js(move |_: web_sys::Event| {
+            let Ok(db) = req_.result().and_then(|db| db.dyn_into::<IdbDatabase>()) else {
+                return;
+            };
+            let mut params = IdbObjectStoreParameters::new();
+            params.key_path(Some(&"id".into())).auto_increment(true);
+            if let Ok(store) = db.create_object_store_with_optional_parameters(STORE, &params) {
+                store.create_index_with_str(AUTHORITY_INDEX, "authority").ok();
+            }
+        })
+        .unchecked_ref(),
+    ));
+
+    request(&req).await?.dyn_into::<IdbDatabase>()
+}
+
+/// Resolves when an IndexedDB request succeeds.
+async fn request(req: &IdbRequest) -> Result<JsValue, JsValue> {
+    let promise = Promise::new(&mut |resolve: Function, reject: Function| {
+        let req_ = req.clone();
+        req.set_onsuccess(Some(
+            Closure::once_into_js(move |_: web_sys::Event| {
+                resolve
+                    .call1(&JsValue::NULL, &req_.result().unwrap_or(JsValue::UNDEFINED))
+                    .ok();
+            })
+            .unchecked_ref(),
+        ));
+        let req_ = req.clone();
+        req.set_onerror(Some(
+            Closure::once_into_js(move |_: web_sys::Event| {
+                let err = req_
+                    .error()
+                    .ok()
+                    .flatten()
+                    .map(JsValue::from)
+                    .unwrap_or(JsValue::UNDEFINED);
+                reject.call1(&JsValue::NULL, &err).ok();
+            })
+            .unchecked_ref(),
+        ));
+    });
+    JsFuture::from(promise).await
+}
@@ -1,50 +1,127 @@
+#[cfg(feature = "web")]
 mod use_appearance;
+#[cfg(feature = "web")]
+mod use_auto_lock;
+#[cfg(feature = "web")]
+mod use_benchmarks;
 // mod use_clipboard;
 // mod use_date;
+#[cfg(feature = "web")]
 mod use_explorer;
+#[cfg(feature = "web")]
 mod use_fee_url;
+#[cfg(feature = "web")]
 mod use_gateway;
+mod use_hashrate;
+#[cfg(feature = "web")]
 mod use_is_onboarded;
+#[cfg(feature = "web")]
 mod use_keypair;
+#[cfg(feature = "web")]
 mod use_miner;
+#[cfg(feature = "web")]
 mod use_miner_toolbar_state;
+#[cfg(feature = "web")]
+mod use_mining_schedule;
+#[cfg(feature = "web")]
 mod use_ore_balance;
+#[cfg(feature = "web")]
 mod use_ore_balances;
+#[cfg(feature = "web")]
 mod use_ore_supply;
+#[cfg(feature = "web")]
 mod use_persistent;
+#[cfg(feature = "web")]
+mod use_pool_url;
+#[cfg(feature = "web")]
 mod use_power_level;
 mod use_priority_fee;
+#[cfg(feature = "web")]
 mod use_priority_fee_cap;
 mod use_priority_fee_strategy;
+#[cfg(feature = "web")]
+mod use_profiles;
+#[cfg(feature = "web")]
 mod use_proof;
+#[cfg(feature = "web")]
+mod use_remote_agents;
+#[cfg(feature = "web")]
 mod use_rpc;
+#[cfg(feature = "web")]
 mod use_screen_size;
+#[cfg(feature = "web")]
+mod use_session;
+#[cfg(feature = "web")]
 mod use_show_backup_warning;
+#[cfg(feature = "web")]
 mod use_sol_balance;
+mod use_submit_policy;
 // mod use_transfers;
 // mod use_treasury;
+#[cfg(feature = "web")]
+mod use_wallet_adapter;
+#[cfg(feature = "web")]
+mod use_watchlist;
 
+#[cfg(feature = "web")]
 pub use use_appearance::*;
+#[cfg(feature = "web")]
+pub use use_auto_lock::*;
+#[cfg(feature = "web")]
+pub use use_benchmarks::*;
 // pub use use_clipboard::*;
 // pub use use_date::*;
+#[cfg(feature = "web")]
 pub use use_explorer::*;
+#[cfg(feature = "web")]
 pub use use_fee_url::*;
+#[cfg(feature = "web")]
 pub use use_gateway::*;
+pub use use_hashrate::*;
+#[cfg(feature = "web")]
 pub use use_is_onboarded::*;
+#[cfg(feature = "web")]
 pub use use_keypair::*;
+#[cfg(feature = "web")]
 pub use use_miner::*;
+#[cfg(feature = "web")]
 pub use use_miner_toolbar_state::*;
+#[cfg(feature = "web")]
+pub use use_mining_schedule::*;
+#[cfg(feature = "web")]
 pub use use_ore_balance::*;
+#[cfg(feature = "web")]
 pub use use_ore_balances::*;
+#[cfg(feature = "web")]
 pub use use_ore_supply::*;
+#[cfg(feature = "web")]
+pub use use_pool_url::*;
+#[cfg(feature = "web")]
 pub use use_power_level::*;
 pub use use_priority_fee::*;
+#[cfg(feature = "web")]
 pub use use_priority_fee_cap::*;
 pub use use_priority_fee_strategy::*;
+#[cfg(feature = "web")]
+pub use use_profiles::*;
+#[cfg(feature = "web")]
 pub use use_proof::*;
+#[cfg(feature = "web")]
+pub use use_remote_agents::*;
+#[cfg(feature = "web")]
 pub use use_rpc::*;
+#[cfg(feature = "web")]
 pub use use_screen_size::*;
+#[cfg(feature = "web")]
+pub use use_session::*;
+#[cfg(feature = "web")]
 pub use use_show_backup_warning::*;
+#[cfg(feature = "web")]
 pub use use_sol_balance::*;
+pub use use_submit_policy::*;
 // pub use use_transfers::*;
 // pub use use_treasury::*;
+#[cfg(feature = "web")]
+pub use use_wallet_adapter::*;
+#[cfg(feature = "web")]
+pub use use_watchlist::*;
@@ -0,0 +1,86 @@
+use std::{cell::Cell, rc::Rc};
+
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::{closure::Closure, JsCast};
+use web_sys::window;
+
+use crate::hooks::use_persistent::use_persistent;
+
+const KEY: &str = "auto_lock";
+
+/// Browser events that count as user activity.
+const ACTIVITY_EVENTS: [&str; 4] = ["pointerdown", "pointermove", "keydown", "scroll"];
+
+/// Selectable idle periods in minutes. Zero disables auto-lock.
+pub const AUTO_LOCK_OPTIONS: [u64; 5] = [1, 5, 15, 60, 0];
+
+/// Idle period in minutes after which sensitive pages require re-authentication.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq)]
+pub struct AutoLock(pub u64);
+
+impl Default for AutoLock {
+    fn default() -> Self {
+        AutoLock(15)
+    }
+}
+
+/// Whether sensitive pages are hidden behind a re-authentication prompt.
+/// Unlike a locked keystore, this leaves the keypair in memory so mining keeps running.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum IdleLock {
+    Unlocked,
+    Locked,
+}
+
+pub fn use_auto_lock() -> Signal<AutoLock> {
+    let auto_lock = use_context::<Signal<AutoLock>>();
+    let mut auto_lock_persistent = use_persistent(KEY, AutoLock::default);
+    use_effect(move || auto_lock_persistent.set(*auto_lock.read()));
+    auto_lock
+}
+
+pub fn use_auto_lock_provider() {
+    let auto_lock = use_persistent(KEY, AutoLock::default).get();
+    let auto_lock = use_context_provider(|| Signal::new(auto_lock));
+    let mut idle_lock = use_context_provider(|| Signal::new(IdleLock::Unlocked));
+
+    // Track the time of the last user interaction
+    let last_activity = use_hook(|| {
+        let last_activity = Rc::new(Cell::new(js_sys::Date::now()));
+        let window = window().expect("should have a window");
+        for event in ACTIVITY_EVENTS {
+            let last_activity = last_activity.clone();
+            let closure = Closure::<dyn FnMut()>::new(move || {
+                last_activity.set(js_sys::Date::now());
+            });
+            window
+                .add_event_listener_with_callback(event, closure.as_ref().unchecked_ref())
+                .ok();
+            closure.forget();
+        }
+        last_activity
+    });
+
+    // Lock once the idle period has elapsed
+    use_future(move || {
+        let last_activity = last_activity.clone();
+        async move {
+            loop {
+                async_std::task::sleep(std::time::Duration::from_secs(5)).await;
+                let minutes = auto_lock.peek().0;
+                if minutes.eq(&0) || idle_lock.peek().eq(&IdleLock::Locked) {
+                    continue;
+                }
+                let idle_ms = js_sys::Date::now() - last_activity.get();
+                if idle_ms.gt(&((minutes * 60_000) as f64)) {
+                    idle_lock.set(IdleLock::Locked);
+                }
+            }
+        }
+    });
+}
+
+pub fn use_idle_lock() -> Signal<IdleLock> {
+    use_context::<Signal<IdleLock>>()
+}
@@ -0,0 +1,41 @@
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{hooks::use_persistent::use_persistent, miner::BenchmarkRun};
+
+const KEY: &str = "benchmarks";
+
+/// Most benchmark runs kept.
+pub const MAX_BENCHMARKS: usize = 20;
+
+/// Benchmark runs on this machine, newest first.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct Benchmarks(pub Vec<BenchmarkRun>);
+
+/// The benchmark in progress, if any. Mining cannot start while one runs.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RunningBenchmark(pub Option<BenchmarkRun>);
+
+impl Benchmarks {
+    pub fn push(&mut self, run: BenchmarkRun) {
+        self.0.insert(0, run);
+        self.0.truncate(MAX_BENCHMARKS);
+    }
+}
+
+pub fn use_benchmarks() -> Signal<Benchmarks> {
+    let benchmarks = use_context::<Signal<Benchmarks>>();
+    let mut benchmarks_persistent = use_persistent(KEY, Benchmarks::default);
+    use_effect(move || benchmarks_persistent.set(benchmarks.read().clone()));
+    benchmarks
+}
+
+pub fn use_running_benchmark() -> Signal<RunningBenchmark> {
+    use_context::<Signal<RunningBenchmark>>()
+}
+
+pub fn use_benchmarks_provider() {
+    let benchmarks = use_persistent(KEY, Benchmarks::default).get();
+    use_context_provider(|| Signal::new(benchmarks));
+    use_context_provider(|| Signal::new(RunningBenchmark::default()));
+}
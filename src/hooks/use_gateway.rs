@@ -2,12 +2,26 @@ use std::rc::Rc;
 
 use dioxus::prelude::*;
 
-use crate::gateway::{Gateway, API_URL};
+use crate::gateway::{self, FeeSettings, Gateway, API_URL};
 
-use super::{use_fee_url, use_rpc_url};
+use super::{use_fee_url, use_priority_fee, use_priority_fee_cap, use_rpc_url};
 
 pub fn use_gateway() -> Rc<Gateway> {
     let rpc_url = use_rpc_url().read().0.clone();
     let fee_url = use_fee_url().read().0.clone();
     Rc::new(Gateway::new(API_URL.to_string(), rpc_url, fee_url))
 }
+
+/// Keeps the gateway's fee settings in sync with the user's settings.
+pub fn use_fee_settings_provider() {
+    let fee_url = use_fee_url();
+    let priority_fee = use_priority_fee();
+    let priority_fee_cap = use_priority_fee_cap();
+    use_effect(move || {
+        gateway::set_fee_settings(FeeSettings {
+            fee_url: fee_url.read().0.clone(),
+            priority_fee: priority_fee.read().0,
+            priority_fee_cap: priority_fee_cap.read().0,
+        })
+    });
+}
@@ -0,0 +1,91 @@
+use std::collections::BTreeMap;
+
+#[cfg(feature = "web")]
+use dioxus::prelude::*;
+
+use crate::miner::WebWorkerResponse;
+
+/// Hashes per second of one hasher in the last batch.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WorkerHashrate {
+    pub worker: usize,
+    pub hashes: u64,
+    pub elapsed_ms: u64,
+}
+
+impl WorkerHashrate {
+    pub fn hashrate(&self) -> u64 {
+        self.hashes
+            .saturating_mul(1000)
+            .saturating_div(self.elapsed_ms.max(1))
+    }
+}
+
+/// Mining statistics for this session. Nothing is persisted.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Hashrate {
+    /// Hashers of the last batch, ordered by worker index.
+    pub workers: Vec<WorkerHashrate>,
+    /// Best difficulty of the last batch.
+    pub best_difficulty: u32,
+    /// Number of hasher results at each difficulty since the app was opened.
+    pub histogram: BTreeMap<u32, u64>,
+    /// Hashes computed since the app was opened.
+    pub total_hashes: u64,
+    /// Number of submissions sent to each bus since the app was opened.
+    pub buses: BTreeMap<usize, u64>,
+    /// Bus of the last submission.
+    pub last_bus: Option<usize>,
+}
+
+impl Hashrate {
+    /// Total hashes per second of the last batch. Hashers run in parallel, so their rates add up.
+    pub fn hashrate(&self) -> u64 {
+        self.workers.iter().map(|w| w.hashrate()).sum()
+    }
+
+    /// Records a completed batch. Results from hashers that never ran are skipped.
+    pub fn record(&mut self, messages: &[WebWorkerResponse]) {
+        self.workers = messages
+            .iter()
+            .filter(|msg| msg.hashes.gt(&0))
+            .map(|msg| WorkerHashrate {
+                worker: msg.worker,
+                hashes: msg.hashes,
+                elapsed_ms: msg.elapsed_ms,
+            })
+            .collect();
+        self.workers.sort_by_key(|w| w.worker);
+        self.best_difficulty = 0;
+        for msg in messages.iter().filter(|msg| msg.hashes.gt(&0)) {
+            self.best_difficulty = self.best_difficulty.max(msg.difficulty);
+            *self.histogram.entry(msg.difficulty).or_default() += 1;
+            self.total_hashes = self.total_hashes.saturating_add(msg.hashes);
+        }
+    }
+
+    /// Records the bus a solution was submitted to.
+    pub fn record_bus(&mut self, bus: usize) {
+        *self.buses.entry(bus).or_default() += 1;
+        self.last_bus = Some(bus);
+    }
+}
+
+/// Formats a hashrate with a metric prefix, e.g. `1.25 kH/s`.
+pub fn format_hashrate(hashrate: u64) -> String {
+    match hashrate {
+        0..=999 => format!("{} H/s", hashrate),
+        1_000..=999_999 => format!("{:.2} kH/s", hashrate as f64 / 1_000.0),
+        _ => format!("{:.2} MH/s", hashrate as f64 / 1_000_000.0),
+    }
+}
+
+#[cfg(feature = "web")]
+pub fn use_hashrate() -> Signal<Hashrate> {
+    use_context::<Signal<Hashrate>>()
+}
+
+#[cfg(feature = "web")]
+pub fn use_hashrate_provider() {
+    use_context_provider(|| Signal::new(Hashrate::default()));
+}
@@ -1,21 +1,16 @@
-use solana_client_wasm::solana_sdk::{pubkey::Pubkey, signature::Keypair, signature::Signer};
+use dioxus::prelude::*;
+use solana_client_wasm::solana_sdk::{pubkey::Pubkey, signature::Keypair};
 
-use crate::hooks::use_persistent::use_persistent;
+use crate::keystore;
 
-use super::use_persistent::UsePersistent;
-
-const KEY: &str = "keypair";
+use super::use_session;
 
 pub fn use_pubkey() -> Pubkey {
-    let kp = use_keypair();
-    kp.pubkey()
+    use_session().read().pubkey().unwrap_or_default()
 }
 
 pub fn use_keypair() -> Keypair {
-    let kp_str = use_persistent(KEY, || Keypair::new().to_base58_string());
-    Keypair::from_base58_string(&kp_str.get())
-}
-
-pub fn use_keypair_persistent() -> UsePersistent<String> {
-    use_persistent(KEY, || Keypair::new().to_base58_string())
+    // Subscribe to session changes
+    let _ = use_session().read();
+    keystore::signer()
 }
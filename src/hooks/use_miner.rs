@@ -1,46 +1,138 @@
+use std::time::Duration;
+
 use dioxus::prelude::*;
 use dioxus_sdk::utils::channel::use_channel;
+use rand::Rng;
 
 use super::{
-    use_gateway, use_miner_toolbar_state, use_power_level, use_priority_fee, use_priority_fee_strategy, use_proof, use_pubkey
+    use_gateway, use_hashrate, use_miner_toolbar_state, use_pool_url, use_power_level, use_priority_fee, use_priority_fee_strategy, use_proof, use_remote_agents, use_session, use_submit_policy, MinerStatus, ReadMinerToolbarState, ResetPolicy
+};
+use crate::miner::{
+    hash_backend, needs_reset, seconds_until_reset, send_reset, Miner, RemoteAgents,
+    WebWorkerResponse, WEB_WORKERS,
 };
-use crate::miner::{Miner, WebWorkerResponse, WEB_WORKERS};
+
+/// Longest wait between checks for a due reset.
+const RESET_POLL_SECS: u64 = 60;
+
+/// Most seconds a volunteer waits after the epoch ends before sending a reset.
+const RESET_JITTER_SECS: u64 = 10;
 
 pub fn use_miner() -> Signal<Miner> {
-    let mut cx = use_channel::<WebWorkerResponse>(*WEB_WORKERS);
+    let cx = use_channel::<WebWorkerResponse>(*WEB_WORKERS);
     let mut toolbar_state = use_miner_toolbar_state();
     let mut proof = use_proof();
+    let mut hashrate = use_hashrate();
     let power_level = use_power_level();
     let priority_fee = use_priority_fee();
     let priority_fee_strategy = use_priority_fee_strategy();
-    let pubkey = use_pubkey();
+    let pool_url = use_pool_url();
+    let remote_agents = use_remote_agents();
+    let submit_policy = use_submit_policy();
+    let session = use_session();
     let gateway = use_gateway();
-    let miner = use_signal(|| Miner::new(cx.clone(), power_level, priority_fee, priority_fee_strategy));
+    let miner = use_signal(|| {
+        let hashers = power_level.peek().0.max(1) as usize;
+        Miner::new(
+            hash_backend(cx.clone(), hashers),
+            RemoteAgents::new(cx.clone()),
+            power_level,
+            priority_fee,
+            priority_fee_strategy,
+            pool_url,
+            submit_policy,
+        )
+    });
 
-    // Process web worker results
+    // Grow or shrink the hashers with the power level, without restarting the miner
+    use_effect(move || {
+        let hashers = power_level.read().0.max(1) as usize;
+        miner.peek().resize(hashers);
+    });
+
+    // Connect to hashing agents, and reconnect any that dropped
+    use_future(move || async move {
+        loop {
+            miner.peek().connect_agents(&remote_agents.peek().0);
+            async_std::task::sleep(std::time::Duration::from_secs(10)).await;
+        }
+    });
+    use_effect(move || {
+        let urls = remote_agents.read().0.clone();
+        miner.peek().connect_agents(&urls);
+    });
+
+    // Volunteer resets in their own transaction as soon as the epoch ends
+    let reset_gateway = gateway.clone();
     use_future(move || {
-        let mut rx = cx.receiver();
+        let gateway = reset_gateway.clone();
+        async move {
+            loop {
+                let is_active = matches!(toolbar_state.status(), MinerStatus::Active);
+                let wait = if is_active
+                    && pool_url.peek().is_solo()
+                    && submit_policy.peek().reset_policy.eq(&ResetPolicy::Standalone)
+                {
+                    match seconds_until_reset(&gateway).await {
+                        Some(secs) if secs.le(&0) => {
+                            // Spread volunteers out, so most find the reset already done
+                            let jitter = rand::thread_rng().gen_range(0..RESET_JITTER_SECS);
+                            async_std::task::sleep(Duration::from_secs(jitter)).await;
+                            if needs_reset(&gateway).await {
+                                send_reset(&gateway).await.ok();
+                            }
+                            RESET_POLL_SECS
+                        }
+                        Some(secs) => (secs as u64).min(RESET_POLL_SECS),
+                        None => RESET_POLL_SECS,
+                    }
+                } else {
+                    RESET_POLL_SECS
+                };
+                async_std::task::sleep(Duration::from_secs(wait)).await;
+            }
+        }
+    });
+
+    // Process batch results
+    use_future(move || {
+        let mut rx = miner.peek().results().receiver();
         let gateway = gateway.clone();
         async move {
             let mut power_level = usize::MAX;
             let mut messages = vec![];
             while let Ok(msg) = rx.recv().await {
+                // Shares are posted as they arrive and are not part of the batch
+                if msg.share {
+                    let pubkey = session.peek().pubkey().unwrap_or_default();
+                    miner.read().submit_pool_share(&msg, pubkey);
+                    continue;
+                }
                 if msg.power_level.lt(&power_level) {
                     power_level = msg.power_level;
                 }
+                // A hasher at the target ends the batch early
+                let reached_target = miner.read().reached_target(&msg);
                 messages.push(msg);
-                if messages.len().gt(&power_level) {
+                if reached_target || messages.len().gt(&power_level) {
+                    if reached_target {
+                        miner.read().stop();
+                    }
+                    hashrate.write().record(&messages);
+                    let pubkey = session.peek().pubkey().unwrap_or_default();
                     miner
                         .read()
                         .process_web_worker_results(
                             &messages,
                             &mut toolbar_state,
+                            &mut hashrate,
                             &mut proof,
                             gateway.clone(),
                             pubkey,
                         )
                         .await;
                     messages.clear();
+                    power_level = usize::MAX;
                 }
             }
         }
@@ -48,3 +140,4 @@ pub fn use_miner() -> Signal<Miner> {
 
     miner
 }
+
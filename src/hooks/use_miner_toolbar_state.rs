@@ -1,6 +1,8 @@
 use dioxus::prelude::*;
 use solana_sdk::keccak::Hash as KeccakHash;
 
+use crate::{gateway::SubmitStatus, hooks::SubmitOutcome};
+
 #[derive(Copy, Clone, Debug)]
 pub enum MinerStatus {
     NotStarted,
@@ -22,6 +24,7 @@ pub struct MinerToolbarState {
     pub status: MinerStatus,
     pub status_message: MinerStatusMessage,
     pub display_hash: KeccakHash,
+    pub submit_outcome: Option<SubmitOutcome>,
     // pub is_open: bool,
 }
 
@@ -35,6 +38,7 @@ pub fn use_miner_toolbar_state_provider() {
             status: MinerStatus::NotStarted,
             status_message: MinerStatusMessage::Searching,
             display_hash: KeccakHash::new_unique(),
+            submit_outcome: None,
             // is_open: false,
         })
     });
@@ -44,6 +48,7 @@ pub trait ReadMinerToolbarState {
     fn status(&self) -> MinerStatus;
     fn status_message(&self) -> MinerStatusMessage;
     fn display_hash(&self) -> String;
+    fn submit_outcome(&self) -> Option<SubmitOutcome>;
     // fn is_open(&self) -> bool;
 }
 
@@ -60,6 +65,10 @@ impl ReadMinerToolbarState for Signal<MinerToolbarState> {
         self.read().display_hash.to_string()
     }
 
+    fn submit_outcome(&self) -> Option<SubmitOutcome> {
+        self.read().submit_outcome
+    }
+
     // fn is_open(&self) -> bool {
     //     self.read().is_open
     // }
@@ -68,6 +77,7 @@ impl ReadMinerToolbarState for Signal<MinerToolbarState> {
 pub trait UpdateMinerToolbarState {
     // fn set_is_open(&mut self, is_open: bool);
     fn set_display_hash(&mut self, hash: KeccakHash);
+    fn set_submit_outcome(&mut self, outcome: SubmitOutcome);
     fn set_status_message(&mut self, status_message: MinerStatusMessage);
     fn set_status(&mut self, status: MinerStatus);
     fn start(&mut self);
@@ -81,6 +91,7 @@ impl UpdateMinerToolbarState for Signal<MinerToolbarState> {
             status: MinerStatus::Activating,
             status_message: old.status_message,
             display_hash: old.display_hash,
+            submit_outcome: old.submit_outcome,
             // is_open: true,
         };
         drop(old);
@@ -93,6 +104,7 @@ impl UpdateMinerToolbarState for Signal<MinerToolbarState> {
             status: MinerStatus::NotStarted,
             status_message: old.status_message,
             display_hash: old.display_hash,
+            submit_outcome: old.submit_outcome,
             // is_open: false,
         };
         drop(old);
@@ -117,6 +129,20 @@ impl UpdateMinerToolbarState for Signal<MinerToolbarState> {
             status: old.status,
             status_message: old.status_message,
             display_hash: hash,
+            submit_outcome: old.submit_outcome,
+            // is_open: old.is_open,
+        };
+        drop(old);
+        self.set(new);
+    }
+
+    fn set_submit_outcome(&mut self, outcome: SubmitOutcome) {
+        let old = self.read();
+        let new = MinerToolbarState {
+            status: old.status,
+            status_message: old.status_message,
+            display_hash: old.display_hash,
+            submit_outcome: Some(outcome),
             // is_open: old.is_open,
         };
         drop(old);
@@ -129,6 +155,7 @@ impl UpdateMinerToolbarState for Signal<MinerToolbarState> {
             status: old.status,
             status_message,
             display_hash: old.display_hash,
+            submit_outcome: old.submit_outcome,
             // is_open: old.is_open,
         };
         drop(old);
@@ -141,9 +168,16 @@ impl UpdateMinerToolbarState for Signal<MinerToolbarState> {
             status,
             status_message: old.status_message,
             display_hash: old.display_hash,
+            submit_outcome: old.submit_outcome,
             // is_open: old.is_open,
         };
         drop(old);
         self.set(new);
     }
 }
+
+impl SubmitStatus for Signal<MinerToolbarState> {
+    fn submitting(&mut self, attempt: u64, priority_fee: u64) {
+        self.set_status_message(MinerStatusMessage::Submitting(attempt, priority_fee));
+    }
+}
@@ -0,0 +1,192 @@
+use chrono::{DateTime, Datelike, Local, Timelike};
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    hooks::{
+        use_miner_toolbar_state, use_persistent::use_profile_persistent_provider, use_power_level,
+        use_running_benchmark, MinerStatus, PowerLevel, ReadMinerToolbarState,
+        UpdateMinerToolbarState,
+    },
+    idle,
+};
+
+const KEY: &str = "mining_schedule";
+
+/// Minutes without input before the user counts as idle, by default.
+pub const DEFAULT_IDLE_MINUTES: u64 = 5;
+
+/// Days of the week, starting on Monday.
+pub const WEEKDAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+/// A recurring time window to mine in, in the local timezone.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct ScheduleWindow {
+    /// Days the window starts on, Monday first.
+    pub days: [bool; 7],
+    /// Minutes after midnight.
+    pub start: u32,
+    /// Minutes after midnight. A window that ends before it starts runs past midnight, and one
+    /// that ends when it starts lasts the whole day.
+    pub end: u32,
+    /// Power level to mine with in this window, or the current one if unset.
+    pub power_level: Option<u64>,
+}
+
+impl Default for ScheduleWindow {
+    fn default() -> Self {
+        Self {
+            days: [true; 7],
+            start: 22 * 60,
+            end: 7 * 60,
+            power_level: None,
+        }
+    }
+}
+
+impl ScheduleWindow {
+    pub fn contains(&self, now: &DateTime<Local>) -> bool {
+        let day = now.weekday().num_days_from_monday() as usize;
+        let yesterday = (day + 6) % 7;
+        let minute = now.hour() * 60 + now.minute();
+        if self.start.eq(&self.end) {
+            self.days[day]
+        } else if self.start.lt(&self.end) {
+            self.days[day] && minute.ge(&self.start) && minute.lt(&self.end)
+        } else {
+            (self.days[day] && minute.ge(&self.start))
+                || (self.days[yesterday] && minute.lt(&self.end))
+        }
+    }
+}
+
+/// When the miner starts and pauses on its own.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct MiningSchedule {
+    /// Mine only inside `windows`, starting and pausing at their edges.
+    pub enabled: bool,
+    pub windows: Vec<ScheduleWindow>,
+    /// Pause while this page is visible.
+    pub pause_when_visible: bool,
+    /// Pause while the user is active.
+    pub pause_when_active: bool,
+    pub idle_minutes: u64,
+}
+
+impl Default for MiningSchedule {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            windows: vec![],
+            pause_when_visible: false,
+            pause_when_active: false,
+            idle_minutes: DEFAULT_IDLE_MINUTES,
+        }
+    }
+}
+
+impl MiningSchedule {
+    /// The window that contains `now`, if schedules are enabled.
+    pub fn active_window(&self, now: &DateTime<Local>) -> Option<&ScheduleWindow> {
+        if !self.enabled {
+            return None;
+        }
+        self.windows.iter().find(|window| window.contains(now))
+    }
+}
+
+/// Seconds between schedule checks.
+const SCHEDULER_INTERVAL_SECS: u64 = 5;
+
+/// Starts and pauses the miner according to the schedule and idle rules.
+///
+/// The scheduler acts on changes only, so starting or stopping the miner by hand sticks until
+/// the next window opens or closes. A miner paused by the idle rules resumes once idle again.
+pub fn use_mining_scheduler() {
+    let schedule = use_mining_schedule();
+    let mut toolbar_state = use_miner_toolbar_state();
+    let mut power_level = use_power_level();
+    let running_benchmark = use_running_benchmark();
+    use_future(move || async move {
+        idle::watch_input();
+        let mut in_window = false;
+        let mut held = false;
+        let mut restore_power_level: Option<PowerLevel> = None;
+        loop {
+            let schedule = schedule.peek().clone();
+            let now = Local::now();
+            let window = schedule.active_window(&now);
+            let is_mining = matches!(
+                toolbar_state.status(),
+                MinerStatus::Active | MinerStatus::Activating
+            );
+
+            // Open and close windows
+            match (in_window, window) {
+                (false, Some(window)) => {
+                    in_window = true;
+                    if let Some(level) = window.power_level {
+                        restore_power_level.get_or_insert(*power_level.peek());
+                        power_level.set(PowerLevel(level));
+                    }
+                    if !is_mining {
+                        held = true;
+                    }
+                }
+                (true, None) => {
+                    in_window = false;
+                    held = false;
+                    if let Some(level) = restore_power_level.take() {
+                        power_level.set(level);
+                    }
+                    if is_mining {
+                        toolbar_state.pause();
+                    }
+                }
+                _ => {}
+            }
+
+            // Hold the miner while the user is around
+            let is_idle = (!schedule.pause_when_visible || !idle::is_page_visible())
+                && (!schedule.pause_when_active
+                    || idle::is_user_idle(schedule.idle_minutes.saturating_mul(60_000)));
+            let is_mining = matches!(
+                toolbar_state.status(),
+                MinerStatus::Active | MinerStatus::Activating
+            );
+            if is_mining && !is_idle {
+                toolbar_state.pause();
+                held = true;
+            } else if held
+                && is_idle
+                && (in_window || !schedule.enabled)
+                && running_benchmark.peek().0.is_none()
+            {
+                toolbar_state.start();
+                held = false;
+            }
+
+            async_std::task::sleep(std::time::Duration::from_secs(SCHEDULER_INTERVAL_SECS)).await;
+        }
+    });
+}
+
+/// Formats minutes after midnight as `HH:MM`.
+pub fn format_minutes(minutes: u32) -> String {
+    format!("{:02}:{:02}", minutes / 60, minutes % 60)
+}
+
+/// Parses `HH:MM` into minutes after midnight.
+pub fn parse_minutes(s: &str) -> Option<u32> {
+    let (h, m) = s.split_once(':')?;
+    let (h, m) = (h.parse::<u32>().ok()?, m.parse::<u32>().ok()?);
+    (h.lt(&24) && m.lt(&60)).then_some(h * 60 + m)
+}
+
+pub fn use_mining_schedule() -> Signal<MiningSchedule> {
+    use_context::<Signal<MiningSchedule>>()
+}
+
+pub fn use_mining_schedule_provider() {
+    use_profile_persistent_provider(KEY, MiningSchedule::default);
+}
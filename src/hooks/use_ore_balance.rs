@@ -7,14 +7,15 @@ use crate::gateway::{
     ore_token_account_address, ore_token_account_address_v1, GatewayError, GatewayResult,
 };
 
-use super::{use_gateway, use_pubkey};
+use super::{use_gateway, use_session};
 
 pub fn use_ore_balance() -> Resource<GatewayResult<UiTokenAmount>> {
     let gateway = use_gateway();
-    let pubkey = use_pubkey();
-    let token_account_address = ore_token_account_address(pubkey);
+    let session = use_session();
     use_resource(move || {
         let gateway = gateway.clone();
+        let pubkey = session.read().pubkey().unwrap_or_default();
+        let token_account_address = ore_token_account_address(pubkey);
         async move {
             // MI
             // gateway
@@ -54,10 +55,11 @@ pub fn use_ore_balance() -> Resource<GatewayResult<UiTokenAmount>> {
 
 pub fn use_ore_v1_balance() -> Resource<GatewayResult<UiTokenAmount>> {
     let gateway = use_gateway();
-    let pubkey = use_pubkey();
-    let token_account_address = ore_token_account_address_v1(pubkey);
+    let session = use_session();
     use_resource(move || {
         let gateway = gateway.clone();
+        let pubkey = session.read().pubkey().unwrap_or_default();
+        let token_account_address = ore_token_account_address_v1(pubkey);
         async move {
             // MI
             // gateway
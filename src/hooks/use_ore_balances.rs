@@ -4,15 +4,16 @@ use solana_extra_wasm::account_decoder::parse_token::UiTokenAmount;
 
 use crate::gateway::{ore_token_account_address, ore_token_account_address_v1};
 
-use super::{use_gateway, use_pubkey};
+use super::{use_gateway, use_session};
 
 // MI
 // current dixous only have one resource per hook
 pub fn use_ore_v1_v2_balances() -> Resource<Option<Balances>> {
     let gateway = use_gateway();
-    let pubkey = use_pubkey();
+    let session = use_session();
     use_resource(move || {
         let gateway = gateway.clone();
+        let pubkey = session.read().pubkey().unwrap_or_default();
         async move {
             let token_account_address_v1 = ore_token_account_address_v1(pubkey);
             let token_account_address_v2 = ore_token_account_address(pubkey);
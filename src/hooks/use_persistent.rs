@@ -2,6 +2,10 @@ use dioxus::prelude::*;
 use gloo_storage::{LocalStorage, Storage};
 use serde::{de::DeserializeOwned, Serialize};
 
+use crate::keystore;
+
+use super::use_session;
+
 /// A persistent storage hook that can be used to store data across application reloads.
 #[allow(clippy::needless_return)]
 pub fn use_persistent<T: Serialize + DeserializeOwned + Default + 'static>(
@@ -54,3 +58,29 @@ impl<T: Serialize + DeserializeOwned + Clone + 'static> UsePersistent<T> {
         inner.value = value;
     }
 }
+
+/// Provides a context signal persisted under a key scoped to the active account profile.
+/// The value is reloaded from storage whenever the active profile changes.
+pub fn use_profile_persistent_provider<T: Serialize + DeserializeOwned + Clone + 'static>(
+    key: &'static str,
+    init: impl Fn() -> T + Copy + 'static,
+) {
+    let session = use_session();
+    let load = move || {
+        LocalStorage::get(keystore::profile_key(key))
+            .ok()
+            .unwrap_or_else(init)
+    };
+    let mut value = use_context_provider(|| Signal::new(load()));
+
+    // Reload when switching profiles
+    use_effect(move || {
+        let _ = session.read();
+        value.set(load());
+    });
+
+    // Persist changes to the active profile
+    use_effect(move || {
+        LocalStorage::set(keystore::profile_key(key), &*value.read()).ok();
+    });
+}
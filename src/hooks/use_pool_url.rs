@@ -0,0 +1,28 @@
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::hooks::use_persistent::use_persistent;
+
+const KEY: &str = "pool_url";
+
+/// Url of the mining pool. Empty when mining solo.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct PoolUrl(pub String);
+
+impl PoolUrl {
+    pub fn is_solo(&self) -> bool {
+        self.0.trim().is_empty()
+    }
+}
+
+pub fn use_pool_url() -> Signal<PoolUrl> {
+    let pool_url = use_context::<Signal<PoolUrl>>();
+    let mut pool_url_persistent = use_persistent(KEY, PoolUrl::default);
+    use_effect(move || pool_url_persistent.set(pool_url.read().clone()));
+    pool_url
+}
+
+pub fn use_pool_url_provider() {
+    let pool_url = use_persistent(KEY, PoolUrl::default).get();
+    use_context_provider(|| Signal::new(pool_url));
+}
@@ -1,7 +1,7 @@
 use dioxus::prelude::*;
 use serde::{Deserialize, Serialize};
 
-use crate::{hooks::use_persistent::use_persistent, miner::WEB_WORKERS};
+use crate::{hooks::use_persistent::use_profile_persistent_provider, miner::WEB_WORKERS};
 
 const KEY: &str = "power_level";
 
@@ -9,13 +9,9 @@ const KEY: &str = "power_level";
 pub struct PowerLevel(pub u64);
 
 pub fn use_power_level() -> Signal<PowerLevel> {
-    let power_level = use_context::<Signal<PowerLevel>>();
-    let mut power_level_persistent = use_persistent(KEY, || PowerLevel(*WEB_WORKERS as u64));
-    use_effect(move || power_level_persistent.set(*power_level.read()));
-    power_level
+    use_context::<Signal<PowerLevel>>()
 }
 
 pub fn use_power_level_provider() {
-    let power_level = use_persistent(KEY, || PowerLevel(*WEB_WORKERS as u64)).get();
-    use_context_provider(|| Signal::new(power_level));
+    use_profile_persistent_provider(KEY, || PowerLevel(*WEB_WORKERS as u64));
 }
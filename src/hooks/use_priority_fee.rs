@@ -1,8 +1,11 @@
+#[cfg(feature = "web")]
 use dioxus::prelude::*;
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "web")]
 use crate::hooks::use_persistent::use_persistent;
 
+#[cfg(feature = "web")]
 const KEY: &str = "priority_fee";
 
 pub const DEFAULT_PRIORITY_FEE: u64 = 5000; // microlamports
@@ -11,6 +14,7 @@ pub const DEFAULT_PRIORITY_FEE: u64 = 5000; // microlamports
 pub struct PriorityFee(pub u64);
 
 // initialize to 5000 microlamports 
+#[cfg(feature = "web")]
 pub fn use_priority_fee() -> Signal<PriorityFee> {
     let priority_fee = use_context::<Signal<PriorityFee>>();
     let mut priority_fee_persistent = use_persistent(KEY, || PriorityFee(DEFAULT_PRIORITY_FEE));
@@ -18,6 +22,7 @@ pub fn use_priority_fee() -> Signal<PriorityFee> {
     priority_fee
 }
 
+#[cfg(feature = "web")]
 pub fn use_priority_fee_provider() {
     let priority_fee = use_persistent(KEY, || PriorityFee(DEFAULT_PRIORITY_FEE)).get();
     use_context_provider(|| Signal::new(priority_fee));
@@ -1,17 +1,53 @@
+use std::{fmt, io, str::FromStr};
+
+#[cfg(feature = "web")]
 use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
 
-use crate::{components::PriorityFeeStrategy, hooks::use_persistent::use_persistent};
+#[cfg(feature = "web")]
+use crate::hooks::use_persistent::use_profile_persistent_provider;
 
+#[cfg(feature = "web")]
 const KEY: &str = "priority_fee_strategy";
 
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub enum PriorityFeeStrategy {
+    #[default]
+    Estimate,
+    Static,
+    // Static(/* priority fee: */ u64),
+}
+
+impl FromStr for PriorityFeeStrategy {
+    type Err = io::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Estimate priority fee" => Ok(PriorityFeeStrategy::Estimate),
+            "Static priority fee" => Ok(PriorityFeeStrategy::Static),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Unknown priority fee strategy",
+            )),
+        }
+    }
+}
+
+impl fmt::Display for PriorityFeeStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PriorityFeeStrategy::Estimate => write!(f, "Estimate priority fee"),
+            PriorityFeeStrategy::Static => write!(f, "Static priority fee"),
+        }
+    }
+}
+
+#[cfg(feature = "web")]
 pub fn use_priority_fee_strategy() -> Signal<PriorityFeeStrategy> {
-    let priority_fee_strategy = use_context::<Signal<PriorityFeeStrategy>>();
-    let mut priority_fee_strategy_persistent = use_persistent(KEY, || PriorityFeeStrategy::Estimate);
-    use_effect(move || priority_fee_strategy_persistent.set(*priority_fee_strategy.read()));
-    priority_fee_strategy
+    use_context::<Signal<PriorityFeeStrategy>>()
 }
 
+#[cfg(feature = "web")]
 pub fn use_priority_fee_strategy_provider() {
-    let priority_fee_strategy = use_persistent(KEY, || PriorityFeeStrategy::Estimate).get();
-    use_context_provider(|| Signal::new(priority_fee_strategy));
+    use_profile_persistent_provider(KEY, || PriorityFeeStrategy::Estimate);
 }
@@ -0,0 +1,18 @@
+use dioxus::prelude::*;
+
+use crate::keystore::{self, Profile};
+
+use super::use_session;
+
+pub fn use_profiles() -> Signal<Vec<Profile>> {
+    use_context::<Signal<Vec<Profile>>>()
+}
+
+pub fn use_profiles_provider() {
+    let session = use_session();
+    let mut profiles = use_context_provider(|| Signal::new(keystore::profiles()));
+    use_effect(move || {
+        let _ = session.read();
+        profiles.set(keystore::profiles());
+    });
+}
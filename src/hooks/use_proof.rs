@@ -4,11 +4,16 @@ use solana_client_wasm::solana_sdk::pubkey::Pubkey;
 
 use crate::gateway::GatewayResult;
 
-use super::{use_gateway, use_pubkey};
+use super::{use_gateway, use_session};
 
 pub fn use_proof() -> Resource<GatewayResult<Proof>> {
-    let authority = use_pubkey();
-    use_user_proof(authority)
+    let session = use_session();
+    let gateway = use_gateway();
+    use_resource(move || {
+        let gateway = gateway.clone();
+        let authority = session.read().pubkey().unwrap_or_default();
+        async move { gateway.get_proof(authority).await }
+    })
 }
 
 pub fn use_user_proof(authority: Pubkey) -> Resource<GatewayResult<Proof>> {
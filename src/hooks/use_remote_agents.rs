@@ -0,0 +1,22 @@
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::hooks::use_persistent::use_persistent;
+
+const KEY: &str = "remote_agents";
+
+/// WebSocket urls of the native hashing agents to mine with.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct RemoteAgentUrls(pub Vec<String>);
+
+pub fn use_remote_agents() -> Signal<RemoteAgentUrls> {
+    let remote_agents = use_context::<Signal<RemoteAgentUrls>>();
+    let mut remote_agents_persistent = use_persistent(KEY, RemoteAgentUrls::default);
+    use_effect(move || remote_agents_persistent.set(remote_agents.read().clone()));
+    remote_agents
+}
+
+pub fn use_remote_agents_provider() {
+    let remote_agents = use_persistent(KEY, RemoteAgentUrls::default).get();
+    use_context_provider(|| Signal::new(remote_agents));
+}
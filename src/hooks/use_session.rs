@@ -0,0 +1,35 @@
+use dioxus::prelude::*;
+use solana_client_wasm::solana_sdk::pubkey::Pubkey;
+
+use crate::keystore;
+
+/// Whether the keystore has an unlocked keypair the app can sign with.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SessionStatus {
+    Locked,
+    Unlocked(Pubkey),
+}
+
+impl SessionStatus {
+    pub fn pubkey(&self) -> Option<Pubkey> {
+        match self {
+            SessionStatus::Locked => None,
+            SessionStatus::Unlocked(pubkey) => Some(*pubkey),
+        }
+    }
+}
+
+pub fn use_session() -> Signal<SessionStatus> {
+    use_context::<Signal<SessionStatus>>()
+}
+
+pub fn use_session_provider() {
+    use_context_provider(|| {
+        // Encrypted keypairs wait for the unlock screen. Plaintext keypairs open right away.
+        if keystore::is_encrypted() {
+            Signal::new(SessionStatus::Locked)
+        } else {
+            Signal::new(SessionStatus::Unlocked(keystore::open()))
+        }
+    });
+}
@@ -2,13 +2,14 @@ use dioxus::prelude::*;
 
 use crate::gateway::{GatewayError, GatewayResult};
 
-use super::{use_gateway, use_pubkey};
+use super::{use_gateway, use_session};
 
 pub fn use_sol_balance() -> Resource<GatewayResult<u64>> {
-    let address = use_pubkey();
+    let session = use_session();
     let gateway = use_gateway();
     use_resource(move || {
         let gateway = gateway.clone();
+        let address = session.read().pubkey().unwrap_or_default();
         async move {
             // MI
             // gateway
@@ -0,0 +1,234 @@
+use std::{fmt, io, str::FromStr};
+
+#[cfg(feature = "web")]
+use dioxus::prelude::*;
+use ore_api::consts::TOLERANCE;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "web")]
+use crate::hooks::use_persistent::use_profile_persistent_provider;
+
+#[cfg(feature = "web")]
+const KEY: &str = "submit_policy";
+
+/// Seconds before the deadline to send the transaction by default.
+pub const DEFAULT_SAFETY_MARGIN: u64 = 5;
+
+/// Largest safety margin. The program rejects a solution sent earlier than this before the
+/// deadline as spam.
+pub const MAX_SAFETY_MARGIN: u64 = TOLERANCE as u64;
+
+/// When the miner stops hashing and submits its best solution.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub enum SubmitMode {
+    /// Hash until the safety margin before the deadline, then submit.
+    #[default]
+    Deadline,
+    /// Stop hashing once the target difficulty is found and submit as soon as the program accepts it.
+    Target,
+    /// Hash for the full minute and submit at the deadline.
+    FullMinute,
+}
+
+impl FromStr for SubmitMode {
+    type Err = io::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Submit before deadline" => Ok(SubmitMode::Deadline),
+            "Submit at target" => Ok(SubmitMode::Target),
+            "Wait full minute" => Ok(SubmitMode::FullMinute),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Unknown submit mode",
+            )),
+        }
+    }
+}
+
+impl fmt::Display for SubmitMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SubmitMode::Deadline => write!(f, "Submit before deadline"),
+            SubmitMode::Target => write!(f, "Submit at target"),
+            SubmitMode::FullMinute => write!(f, "Wait full minute"),
+        }
+    }
+}
+
+/// How the miner picks the bus to mine against.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub enum BusStrategy {
+    /// Any bus, uniformly at random.
+    #[default]
+    Random,
+    /// The bus with the most rewards left this epoch.
+    Richest,
+    /// The bus with the lowest recent priority fees.
+    LeastContended,
+    /// Each bus in turn.
+    RoundRobin,
+}
+
+impl FromStr for BusStrategy {
+    type Err = io::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Random bus" => Ok(BusStrategy::Random),
+            "Richest bus" => Ok(BusStrategy::Richest),
+            "Least contended bus" => Ok(BusStrategy::LeastContended),
+            "Round robin" => Ok(BusStrategy::RoundRobin),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Unknown bus strategy",
+            )),
+        }
+    }
+}
+
+impl fmt::Display for BusStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BusStrategy::Random => write!(f, "Random bus"),
+            BusStrategy::Richest => write!(f, "Richest bus"),
+            BusStrategy::LeastContended => write!(f, "Least contended bus"),
+            BusStrategy::RoundRobin => write!(f, "Round robin"),
+        }
+    }
+}
+
+/// Whether this miner pays to reset the epoch when a reset is due.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub enum ResetPolicy {
+    /// Add the reset instruction to the mine transaction.
+    #[default]
+    Include,
+    /// Send the reset in its own transaction as soon as it is due.
+    Standalone,
+    /// Leave resets to other miners. A mine that fails on a pending reset still resets itself.
+    Wait,
+}
+
+impl FromStr for ResetPolicy {
+    type Err = io::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Reset with mine" => Ok(ResetPolicy::Include),
+            "Reset separately" => Ok(ResetPolicy::Standalone),
+            "Let others reset" => Ok(ResetPolicy::Wait),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Unknown reset policy",
+            )),
+        }
+    }
+}
+
+impl fmt::Display for ResetPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ResetPolicy::Include => write!(f, "Reset with mine"),
+            ResetPolicy::Standalone => write!(f, "Reset separately"),
+            ResetPolicy::Wait => write!(f, "Let others reset"),
+        }
+    }
+}
+
+/// User policy for submitting solutions.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq)]
+pub struct SubmitPolicy {
+    pub mode: SubmitMode,
+    /// Difficulty that ends hashing early in `Target` mode.
+    pub target_difficulty: u32,
+    /// Solutions below this, or below the program minimum, are not submitted and hashing continues.
+    pub min_difficulty: u32,
+    /// Seconds before the deadline to send the transaction, so it lands in time. At most
+    /// [`MAX_SAFETY_MARGIN`].
+    pub safety_margin: u64,
+    #[serde(default)]
+    pub bus_strategy: BusStrategy,
+    #[serde(default)]
+    pub reset_policy: ResetPolicy,
+}
+
+impl Default for SubmitPolicy {
+    fn default() -> Self {
+        Self {
+            mode: SubmitMode::Deadline,
+            target_difficulty: 0,
+            min_difficulty: 0,
+            safety_margin: DEFAULT_SAFETY_MARGIN,
+            bus_strategy: BusStrategy::Random,
+            reset_policy: ResetPolicy::Include,
+        }
+    }
+}
+
+impl SubmitPolicy {
+    /// Seconds to hash, given the seconds left until the deadline.
+    pub fn cutoff_time(&self, deadline: u64) -> u64 {
+        match self.mode {
+            SubmitMode::FullMinute => deadline,
+            SubmitMode::Deadline | SubmitMode::Target => {
+                deadline.saturating_sub(self.safety_margin.min(MAX_SAFETY_MARGIN))
+            }
+        }
+    }
+
+    /// Difficulty at which hashers stop early, if any.
+    pub fn target(&self) -> Option<u32> {
+        match self.mode {
+            SubmitMode::Target if self.target_difficulty.gt(&0) => Some(self.target_difficulty),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for SubmitPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.mode {
+            SubmitMode::Deadline => write!(f, "Submit {}s before deadline", self.safety_margin),
+            SubmitMode::Target => write!(f, "Target difficulty {}", self.target_difficulty),
+            SubmitMode::FullMinute => write!(f, "Full minute"),
+        }?;
+        if self.min_difficulty.gt(&0) {
+            write!(f, ", min {}", self.min_difficulty)?;
+        }
+        Ok(())
+    }
+}
+
+/// What the policy did with the last batch.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SubmitOutcome {
+    /// The target was found before the deadline.
+    TargetReached(u32),
+    /// The best solution was submitted at the deadline.
+    Submitted(u32),
+    /// The best solution was below the minimum, so hashing continues.
+    BelowMinimum(u32, u32),
+}
+
+impl fmt::Display for SubmitOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SubmitOutcome::TargetReached(d) => write!(f, "Target reached with difficulty {}", d),
+            SubmitOutcome::Submitted(d) => write!(f, "Submitted difficulty {}", d),
+            SubmitOutcome::BelowMinimum(d, min) => {
+                write!(f, "Best difficulty {} below {}, still hashing", d, min)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "web")]
+pub fn use_submit_policy() -> Signal<SubmitPolicy> {
+    use_context::<Signal<SubmitPolicy>>()
+}
+
+#[cfg(feature = "web")]
+pub fn use_submit_policy_provider() {
+    use_profile_persistent_provider(KEY, SubmitPolicy::default);
+}
@@ -0,0 +1,85 @@
+use dioxus::prelude::*;
+use ore_api::consts::{TOKEN_DECIMALS, TOKEN_DECIMALS_V1};
+use solana_client_wasm::solana_sdk::pubkey::Pubkey;
+use solana_extra_wasm::account_decoder::parse_token::UiTokenAmount;
+
+use crate::{
+    gateway::{ore_token_account_address, ore_token_account_address_v1},
+    wallet_adapter,
+};
+
+use super::{use_gateway, Balances, UiTokenAmountDefault};
+
+/// External wallet connected through the wallet adapter bridge.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WalletAdapter {
+    Disconnected,
+    Connected(Pubkey),
+}
+
+impl WalletAdapter {
+    pub fn pubkey(&self) -> Option<Pubkey> {
+        match self {
+            WalletAdapter::Disconnected => None,
+            WalletAdapter::Connected(pubkey) => Some(*pubkey),
+        }
+    }
+}
+
+/// Where the funds for an upgrade or stake come from.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FundingSource {
+    Miner,
+    Wallet(Pubkey),
+}
+
+pub fn use_wallet_adapter() -> Signal<WalletAdapter> {
+    use_context::<Signal<WalletAdapter>>()
+}
+
+pub fn use_wallet_adapter_provider() {
+    let mut wallet_adapter = use_context_provider(|| Signal::new(WalletAdapter::Disconnected));
+    use_hook(move || {
+        wallet_adapter::on_wallet_change(move |pubkey| match pubkey {
+            Some(pubkey) => wallet_adapter.set(WalletAdapter::Connected(pubkey)),
+            None => wallet_adapter.set(WalletAdapter::Disconnected),
+        })
+    });
+}
+
+pub fn use_wallet_sol_balance() -> Resource<Option<u64>> {
+    let wallet_adapter = use_wallet_adapter();
+    let gateway = use_gateway();
+    use_resource(move || {
+        let gateway = gateway.clone();
+        async move {
+            let pubkey = wallet_adapter.read().pubkey()?;
+            gateway.rpc.get_balance(&pubkey).await.ok()
+        }
+    })
+}
+
+pub fn use_wallet_ore_balances() -> Resource<Option<Balances>> {
+    let wallet_adapter = use_wallet_adapter();
+    let gateway = use_gateway();
+    use_resource(move || {
+        let gateway = gateway.clone();
+        async move {
+            let pubkey = wallet_adapter.read().pubkey()?;
+            let balance_v1 = gateway
+                .rpc
+                .get_token_account_balance(&ore_token_account_address_v1(pubkey))
+                .await
+                .unwrap_or(UiTokenAmount::default(TOKEN_DECIMALS_V1));
+            let balance_v2 = gateway
+                .rpc
+                .get_token_account_balance(&ore_token_account_address(pubkey))
+                .await
+                .unwrap_or(UiTokenAmount::default(TOKEN_DECIMALS));
+            Some(Balances {
+                v1: balance_v1,
+                v2: balance_v2,
+            })
+        }
+    })
+}
@@ -0,0 +1,116 @@
+use dioxus::prelude::*;
+use futures::future::join_all;
+use ore_api::state::Proof;
+use serde::{Deserialize, Serialize};
+use solana_client_wasm::solana_sdk::pubkey::Pubkey;
+
+use crate::{gateway::ore_token_account_address, hooks::use_persistent::use_persistent};
+
+use super::{use_gateway, UiTokenAmountBalance};
+
+const KEY: &str = "watchlist";
+
+/// Minutes without a submitted hash after which a miner is shown as stale.
+pub const DEFAULT_STALE_MINUTES: u64 = 5;
+
+/// A watch-only miner address tracked on the dashboard.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct WatchedAccount {
+    pub pubkey: Pubkey,
+    pub label: String,
+    pub group: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct Watchlist {
+    pub accounts: Vec<WatchedAccount>,
+    pub stale_minutes: u64,
+}
+
+impl Default for Watchlist {
+    fn default() -> Self {
+        Watchlist {
+            accounts: vec![],
+            stale_minutes: DEFAULT_STALE_MINUTES,
+        }
+    }
+}
+
+impl Watchlist {
+    pub fn contains(&self, pubkey: &Pubkey) -> bool {
+        self.accounts.iter().any(|a| a.pubkey.eq(pubkey))
+    }
+
+    /// Group names in display order.
+    pub fn groups(&self) -> Vec<String> {
+        let mut groups: Vec<String> = self.accounts.iter().map(|a| a.group.clone()).collect();
+        groups.sort();
+        groups.dedup();
+        groups
+    }
+}
+
+/// On-chain stats of a watched account.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WatchedStats {
+    pub pubkey: Pubkey,
+    pub sol_balance: Option<u64>,
+    pub ore_balance: Option<u64>,
+    pub proof: Option<Proof>,
+    pub multiplier: Option<f64>,
+    /// Seconds since the account last submitted a hash.
+    pub last_hash_age: Option<i64>,
+}
+
+pub fn use_watchlist() -> Signal<Watchlist> {
+    let watchlist = use_context::<Signal<Watchlist>>();
+    let mut watchlist_persistent = use_persistent(KEY, Watchlist::default);
+    use_effect(move || watchlist_persistent.set(watchlist.read().clone()));
+    watchlist
+}
+
+pub fn use_watchlist_provider() {
+    let watchlist = use_persistent(KEY, Watchlist::default).get();
+    use_context_provider(|| Signal::new(watchlist));
+}
+
+pub fn use_watchlist_stats() -> Resource<Vec<WatchedStats>> {
+    let watchlist = use_watchlist();
+    let gateway = use_gateway();
+    use_resource(move || {
+        let gateway = gateway.clone();
+        let pubkeys: Vec<Pubkey> = watchlist.read().accounts.iter().map(|a| a.pubkey).collect();
+        async move {
+            let config = gateway.get_config().await.ok();
+            let now = gateway.get_clock().await.ok().map(|clock| clock.unix_timestamp);
+            join_all(pubkeys.into_iter().map(|pubkey| {
+                let gateway = gateway.clone();
+                async move {
+                    let sol_balance = gateway.rpc.get_balance(&pubkey).await.ok();
+                    let ore_balance = gateway
+                        .rpc
+                        .get_token_account_balance(&ore_token_account_address(pubkey))
+                        .await
+                        .ok()
+                        .map(|b| b.balance());
+                    let proof = gateway.get_proof(pubkey).await.ok();
+                    let multiplier = proof.zip(config).map(|(proof, config)| {
+                        1.0 + (proof.balance as f64 / config.top_balance as f64).min(1.0f64)
+                    });
+                    let last_hash_age = proof
+                        .zip(now)
+                        .map(|(proof, now)| now.saturating_sub(proof.last_hash_at));
+                    WatchedStats {
+                        pubkey,
+                        sol_balance,
+                        ore_balance,
+                        proof,
+                        multiplier,
+                        last_hash_age,
+                    }
+                }
+            }))
+            .await
+        }
+    })
+}
@@ -0,0 +1,102 @@
+use std::cell::{Cell, RefCell};
+
+use js_sys::{Promise, Reflect};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+
+// Bindings to the browser's Idle Detection API. Only some browsers ship it, and it needs
+// permission, so the page's own input events are the fallback.
+#[wasm_bindgen]
+extern "C" {
+    type IdleDetector;
+
+    #[wasm_bindgen(constructor, catch)]
+    fn new() -> Result<IdleDetector, JsValue>;
+
+    #[wasm_bindgen(method, getter, js_name = userState)]
+    fn user_state(this: &IdleDetector) -> Option<String>;
+
+    #[wasm_bindgen(method)]
+    fn start(this: &IdleDetector, options: &JsValue) -> Promise;
+
+    #[wasm_bindgen(static_method_of = IdleDetector, js_name = requestPermission)]
+    fn request_permission() -> Promise;
+}
+
+/// Shortest threshold the Idle Detection API accepts.
+const MIN_DETECTOR_THRESHOLD_MS: u64 = 60_000;
+
+thread_local! {
+    static LAST_INPUT: Cell<f64> = Cell::new(js_sys::Date::now());
+    static WATCHING_INPUT: Cell<bool> = const { Cell::new(false) };
+    static DETECTOR: RefCell<Option<IdleDetector>> = const { RefCell::new(None) };
+}
+
+/// Returns true if the browser can tell when the whole machine is idle.
+pub fn is_detector_supported() -> bool {
+    Reflect::has(&js_sys::global(), &"IdleDetector".into()).unwrap_or(false)
+}
+
+/// Returns true once system idle detection is running.
+pub fn is_detector_running() -> bool {
+    DETECTOR.with(|detector| detector.borrow().is_some())
+}
+
+/// Asks for permission and starts system idle detection. Must be called from a user gesture.
+pub async fn start_detector(threshold_ms: u64) -> Result<(), JsValue> {
+    let state = JsFuture::from(IdleDetector::request_permission()).await?;
+    if state.as_string().as_deref().ne(&Some("granted")) {
+        return Err(JsValue::from_str("Idle detection permission denied"));
+    }
+    let detector = IdleDetector::new()?;
+    let options = js_sys::Object::new();
+    Reflect::set(
+        &options,
+        &"threshold".into(),
+        &(threshold_ms.max(MIN_DETECTOR_THRESHOLD_MS) as f64).into(),
+    )?;
+    JsFuture::from(detector.start(&options)).await?;
+    DETECTOR.with(|d| d.replace(Some(detector)));
+    Ok(())
+}
+
+/// Starts tracking input on this page, for browsers without idle detection.
+pub fn watch_input() {
+    if WATCHING_INPUT.with(|w| w.replace(true)) {
+        return;
+    }
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    let on_input = Closure::<dyn Fn()>::new(|| {
+        LAST_INPUT.with(|t| t.set(js_sys::Date::now()));
+    })
+    .into_js_value();
+    for event in ["pointermove", "pointerdown", "keydown", "wheel", "touchstart"] {
+        window
+            .add_event_listener_with_callback(event, on_input.unchecked_ref())
+            .ok();
+    }
+}
+
+/// Returns true if the user has been idle for at least `threshold_ms`.
+///
+/// Uses system idle detection once started, otherwise input on this page.
+pub fn is_user_idle(threshold_ms: u64) -> bool {
+    let state = DETECTOR.with(|d| d.borrow().as_ref().map(|d| d.user_state()));
+    match state {
+        Some(state) => state.as_deref().eq(&Some("idle")),
+        None => {
+            let elapsed = js_sys::Date::now() - LAST_INPUT.with(|t| t.get());
+            elapsed.ge(&(threshold_ms as f64))
+        }
+    }
+}
+
+/// Returns true if this page is visible.
+pub fn is_page_visible() -> bool {
+    web_sys::window()
+        .and_then(|window| window.document())
+        .map(|document| !document.hidden())
+        .unwrap_or(true)
+}
@@ -0,0 +1,145 @@
+use std::collections::BTreeMap;
+
+use gloo_storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use solana_client_wasm::solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+
+use super::{
+    add_profile, decrypt, encrypt, profiles, read_keypair, rename_profile, scoped_key,
+    session_vault_key, EncryptedSecret, KeystoreError, KeystoreResult, Vault,
+};
+
+const BACKUP_VERSION: u8 = 1;
+
+/// Storage keys of the app-wide settings carried in a backup.
+const SETTINGS_KEYS: [&str; 8] = [
+    "appearance",
+    "auto_lock",
+    "explorer",
+    "fee_url",
+    "priority_fee",
+    "priority_fee_cap",
+    "rpc",
+    "watchlist",
+];
+
+/// Storage keys of the settings kept per profile.
+const PROFILE_SETTINGS_KEYS: [&str; 2] = ["power_level", "priority_fee_strategy"];
+
+/// Passphrase-encrypted backup file. The payload is a serialized [`BackupPayload`].
+#[derive(Debug, Deserialize, Serialize)]
+pub struct BackupFile {
+    pub version: u8,
+    pub vault: Vault,
+    pub payload: EncryptedSecret,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct BackupPayload {
+    pub profiles: Vec<BackupProfile>,
+    pub settings: BTreeMap<String, Value>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct BackupProfile {
+    pub name: String,
+    pub keypair: Vec<u8>,
+    pub settings: BTreeMap<String, Value>,
+}
+
+impl BackupProfile {
+    pub fn pubkey(&self) -> Option<Pubkey> {
+        Keypair::from_bytes(&self.keypair).ok().map(|kp| kp.pubkey())
+    }
+}
+
+impl BackupPayload {
+    pub fn contains(&self, pubkey: &Pubkey) -> bool {
+        self.profiles.iter().any(|p| p.pubkey().eq(&Some(*pubkey)))
+    }
+}
+
+/// Serializes every profile and the app settings into an encrypted backup file.
+pub fn create_backup(passphrase: &str) -> KeystoreResult<String> {
+    let vault_key = session_vault_key()?;
+    let mut backup_profiles = vec![];
+    for profile in profiles() {
+        let keypair = read_keypair(&profile.id, vault_key.as_ref())?;
+        backup_profiles.push(BackupProfile {
+            name: profile.name,
+            keypair: keypair.to_bytes().to_vec(),
+            settings: read_settings(&PROFILE_SETTINGS_KEYS, |key| scoped_key(key, &profile.id)),
+        });
+    }
+    let payload = BackupPayload {
+        profiles: backup_profiles,
+        settings: read_settings(&SETTINGS_KEYS, |key| key.to_string()),
+    };
+    let plaintext = serde_json::to_vec(&payload).or(Err(KeystoreError::Corrupted))?;
+    let vault = Vault::new();
+    let backup = BackupFile {
+        version: BACKUP_VERSION,
+        payload: encrypt(&vault.derive_key(passphrase)?, &plaintext)?,
+        vault,
+    };
+    serde_json::to_string_pretty(&backup).or(Err(KeystoreError::Corrupted))
+}
+
+/// Decrypts a backup file without restoring anything.
+pub fn open_backup(contents: &str, passphrase: &str) -> KeystoreResult<BackupPayload> {
+    let backup: BackupFile = serde_json::from_str(contents).or(Err(KeystoreError::Corrupted))?;
+    if backup.version.ne(&BACKUP_VERSION) {
+        return Err(KeystoreError::Corrupted);
+    }
+    let vault_key = backup.vault.derive_key(passphrase)?;
+    let plaintext = decrypt(&vault_key, &backup.payload)?;
+    serde_json::from_slice(&plaintext).or(Err(KeystoreError::Corrupted))
+}
+
+/// Adds the backed up profiles and writes back their settings.
+/// Returns the pubkey of the first restored profile, which becomes active.
+pub fn restore_backup(payload: &BackupPayload) -> KeystoreResult<Pubkey> {
+    let mut first = None;
+    for profile in payload.profiles.iter().rev() {
+        let keypair = Keypair::from_bytes(&profile.keypair).or(Err(KeystoreError::Corrupted))?;
+        let pubkey = add_profile(keypair)?;
+        let id = profiles()
+            .into_iter()
+            .find(|p| p.pubkey.eq(&pubkey))
+            .map(|p| p.id)
+            .ok_or(KeystoreError::NotFound)?;
+        if !profile.name.is_empty() {
+            rename_profile(&id, &profile.name)?;
+        }
+        write_settings(&profile.settings, &PROFILE_SETTINGS_KEYS, |key| {
+            scoped_key(key, &id)
+        });
+        first = Some(pubkey);
+    }
+    write_settings(&payload.settings, &SETTINGS_KEYS, |key| key.to_string());
+    first.ok_or(KeystoreError::NotFound)
+}
+
+fn read_settings(keys: &[&str], storage_key: impl Fn(&str) -> String) -> BTreeMap<String, Value> {
+    keys.iter()
+        .filter_map(|key| {
+            LocalStorage::get::<Value>(storage_key(key))
+                .ok()
+                .map(|value| (key.to_string(), value))
+        })
+        .collect()
+}
+
+/// Only known setting keys are written, so a crafted backup cannot overwrite the keystore.
+fn write_settings(
+    settings: &BTreeMap<String, Value>,
+    keys: &[&str],
+    storage_key: impl Fn(&str) -> String,
+) {
+    for (key, value) in settings {
+        if keys.contains(&key.as_str()) {
+            LocalStorage::set(storage_key(key), value).ok();
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use solana_client_wasm::solana_sdk::{bs58, signature::Keypair};
+
+use super::{KeystoreError, KeystoreResult};
+
+/// Serializes the keypair in the JSON byte-array format used by `solana-keygen` and `ore-cli`.
+pub fn keypair_to_json(keypair: &Keypair) -> String {
+    serde_json::to_string(&keypair.to_bytes().to_vec()).expect("bytes serialize")
+}
+
+/// Parses a secret key given either as base58 text or as a Solana CLI JSON byte array.
+pub fn parse_secret_key(input: &str) -> KeystoreResult<Keypair> {
+    let input = input.trim();
+    let bytes = if input.starts_with('[') {
+        serde_json::from_str::<Vec<u8>>(input).or(Err(KeystoreError::Corrupted))?
+    } else {
+        bs58::decode(input)
+            .into_vec()
+            .or(Err(KeystoreError::Corrupted))?
+    };
+    Keypair::from_bytes(&bytes).or(Err(KeystoreError::Corrupted))
+}
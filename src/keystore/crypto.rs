@@ -0,0 +1,82 @@
+use aes_gcm::{
+    aead::{Aead, KeyInit},
+    Aes256Gcm, Key, Nonce,
+};
+use base64::{prelude::BASE64_STANDARD, Engine};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+use super::{KeystoreError, KeystoreResult};
+
+pub const PBKDF2_ITERATIONS: u32 = 310_000;
+const SALT_LENGTH: usize = 16;
+const NONCE_LENGTH: usize = 12;
+
+/// Symmetric key derived from a passphrase.
+pub type VaultKey = [u8; 32];
+
+/// Key derivation parameters. A vault is created once, when the user sets a passphrase.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct Vault {
+    pub salt: String,
+    pub iterations: u32,
+}
+
+impl Vault {
+    pub fn new() -> Self {
+        let mut salt = [0u8; SALT_LENGTH];
+        rand::thread_rng().fill_bytes(&mut salt);
+        Vault {
+            salt: BASE64_STANDARD.encode(salt),
+            iterations: PBKDF2_ITERATIONS,
+        }
+    }
+
+    pub fn derive_key(&self, passphrase: &str) -> KeystoreResult<VaultKey> {
+        let salt = BASE64_STANDARD
+            .decode(&self.salt)
+            .or(Err(KeystoreError::Corrupted))?;
+        let mut key = [0u8; 32];
+        pbkdf2::pbkdf2_hmac::<Sha256>(passphrase.as_bytes(), &salt, self.iterations, &mut key);
+        Ok(key)
+    }
+}
+
+/// AES-256-GCM ciphertext and the nonce it was sealed with.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct EncryptedSecret {
+    pub nonce: String,
+    pub ciphertext: String,
+}
+
+pub fn encrypt(key: &VaultKey, plaintext: &[u8]) -> KeystoreResult<EncryptedSecret> {
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key));
+    let mut nonce = [0u8; NONCE_LENGTH];
+    rand::thread_rng().fill_bytes(&mut nonce);
+    let ciphertext = cipher
+        .encrypt(Nonce::from_slice(&nonce), plaintext)
+        .or(Err(KeystoreError::Corrupted))?;
+    Ok(EncryptedSecret {
+        nonce: BASE64_STANDARD.encode(nonce),
+        ciphertext: BASE64_STANDARD.encode(ciphertext),
+    })
+}
+
+pub fn decrypt(key: &VaultKey, secret: &EncryptedSecret) -> KeystoreResult<Vec<u8>> {
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key));
+    let nonce = BASE64_STANDARD
+        .decode(&secret.nonce)
+        .or(Err(KeystoreError::Corrupted))?;
+    let ciphertext = BASE64_STANDARD
+        .decode(&secret.ciphertext)
+        .or(Err(KeystoreError::Corrupted))?;
+    if nonce.len().ne(&NONCE_LENGTH) {
+        return Err(KeystoreError::Corrupted);
+    }
+
+    // Authentication fails if the key was derived from the wrong passphrase
+    cipher
+        .decrypt(Nonce::from_slice(&nonce), ciphertext.as_slice())
+        .or(Err(KeystoreError::InvalidPassphrase))
+}
@@ -0,0 +1,120 @@
+use std::fmt;
+
+use bip39::{Language, Mnemonic};
+use rand::RngCore;
+use solana_client_wasm::solana_sdk::{
+    derivation_path::DerivationPath,
+    signature::Keypair,
+    signer::keypair::{
+        generate_seed_from_seed_phrase_and_passphrase, keypair_from_seed,
+        keypair_from_seed_and_derivation_path,
+    },
+};
+
+use super::{KeystoreError, KeystoreResult};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WordCount {
+    Twelve,
+    TwentyFour,
+}
+
+impl WordCount {
+    fn entropy_bytes(&self) -> usize {
+        match self {
+            WordCount::Twelve => 16,
+            WordCount::TwentyFour => 32,
+        }
+    }
+}
+
+/// Common schemes wallets use to derive Solana keypairs from a seed phrase.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DerivationScheme {
+    /// `m/44'/501'/x'/0'`, used by Phantom, Solflare and Backpack.
+    Bip44Change,
+    /// `m/44'/501'/x'`, used by Ledger and older web wallets.
+    Bip44,
+    /// The seed itself, used by `solana-keygen` without a derivation path.
+    Root,
+}
+
+pub const DERIVATION_SCHEMES: [DerivationScheme; 3] = [
+    DerivationScheme::Bip44Change,
+    DerivationScheme::Bip44,
+    DerivationScheme::Root,
+];
+
+impl DerivationScheme {
+    pub fn path(&self, index: u32) -> Option<String> {
+        match self {
+            DerivationScheme::Bip44Change => Some(format!("m/44'/501'/{}'/0'", index)),
+            DerivationScheme::Bip44 => Some(format!("m/44'/501'/{}'", index)),
+            DerivationScheme::Root => None,
+        }
+    }
+
+    /// The root scheme yields a single keypair per seed phrase.
+    pub fn is_indexed(&self) -> bool {
+        !matches!(self, DerivationScheme::Root)
+    }
+}
+
+impl fmt::Display for DerivationScheme {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DerivationScheme::Bip44Change => write!(f, "m/44'/501'/x'/0'"),
+            DerivationScheme::Bip44 => write!(f, "m/44'/501'/x'"),
+            DerivationScheme::Root => write!(f, "No derivation (solana-keygen)"),
+        }
+    }
+}
+
+/// Generates a new English BIP39 seed phrase.
+pub fn generate_mnemonic(word_count: WordCount) -> String {
+    let mut entropy = vec![0u8; word_count.entropy_bytes()];
+    rand::thread_rng().fill_bytes(&mut entropy);
+    Mnemonic::from_entropy(&entropy, Language::English)
+        .expect("entropy length is valid")
+        .into_phrase()
+}
+
+/// Normalizes whitespace and case so pasted phrases validate.
+pub fn normalize_mnemonic(phrase: &str) -> String {
+    phrase
+        .split_whitespace()
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+pub fn validate_mnemonic(phrase: &str) -> bool {
+    Mnemonic::validate(phrase, Language::English).is_ok()
+}
+
+/// Returns the BIP39 seed for the phrase and optional passphrase.
+pub fn mnemonic_seed(phrase: &str, passphrase: &str) -> KeystoreResult<Vec<u8>> {
+    if !validate_mnemonic(phrase) {
+        return Err(KeystoreError::InvalidMnemonic);
+    }
+    Ok(generate_seed_from_seed_phrase_and_passphrase(
+        phrase, passphrase,
+    ))
+}
+
+/// Derives the keypair at `index` from a BIP39 seed.
+pub fn keypair_from_mnemonic_seed(
+    seed: &[u8],
+    scheme: DerivationScheme,
+    index: u32,
+) -> KeystoreResult<Keypair> {
+    match scheme.path(index) {
+        Some(path) => {
+            let derivation_path = DerivationPath::from_absolute_path_str(&path)
+                .or(Err(KeystoreError::InvalidMnemonic))?;
+            keypair_from_seed_and_derivation_path(seed, Some(derivation_path))
+        }
+        None => keypair_from_seed(seed),
+    }
+    .or(Err(KeystoreError::InvalidMnemonic))
+}
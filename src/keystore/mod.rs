@@ -0,0 +1,256 @@
+#[cfg(feature = "web")]
+mod backup;
+mod cli;
+mod crypto;
+#[cfg(feature = "web")]
+mod mnemonic;
+#[cfg(feature = "web")]
+mod profile;
+#[cfg(feature = "web")]
+mod transfer;
+
+#[cfg(feature = "web")]
+pub use backup::*;
+pub use cli::*;
+pub use crypto::*;
+#[cfg(feature = "web")]
+pub use mnemonic::*;
+#[cfg(feature = "web")]
+pub use profile::*;
+#[cfg(feature = "web")]
+pub use transfer::*;
+
+use std::sync::RwLock;
+
+#[cfg(feature = "web")]
+use gloo_storage::{LocalStorage, Storage};
+use lazy_static::lazy_static;
+#[cfg(feature = "web")]
+use solana_client_wasm::solana_sdk::bs58;
+use solana_client_wasm::solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+
+#[cfg(feature = "web")]
+const KEYPAIR_KEY: &str = "keypair";
+#[cfg(feature = "web")]
+const KEYPAIR_ENCRYPTED_KEY: &str = "keypair_encrypted";
+#[cfg(feature = "web")]
+const VAULT_KEY: &str = "vault";
+
+pub type KeystoreResult<T> = Result<T, KeystoreError>;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum KeystoreError {
+    InvalidPassphrase,
+    Corrupted,
+    NotFound,
+    Locked,
+    InvalidMnemonic,
+}
+
+/// The active profile's unlocked keypair, and the vault key if keypairs are encrypted at rest.
+struct Session {
+    keypair: Keypair,
+    vault_key: Option<VaultKey>,
+}
+
+lazy_static! {
+    static ref SESSION: RwLock<Option<Session>> = RwLock::new(None);
+}
+
+/// Returns true if the keypairs are stored encrypted and need a passphrase to unlock.
+#[cfg(feature = "web")]
+pub fn is_encrypted() -> bool {
+    LocalStorage::get::<Vault>(VAULT_KEY).is_ok()
+}
+
+/// Opens a session with the active profile's plaintext keypair, generating a new one on first launch.
+#[cfg(feature = "web")]
+pub fn open() -> Pubkey {
+    let id = active_profile_id();
+    let keypair = read_keypair(&id, None).unwrap_or_else(|_| {
+        let keypair = Keypair::new();
+        write_keypair(&id, &keypair, None).ok();
+        keypair
+    });
+    start_session(keypair, None)
+}
+
+/// Opens a session with a keypair that is not stored, like the headless miner's keypair file.
+#[cfg(feature = "headless")]
+pub fn open_with_keypair(keypair: Keypair) -> Pubkey {
+    let pubkey = keypair.pubkey();
+    *SESSION.write().unwrap() = Some(Session {
+        keypair,
+        vault_key: None,
+    });
+    pubkey
+}
+
+/// Decrypts the active profile's keypair and opens a session with it.
+#[cfg(feature = "web")]
+pub fn unlock(passphrase: &str) -> KeystoreResult<Pubkey> {
+    let vault: Vault = LocalStorage::get(VAULT_KEY).or(Err(KeystoreError::NotFound))?;
+    let vault_key = vault.derive_key(passphrase)?;
+    let id = active_profile_id();
+    let keypair = read_keypair(&id, Some(&vault_key))?;
+
+    // Drop any plaintext copy left behind by an older version of the app
+    LocalStorage::delete(scoped_key(KEYPAIR_KEY, &id));
+
+    Ok(start_session(keypair, Some(vault_key)))
+}
+
+/// Checks the passphrase against the stored keypair without touching the session.
+#[cfg(feature = "web")]
+pub fn verify_passphrase(passphrase: &str) -> KeystoreResult<()> {
+    let vault: Vault = LocalStorage::get(VAULT_KEY).or(Err(KeystoreError::NotFound))?;
+    let vault_key = vault.derive_key(passphrase)?;
+    read_keypair(&active_profile_id(), Some(&vault_key)).map(|_| ())
+}
+
+/// Encrypts every profile's keypair under a new passphrase and removes the plaintext copies.
+/// If the keypairs are already encrypted, the current passphrase must be given.
+#[cfg(feature = "web")]
+pub fn set_passphrase(current: Option<&str>, passphrase: &str) -> KeystoreResult<()> {
+    let current_key = match LocalStorage::get::<Vault>(VAULT_KEY) {
+        Ok(vault) => {
+            let vault_key = vault.derive_key(current.unwrap_or_default())?;
+            read_keypair(&active_profile_id(), Some(&vault_key))?;
+            Some(vault_key)
+        }
+        Err(_) => None,
+    };
+    let vault = Vault::new();
+    let vault_key = vault.derive_key(passphrase)?;
+    let mut session = SESSION.write().unwrap();
+    let session = session.as_mut().ok_or(KeystoreError::Locked)?;
+
+    // Re-encrypt everything in memory first so a bad entry leaves storage untouched
+    let mut secrets = vec![];
+    for profile in profiles() {
+        let keypair = read_keypair(&profile.id, current_key.as_ref())?;
+        secrets.push((profile.id, encrypt(&vault_key, &keypair.to_bytes())?));
+    }
+    LocalStorage::set(VAULT_KEY, &vault).or(Err(KeystoreError::Corrupted))?;
+    for (id, secret) in secrets {
+        LocalStorage::set(scoped_key(KEYPAIR_ENCRYPTED_KEY, &id), &secret)
+            .or(Err(KeystoreError::Corrupted))?;
+        LocalStorage::delete(scoped_key(KEYPAIR_KEY, &id));
+    }
+    session.vault_key = Some(vault_key);
+    Ok(())
+}
+
+/// Stores the keypair as a new profile and switches to it.
+/// Adding a keypair that already has a profile just switches to that profile.
+#[cfg(feature = "web")]
+pub fn add_profile(keypair: Keypair) -> KeystoreResult<Pubkey> {
+    let id = save_profile(&keypair)?;
+    let vault_key = session_vault_key()?;
+    set_active_profile_id(&id);
+    Ok(start_session(keypair, vault_key))
+}
+
+/// Stores the keypair as a new profile without leaving the current one.
+/// Returns the id of the new or already existing profile.
+#[cfg(feature = "web")]
+pub fn save_profile(keypair: &Keypair) -> KeystoreResult<String> {
+    let pubkey = keypair.pubkey();
+    let mut profiles = profiles();
+    if let Some(profile) = profiles.iter().find(|p| p.pubkey.eq(&pubkey)) {
+        return Ok(profile.id.clone());
+    }
+    let vault_key = session_vault_key()?;
+    let id = pubkey.to_string();
+    write_keypair(&id, keypair, vault_key.as_ref())?;
+    profiles.push(Profile {
+        id: id.clone(),
+        name: default_profile_name(profiles.len()),
+        pubkey,
+    });
+    save_profiles(&profiles);
+    Ok(id)
+}
+
+/// Opens a session with another profile's keypair.
+#[cfg(feature = "web")]
+pub fn switch_profile(id: &str) -> KeystoreResult<Pubkey> {
+    let vault_key = session_vault_key()?;
+    let keypair = read_keypair(id, vault_key.as_ref())?;
+    set_active_profile_id(id);
+    Ok(start_session(keypair, vault_key))
+}
+
+#[cfg(feature = "web")]
+pub fn rename_profile(id: &str, name: &str) -> KeystoreResult<()> {
+    let mut profiles = profiles();
+    let profile = profiles
+        .iter_mut()
+        .find(|p| p.id.eq(id))
+        .ok_or(KeystoreError::NotFound)?;
+    profile.name = name.trim().to_string();
+    save_profiles(&profiles);
+    Ok(())
+}
+
+/// Returns the unlocked keypair.
+///
+/// Panics if the session has not been opened. The app only renders once it has been.
+pub fn signer() -> Keypair {
+    SESSION
+        .read()
+        .unwrap()
+        .as_ref()
+        .map(|session| session.keypair.insecure_clone())
+        .expect("keystore is locked")
+}
+
+#[cfg(feature = "web")]
+fn session_vault_key() -> KeystoreResult<Option<VaultKey>> {
+    SESSION
+        .read()
+        .unwrap()
+        .as_ref()
+        .map(|session| session.vault_key)
+        .ok_or(KeystoreError::Locked)
+}
+
+#[cfg(feature = "web")]
+fn read_keypair(id: &str, vault_key: Option<&VaultKey>) -> KeystoreResult<Keypair> {
+    match vault_key {
+        Some(vault_key) => {
+            let secret: EncryptedSecret = LocalStorage::get(scoped_key(KEYPAIR_ENCRYPTED_KEY, id))
+                .or(Err(KeystoreError::NotFound))?;
+            let bytes = decrypt(vault_key, &secret)?;
+            Keypair::from_bytes(&bytes).or(Err(KeystoreError::Corrupted))
+        }
+        None => {
+            let value: String =
+                LocalStorage::get(scoped_key(KEYPAIR_KEY, id)).or(Err(KeystoreError::NotFound))?;
+            let bytes = bs58::decode(value)
+                .into_vec()
+                .or(Err(KeystoreError::Corrupted))?;
+            Keypair::from_bytes(&bytes).or(Err(KeystoreError::Corrupted))
+        }
+    }
+}
+
+#[cfg(feature = "web")]
+fn write_keypair(id: &str, keypair: &Keypair, vault_key: Option<&VaultKey>) -> KeystoreResult<()> {
+    match vault_key {
+        Some(vault_key) => {
+            let secret = encrypt(vault_key, &keypair.to_bytes())?;
+            LocalStorage::set(scoped_key(KEYPAIR_ENCRYPTED_KEY, id), &secret)
+        }
+        None => LocalStorage::set(scoped_key(KEYPAIR_KEY, id), keypair.to_base58_string()),
+    }
+    .or(Err(KeystoreError::Corrupted))
+}
+
+#[cfg(feature = "web")]
+fn start_session(keypair: Keypair, vault_key: Option<VaultKey>) -> Pubkey {
+    let pubkey = keypair.pubkey();
+    ensure_profile(pubkey);
+    *SESSION.write().unwrap() = Some(Session { keypair, vault_key });
+    pubkey
+}
@@ -0,0 +1,70 @@
+use gloo_storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use solana_client_wasm::solana_sdk::pubkey::Pubkey;
+
+const PROFILES_KEY: &str = "profiles";
+const ACTIVE_PROFILE_KEY: &str = "active_profile";
+
+/// Id of the profile created for the keypair stored before profiles existed.
+pub const DEFAULT_PROFILE_ID: &str = "default";
+
+/// A named miner account with its own keypair and settings.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct Profile {
+    pub id: String,
+    pub name: String,
+    pub pubkey: Pubkey,
+}
+
+pub fn profiles() -> Vec<Profile> {
+    LocalStorage::get(PROFILES_KEY).unwrap_or_default()
+}
+
+pub fn active_profile_id() -> String {
+    LocalStorage::get(ACTIVE_PROFILE_KEY).unwrap_or(DEFAULT_PROFILE_ID.to_string())
+}
+
+pub fn active_profile() -> Option<Profile> {
+    let id = active_profile_id();
+    profiles().into_iter().find(|p| p.id.eq(&id))
+}
+
+/// Storage key of a setting scoped to the active profile.
+pub fn profile_key(key: &str) -> String {
+    scoped_key(key, &active_profile_id())
+}
+
+/// The default profile keeps the unscoped keys so data from older versions carries over.
+pub(super) fn scoped_key(key: &str, id: &str) -> String {
+    if id.eq(DEFAULT_PROFILE_ID) {
+        key.to_string()
+    } else {
+        format!("{}:{}", key, id)
+    }
+}
+
+pub(super) fn save_profiles(profiles: &[Profile]) {
+    LocalStorage::set(PROFILES_KEY, profiles).ok();
+}
+
+pub(super) fn set_active_profile_id(id: &str) {
+    LocalStorage::set(ACTIVE_PROFILE_KEY, id).ok();
+}
+
+/// Registers the active profile the first time its keypair is opened.
+pub(super) fn ensure_profile(pubkey: Pubkey) {
+    let id = active_profile_id();
+    let mut profiles = profiles();
+    if !profiles.iter().any(|p| p.id.eq(&id)) {
+        profiles.push(Profile {
+            name: default_profile_name(profiles.len()),
+            id,
+            pubkey,
+        });
+        save_profiles(&profiles);
+    }
+}
+
+pub(super) fn default_profile_name(index: usize) -> String {
+    format!("Account {}", index + 1)
+}
@@ -0,0 +1,139 @@
+use std::collections::BTreeMap;
+
+use base64::{prelude::BASE64_STANDARD, Engine};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use solana_client_wasm::solana_sdk::signature::Keypair;
+
+use super::{decrypt, encrypt, EncryptedSecret, KeystoreError, KeystoreResult, Vault};
+
+/// Marks QR frames produced by the key transfer export.
+const TRANSFER_PREFIX: &str = "ORE-KEY";
+
+/// Characters of payload per QR frame, small enough to scan reliably from a screen.
+const FRAME_SIZE: usize = 240;
+
+/// Length of the one-time code. Ambiguous characters (0/O, 1/I) are left out of the alphabet.
+pub const CODE_LENGTH: usize = 10;
+const CODE_ALPHABET: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+
+/// Length of the id tying the frames of one export together.
+const SESSION_LENGTH: usize = 6;
+
+#[derive(Debug, Deserialize, Serialize)]
+struct TransferPayload {
+    vault: Vault,
+    secret: EncryptedSecret,
+}
+
+pub fn generate_transfer_code() -> String {
+    random_code(CODE_LENGTH)
+}
+
+fn random_code(len: usize) -> String {
+    let mut rng = rand::thread_rng();
+    (0..len)
+        .map(|_| CODE_ALPHABET[rng.gen_range(0..CODE_ALPHABET.len())] as char)
+        .collect()
+}
+
+/// Strips separators and case so codes can be typed as shown or in lowercase.
+pub fn normalize_transfer_code(code: &str) -> String {
+    code.chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+/// Encrypts the keypair with the one-time code and splits it into QR frames
+/// of the form `ORE-KEY:<session>:<index>/<total>:<chunk>`. The session id is random per
+/// export, so frames left over from an earlier export cannot be mixed in.
+pub fn encode_transfer(keypair: &Keypair, code: &str) -> KeystoreResult<Vec<String>> {
+    let vault = Vault::new();
+    let vault_key = vault.derive_key(&normalize_transfer_code(code))?;
+    let payload = TransferPayload {
+        secret: encrypt(&vault_key, &keypair.to_bytes())?,
+        vault,
+    };
+    let json = serde_json::to_vec(&payload).or(Err(KeystoreError::Corrupted))?;
+    let data = BASE64_STANDARD.encode(json);
+    let chunks: Vec<&str> = data
+        .as_bytes()
+        .chunks(FRAME_SIZE)
+        .map(|chunk| std::str::from_utf8(chunk).expect("base64 is ascii"))
+        .collect();
+    let session = random_code(SESSION_LENGTH);
+    let total = chunks.len();
+    Ok(chunks
+        .into_iter()
+        .enumerate()
+        .map(|(i, chunk)| format!("{TRANSFER_PREFIX}:{session}:{}/{total}:{chunk}", i + 1))
+        .collect())
+}
+
+/// Frames collected on the receiving device.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TransferFrames {
+    session: String,
+    total: usize,
+    frames: BTreeMap<usize, String>,
+}
+
+impl TransferFrames {
+    /// Adds a scanned frame. Frames may arrive in any order and repeat, but must all come
+    /// from the same export as the first one.
+    pub fn add(&mut self, frame: &str) -> KeystoreResult<()> {
+        let mut parts = frame.trim().splitn(4, ':');
+        if parts.next().ne(&Some(TRANSFER_PREFIX)) {
+            return Err(KeystoreError::Corrupted);
+        }
+        let session = parts
+            .next()
+            .filter(|session| session.len().eq(&SESSION_LENGTH))
+            .ok_or(KeystoreError::Corrupted)?;
+        if !self.session.is_empty() && self.session.ne(session) {
+            return Err(KeystoreError::Corrupted);
+        }
+        let (index, total) = parts
+            .next()
+            .and_then(|position| position.split_once('/'))
+            .and_then(|(index, total)| Some((index.parse::<usize>().ok()?, total.parse::<usize>().ok()?)))
+            .ok_or(KeystoreError::Corrupted)?;
+        let chunk = parts.next().ok_or(KeystoreError::Corrupted)?;
+        if index.eq(&0) || index.gt(&total) || (self.total.gt(&0) && self.total.ne(&total)) {
+            return Err(KeystoreError::Corrupted);
+        }
+        self.session = session.to_string();
+        self.total = total;
+        self.frames.insert(index, chunk.to_string());
+        Ok(())
+    }
+
+    pub fn received(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.total.gt(&0) && self.frames.len().eq(&self.total)
+    }
+
+    /// Reassembles the frames and decrypts the keypair with the one-time code.
+    pub fn decode(&self, code: &str) -> KeystoreResult<Keypair> {
+        if !self.is_complete() {
+            return Err(KeystoreError::NotFound);
+        }
+        let data: String = self.frames.values().map(String::as_str).collect();
+        let json = BASE64_STANDARD
+            .decode(data)
+            .or(Err(KeystoreError::Corrupted))?;
+        let payload: TransferPayload =
+            serde_json::from_slice(&json).or(Err(KeystoreError::Corrupted))?;
+        let vault_key = payload.vault.derive_key(&normalize_transfer_code(code))?;
+        let bytes = decrypt(&vault_key, &payload.secret)?;
+        Keypair::from_bytes(&bytes).or(Err(KeystoreError::Corrupted))
+    }
+}
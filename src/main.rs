@@ -1,44 +1,90 @@
 #![allow(non_snake_case)]
 
+#[cfg(feature = "web")]
 use dioxus::prelude::*;
 
+#[cfg(all(feature = "native", not(feature = "headless")))]
+compile_error!("the `native` feature only builds the headless miner, enable `headless` instead");
+
+#[cfg(feature = "web")]
 mod components;
 mod gateway;
+#[cfg(feature = "headless")]
+mod headless;
+#[cfg(feature = "web")]
+mod history;
 mod hooks;
+#[cfg(feature = "web")]
+mod idle;
+mod keystore;
 mod miner;
+#[cfg(feature = "web")]
+mod qr_scanner;
+#[cfg(feature = "web")]
 mod route;
 mod utils;
+#[cfg(feature = "web")]
+mod wallet_adapter;
 
+#[cfg(feature = "web")]
 use crate::{
+    components::Unlock,
     hooks::{
-        use_appearance_provider, use_explorer_provider, use_fee_url_provider,
-        use_is_onboarded_provider, use_power_level_provider, use_priority_fee_cap_provider,
-        use_priority_fee_provider, use_priority_fee_strategy_provider, use_rpc_url_provider,
-        use_show_backup_warning_provider,
+        use_appearance_provider, use_auto_lock_provider, use_benchmarks_provider,
+        use_explorer_provider, use_fee_settings_provider, use_fee_url_provider,
+        use_is_onboarded_provider, use_mining_schedule_provider, use_pool_url_provider,
+        use_power_level_provider, use_priority_fee_cap_provider, use_priority_fee_provider,
+        use_priority_fee_strategy_provider, use_profiles_provider, use_remote_agents_provider,
+        use_rpc_url_provider, use_session, use_session_provider, use_show_backup_warning_provider,
+        use_submit_policy_provider, use_wallet_adapter_provider, use_watchlist_provider,
+        SessionStatus,
     },
     route::Route,
 };
 
+#[cfg(all(feature = "web", not(feature = "headless")))]
 fn main() {
     wasm_logger::init(wasm_logger::Config::default());
     launch(App);
 }
 
+#[cfg(feature = "headless")]
+fn main() {
+    headless::run();
+}
+
+#[cfg(feature = "web")]
 pub fn App() -> Element {
     // Global variables
+    use_session_provider();
+    use_profiles_provider();
     use_appearance_provider();
     use_explorer_provider();
     use_power_level_provider();
+    use_benchmarks_provider();
+    use_mining_schedule_provider();
     use_is_onboarded_provider();
     use_priority_fee_provider();
     use_priority_fee_cap_provider();
     use_priority_fee_strategy_provider();
+    use_submit_policy_provider();
     use_show_backup_warning_provider();
     use_fee_url_provider();
     use_rpc_url_provider();
+    use_pool_url_provider();
+    use_remote_agents_provider();
+    use_fee_settings_provider();
+    use_wallet_adapter_provider();
+    use_auto_lock_provider();
+    use_watchlist_provider();
+    let session = use_session();
 
     // Render
     rsx! {
-        Router::<Route> {}
+        if let SessionStatus::Locked = *session.read() {
+            Unlock {}
+        } else {
+            Router::<Route> {}
+        }
     }
 }
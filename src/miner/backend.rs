@@ -0,0 +1,110 @@
+#[cfg(feature = "web")]
+use dioxus_sdk::utils::channel::UseChannel;
+use serde::{Deserialize, Serialize};
+
+#[cfg(all(feature = "web", feature = "native"))]
+use super::NativeBackend;
+#[cfg(all(feature = "web", not(feature = "native")))]
+use super::WebWorkerPool;
+
+/// Mining request for one hasher: a web worker, an OS thread or an agent core.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebWorkerRequest {
+    pub challenge: [u8; 32],
+    /// First nonce to hash.
+    pub nonce: [u8; 8],
+    /// Start of this hasher's range. The response's `offset` is where the hasher stopped.
+    pub offset: u64,
+    pub cutoff_time: u64,
+    pub power_level: usize,
+    pub worker: usize,
+    /// Difficulty at which to stop hashing early.
+    pub target: Option<u32>,
+    /// Difficulty at which every hash is sent as a share while the batch keeps hashing.
+    #[serde(default)]
+    pub share_difficulty: Option<u32>,
+}
+
+/// Mining response from one hasher.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebWorkerResponse {
+    pub hash: [u8; 32],
+    pub digest: [u8; 16],
+    pub nonce: [u8; 8],
+    pub difficulty: u32,
+    /// The request's `offset` plus the number of hashes computed.
+    pub offset: u64,
+    pub challenge: [u8; 32],
+    pub power_level: usize,
+    /// Index of the hasher that sent this response within its batch.
+    pub worker: usize,
+    /// Number of hashes computed in this batch.
+    pub hashes: u64,
+    /// Time spent hashing in this batch.
+    pub elapsed_ms: u64,
+    /// Set on a share sent mid-batch. The hasher's final response still follows.
+    #[serde(default)]
+    pub share: bool,
+}
+
+#[cfg(feature = "web")]
+impl WebWorkerResponse {
+    /// Stands in for a hasher that never answered, so its batch still completes.
+    pub fn missing(req: &WebWorkerRequest) -> Self {
+        Self::partial(req, 0, 0)
+    }
+
+    /// Records how far a hasher got in a batch that was stopped before it answered.
+    pub fn partial(req: &WebWorkerRequest, hashes: u64, elapsed_ms: u64) -> Self {
+        Self {
+            hash: [0; 32],
+            digest: [0; 16],
+            nonce: req.nonce,
+            difficulty: 0,
+            offset: req.offset + hashes,
+            challenge: req.challenge,
+            power_level: req.power_level,
+            worker: req.worker,
+            hashes,
+            elapsed_ms,
+            share: false,
+        }
+    }
+}
+
+/// A source of drillx hashes for the miner.
+///
+/// `Miner` only talks to this trait, so hashing can run in web workers, on OS threads or on
+/// another machine without touching the submission logic. Every hasher in a batch sends one
+/// `WebWorkerResponse` to the results channel, tagged with the batch's `power_level`: one less
+/// than the number of responses in the batch, including those from other backends.
+#[cfg(feature = "web")]
+pub trait HashBackend {
+    /// Starts a batch with one request per hasher, each with its own nonce range.
+    /// A hasher returns early once it finds a hash at the request's `target` difficulty.
+    fn start(&self, reqs: Vec<WebWorkerRequest>);
+
+    /// Abandons the running batch. No more results are sent for it.
+    ///
+    /// Returns a [`WebWorkerResponse::partial`] per unfinished hasher with how far it got, so
+    /// its nonces are not hashed again when mining resumes.
+    fn stop(&self) -> Vec<WebWorkerResponse>;
+
+    /// Changes the number of hashers available to later batches.
+    fn resize(&self, hashers: usize);
+
+    /// Channel the batch results are sent to.
+    fn results(&self) -> UseChannel<WebWorkerResponse>;
+}
+
+/// The backend for this build: web workers in the browser, OS threads with the `native` feature.
+#[cfg(all(feature = "web", not(feature = "native")))]
+pub fn hash_backend(cx: UseChannel<WebWorkerResponse>, hashers: usize) -> Box<dyn HashBackend> {
+    Box::new(WebWorkerPool::new(cx, hashers))
+}
+
+/// The backend for this build: web workers in the browser, OS threads with the `native` feature.
+#[cfg(all(feature = "web", feature = "native"))]
+pub fn hash_backend(cx: UseChannel<WebWorkerResponse>, _hashers: usize) -> Box<dyn HashBackend> {
+    Box::new(NativeBackend::new(cx))
+}
@@ -0,0 +1,113 @@
+use serde::{Deserialize, Serialize};
+
+use super::{HashBackend, WebWorkerRequest, WEB_WORKERS};
+
+/// Seconds each worker count is hashed for.
+pub const BENCHMARK_SECS: u64 = 5;
+
+/// Share of the best hashrate a worker count must reach to be recommended.
+const RECOMMEND_THRESHOLD: f64 = 0.95;
+
+/// Hashrate measured at one worker count.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct BenchmarkStep {
+    pub workers: usize,
+    pub hashrate: u64,
+    pub best_difficulty: u32,
+}
+
+/// One benchmark run across every worker count.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct BenchmarkRun {
+    /// Unix timestamp in seconds.
+    pub timestamp: i64,
+    pub machine: String,
+    pub cores: usize,
+    pub seconds: u64,
+    pub steps: Vec<BenchmarkStep>,
+}
+
+impl BenchmarkRun {
+    pub fn new() -> Self {
+        Self {
+            timestamp: chrono::Utc::now().timestamp(),
+            machine: machine_name(),
+            cores: *WEB_WORKERS,
+            seconds: BENCHMARK_SECS,
+            steps: vec![],
+        }
+    }
+
+    /// The fewest workers within reach of the best hashrate.
+    ///
+    /// Past this point more workers only compete for the same cores, because of hyperthreads or
+    /// the browser throttling background workers.
+    pub fn recommended(&self) -> Option<usize> {
+        let best = self.steps.iter().map(|step| step.hashrate).max()?;
+        self.steps
+            .iter()
+            .filter(|step| (step.hashrate as f64).ge(&(best as f64 * RECOMMEND_THRESHOLD)))
+            .map(|step| step.workers)
+            .min()
+    }
+
+    pub fn best_hashrate(&self) -> u64 {
+        self.steps.iter().map(|step| step.hashrate).max().unwrap_or(0)
+    }
+}
+
+/// Hashes a random challenge on `workers` hashers for the benchmark time and measures the batch.
+pub async fn benchmark_step(backend: &dyn HashBackend, workers: usize) -> BenchmarkStep {
+    let mut rx = backend.results().receiver();
+    let challenge: [u8; 32] = rand::random();
+    let lane = u64::MAX / workers as u64;
+    let reqs = (0..workers)
+        .map(|worker| {
+            let nonce = lane.saturating_mul(worker as u64);
+            WebWorkerRequest {
+                challenge,
+                nonce: nonce.to_le_bytes(),
+                offset: nonce,
+                cutoff_time: BENCHMARK_SECS,
+                power_level: workers - 1,
+                worker,
+                target: None,
+                share_difficulty: None,
+            }
+        })
+        .collect();
+    backend.start(reqs);
+
+    let mut step = BenchmarkStep {
+        workers,
+        ..Default::default()
+    };
+    let mut received = 0;
+    while received.lt(&workers) {
+        let Ok(msg) = rx.recv().await else {
+            break;
+        };
+        if msg.challenge.ne(&challenge) {
+            continue;
+        }
+        received += 1;
+        step.hashrate += msg
+            .hashes
+            .saturating_mul(1000)
+            .saturating_div(msg.elapsed_ms.max(1));
+        step.best_difficulty = step.best_difficulty.max(msg.difficulty);
+    }
+    step
+}
+
+#[cfg(not(feature = "native"))]
+fn machine_name() -> String {
+    web_sys::window()
+        .and_then(|window| window.navigator().user_agent().ok())
+        .unwrap_or_default()
+}
+
+#[cfg(feature = "native")]
+fn machine_name() -> String {
+    format!("{} {}", std::env::consts::OS, std::env::consts::ARCH)
+}
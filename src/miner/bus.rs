@@ -0,0 +1,65 @@
+use std::{
+    rc::Rc,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use drillx::Solution;
+use ore_api::consts::BUS_COUNT;
+use rand::Rng;
+
+use crate::{
+    gateway::{self, Gateway},
+    hooks::BusStrategy,
+};
+
+/// Next bus of `BusStrategy::RoundRobin`.
+static NEXT_BUS: AtomicUsize = AtomicUsize::new(0);
+
+/// Picks the bus for a mine transaction, skipping the buses in `exclude`.
+///
+/// Strategies that need live data fall back to a random bus if it cannot be fetched.
+pub async fn pick_bus(gateway: &Rc<Gateway>, strategy: BusStrategy, exclude: &[usize]) -> usize {
+    let candidates: Vec<usize> = (0..BUS_COUNT).filter(|id| !exclude.contains(id)).collect();
+    if candidates.is_empty() {
+        return rand::thread_rng().gen_range(0..BUS_COUNT);
+    }
+    let bus = match strategy {
+        BusStrategy::Random => None,
+        BusStrategy::RoundRobin => (0..BUS_COUNT)
+            .map(|_| NEXT_BUS.fetch_add(1, Ordering::Relaxed) % BUS_COUNT)
+            .find(|id| candidates.contains(id)),
+        BusStrategy::Richest => match gateway.get_buses().await {
+            Ok(buses) => candidates
+                .iter()
+                .copied()
+                .max_by_key(|id| buses.get(*id).map(|bus| bus.rewards).unwrap_or(0)),
+            Err(err) => {
+                log::error!("Failed to fetch buses: {:?}", err);
+                None
+            }
+        },
+        BusStrategy::LeastContended => match gateway::get_bus_fees().await {
+            Ok(fees) => candidates
+                .iter()
+                .copied()
+                .min_by_key(|id| fees.get(*id).copied().unwrap_or(u64::MAX)),
+            Err(err) => {
+                log::error!("Failed to fetch bus fees: {}", err);
+                None
+            }
+        },
+    };
+    bus.unwrap_or_else(|| candidates[rand::thread_rng().gen_range(0..candidates.len())])
+}
+
+/// Returns true if `bus` has fewer rewards left than the base reward for `solution`.
+pub async fn is_bus_exhausted(gateway: &Rc<Gateway>, bus: usize, solution: &Solution) -> bool {
+    let (Ok(bus), Ok(config)) = (gateway.get_bus(bus).await, gateway.get_config().await) else {
+        return false;
+    };
+    let difficulty = solution.to_hash().difficulty();
+    let reward = config.base_reward_rate.saturating_mul(
+        2u64.saturating_pow(difficulty.saturating_sub(config.min_difficulty as u32)),
+    );
+    bus.rewards.lt(&reward)
+}
@@ -0,0 +1,95 @@
+// The hashing loop shared by web workers, native threads and the hashing agent in
+// `serve/src/bin/agent.rs`. The agent includes this file by path, so it may only depend on
+// `drillx`, `web_time` and the request and response types of its parent module.
+
+use web_time::Instant;
+
+use super::{WebWorkerRequest, WebWorkerResponse};
+
+/// Searches a request's nonce range for the best drillx hash.
+///
+/// Every 100 nonces the hasher checks `should_stop`, passing it the hashes done so far, and
+/// whether the cutoff time has passed. It also returns once it finds a hash at the request's
+/// target difficulty. Every hash at the share difficulty is passed to `on_share`.
+pub fn find_next_hash(
+    req: WebWorkerRequest,
+    should_stop: impl Fn(u64) -> bool,
+    on_share: impl Fn(WebWorkerResponse),
+) -> WebWorkerResponse {
+    let WebWorkerRequest {
+        challenge,
+        nonce,
+        offset,
+        cutoff_time,
+        power_level,
+        worker,
+        target,
+        share_difficulty,
+    } = req;
+    let timer = Instant::now();
+    let mut i = 0;
+    let mut nonce = u64::from_le_bytes(nonce);
+    let mut best_hash = [0u8; 32];
+    let mut best_digest = [0u8; 16];
+    let mut best_nonce = [0u8; 8];
+    let mut best_difficulty = 0u32;
+    let mut memory = drillx::equix::SolverMemory::new();
+    loop {
+        if let Ok(hash) = drillx::hash_with_memory(&mut memory, &challenge, &nonce.to_le_bytes()) {
+            let difficulty = hash.difficulty();
+            if share_difficulty.is_some_and(|share| difficulty.ge(&share)) {
+                on_share(WebWorkerResponse {
+                    hash: hash.h,
+                    digest: hash.d,
+                    nonce: nonce.to_le_bytes(),
+                    difficulty,
+                    offset: offset + i,
+                    challenge,
+                    power_level,
+                    worker,
+                    hashes: i,
+                    elapsed_ms: timer.elapsed().as_millis() as u64,
+                    share: true,
+                });
+            }
+            if difficulty.gt(&best_difficulty) {
+                best_digest = hash.d;
+                best_difficulty = difficulty;
+                best_nonce = nonce.to_le_bytes();
+                best_hash = hash.h;
+            }
+        }
+
+        // Break if the target is reached
+        if target.is_some_and(|target| best_difficulty.ge(&target)) {
+            break;
+        }
+
+        // Break if stopped, or if time has elapsed and batch size is processed
+        if nonce % 100 == 0 {
+            if should_stop(i) {
+                break;
+            }
+            if timer.elapsed().as_secs().ge(&cutoff_time) && i.ge(&100) {
+                break;
+            }
+        }
+
+        nonce += 1;
+        i += 1;
+    }
+
+    WebWorkerResponse {
+        digest: best_digest,
+        hash: best_hash,
+        nonce: best_nonce,
+        difficulty: best_difficulty,
+        offset: offset + i,
+        challenge,
+        power_level,
+        worker,
+        hashes: i,
+        elapsed_ms: timer.elapsed().as_millis() as u64,
+        share: false,
+    }
+}
@@ -1,42 +1,95 @@
+mod backend;
+#[cfg(feature = "web")]
+mod benchmark;
+mod bus;
+mod hasher;
+#[cfg(feature = "native")]
+mod native;
+mod nonces;
+#[cfg(feature = "web")]
+mod pool;
+#[cfg(feature = "web")]
+mod remote;
+mod reset;
+#[cfg(feature = "web")]
+mod vanity;
+#[cfg(feature = "web")]
 mod web_worker;
 
 use std::rc::Rc;
 
-use dioxus::prelude::*;
-use dioxus_sdk::utils::channel::UseChannel;
 use drillx::Solution;
-use lazy_static::lazy_static;
-use ore_api::{
-    consts::{BUS_COUNT, EPOCH_DURATION},
-    state::Proof,
-};
-use rand::Rng;
-use serde_wasm_bindgen::to_value;
+use ore_api::state::Proof;
 use solana_client_wasm::solana_sdk::{
-    compute_budget::ComputeBudgetInstruction, keccak::Hash as KeccakHash, pubkey::Pubkey,
-    signature::Signature, signer::Signer,
+    clock::Clock, compute_budget::ComputeBudgetInstruction, signature::Signature, signer::Signer,
 };
-use web_sys::{window, Worker};
+pub use backend::*;
+#[cfg(feature = "web")]
+pub use benchmark::*;
+pub use bus::*;
+pub use hasher::*;
+#[cfg(feature = "native")]
+pub use native::*;
+pub use nonces::*;
+#[cfg(feature = "web")]
+pub use remote::*;
+pub use reset::*;
+#[cfg(feature = "web")]
+pub use vanity::*;
+#[cfg(feature = "web")]
 pub use web_worker::*;
 
 use crate::{
-    components::PriorityFeeStrategy,
     gateway::{
-        self, signer, ComputeBudget, Gateway, GatewayResult, CU_LIMIT_MINE,
+        self, signer, ComputeBudget, Gateway, GatewayResult, SubmitStatus, CU_LIMIT_MINE,
         PRIORITY_FEE_CAP,
     },
+    hooks::{BusStrategy, PriorityFeeStrategy, ResetPolicy},
+    utils,
+};
+
+// The miner driven by the app's UI state
+#[cfg(feature = "web")]
+use std::{
+    cell::{Cell, RefCell},
+    time::Duration,
+};
+
+#[cfg(feature = "web")]
+use dioxus::prelude::*;
+#[cfg(feature = "web")]
+use dioxus_sdk::utils::channel::UseChannel;
+#[cfg(feature = "web")]
+use lazy_static::lazy_static;
+#[cfg(feature = "web")]
+use solana_client_wasm::solana_sdk::{keccak::Hash as KeccakHash, pubkey::Pubkey};
+#[cfg(all(feature = "web", not(feature = "native")))]
+use web_sys::window;
+#[cfg(feature = "web")]
+use web_time::Instant;
+
+#[cfg(feature = "web")]
+use crate::{
+    gateway::PoolJob,
+    history::{self, SubmissionRecord},
     hooks::{
-        MinerStatus, MinerStatusMessage, MinerToolbarState, PowerLevel, PriorityFee,
-        ReadMinerToolbarState, UpdateMinerToolbarState,
+        Hashrate, MinerStatus, MinerStatusMessage, MinerToolbarState, PoolUrl, PowerLevel,
+        PriorityFee, ReadMinerToolbarState, SubmitOutcome, SubmitPolicy, UpdateMinerToolbarState,
     },
-    utils,
 };
 
 // Number of physical cores on machine
+#[cfg(feature = "web")]
 lazy_static! {
     pub static ref WEB_WORKERS: usize = fetch_logical_processors();
 }
 
+#[cfg(all(feature = "web", feature = "native"))]
+fn fetch_logical_processors() -> usize {
+    native_threads()
+}
+
+#[cfg(all(feature = "web", not(feature = "native")))]
 fn fetch_logical_processors() -> usize {
     let window = window().expect("should have a window");
     let navigator = window.navigator();
@@ -44,64 +97,150 @@ fn fetch_logical_processors() -> usize {
 }
 
 /// Miner encapsulates the logic needed to efficiently mine for valid hashes according to the application runtime and hardware.
+#[cfg(feature = "web")]
 pub struct Miner {
     power_level: Signal<PowerLevel>,
     priority_fee: Signal<PriorityFee>,
     priority_fee_strategy: Signal<PriorityFeeStrategy>,
-    web_workers: Vec<Worker>,
+    pool_url: Signal<PoolUrl>,
+    pool_job: RefCell<Option<PoolJob>>,
+    submit_policy: Signal<SubmitPolicy>,
+    submit_at: Cell<Instant>,
+    nonce_progress: RefCell<Option<NonceProgress>>,
+    backend: Box<dyn HashBackend>,
+    remote: RemoteAgents,
 }
 
+#[cfg(feature = "web")]
 impl Miner {
     pub fn new(
-        cx: UseChannel<WebWorkerResponse>,
+        backend: Box<dyn HashBackend>,
+        remote: RemoteAgents,
         power_level: Signal<PowerLevel>,
         priority_fee: Signal<PriorityFee>,
         priority_fee_strategy: Signal<PriorityFeeStrategy>,
+        pool_url: Signal<PoolUrl>,
+        submit_policy: Signal<SubmitPolicy>,
     ) -> Self {
         Self {
             power_level: power_level.clone(),
             priority_fee: priority_fee.clone(),
             priority_fee_strategy: priority_fee_strategy.clone(),
-            web_workers: (0..*WEB_WORKERS)
-                .map(|_| create_web_worker(cx.clone()))
-                .collect(),
+            pool_url,
+            pool_job: RefCell::new(None),
+            submit_policy,
+            submit_at: Cell::new(Instant::now()),
+            nonce_progress: RefCell::new(None),
+            backend,
+            remote,
+        }
+    }
+
+    /// Starts a batch on `challenge`, resuming from the nonces already hashed for it.
+    pub async fn start_mining(&self, challenge: [u8; 32], cutoff_time: u64) {
+        let target = self.submit_policy.read().target();
+        self.submit_at.set(Instant::now() + Duration::from_secs(cutoff_time));
+        let mut progress = self.nonce_progress.borrow_mut();
+        if progress.as_ref().map_or(true, |p| p.challenge.ne(&challenge)) {
+            *progress = Some(NonceProgress::load(challenge));
         }
+        let progress = progress.as_mut().unwrap();
+
+        // Agents only report how far a stopped batch got after it was stopped
+        let stopped = self.remote.take_stopped();
+        if !stopped.is_empty() {
+            progress.record(&stopped);
+            progress.save();
+        }
+        let progress = &*progress;
+        self.start_batch(
+            challenge,
+            |worker, _| progress.next(worker),
+            cutoff_time,
+            target,
+            None,
+        );
     }
 
-    pub async fn start_mining(&self, challenge: [u8; 32], offset: u64, cutoff_time: u64) {
-        self.start_mining_web(challenge, offset, cutoff_time).await;
+    /// Starts one batch across the local hashers and the remote agent cores, numbered in that
+    /// order. `nonce` gives each hasher's first nonce from its index and the batch size.
+    fn start_batch(
+        &self,
+        challenge: [u8; 32],
+        nonce: impl Fn(usize, usize) -> u64,
+        cutoff_time: u64,
+        target: Option<u32>,
+        share_difficulty: Option<u32>,
+    ) {
+        let hashers = self.power_level.read().0.max(1) as usize;
+        let size = hashers + self.remote.cores();
+        let mut reqs: Vec<WebWorkerRequest> = (0..size)
+            .map(|worker| {
+                let nonce = nonce(worker, size);
+                WebWorkerRequest {
+                    challenge,
+                    nonce: nonce.to_le_bytes(),
+                    offset: nonce,
+                    cutoff_time,
+                    power_level: size - 1,
+                    worker,
+                    target,
+                    share_difficulty,
+                }
+            })
+            .collect();
+        let remote = reqs.split_off(hashers);
+        self.backend.start(reqs);
+        self.remote.start(remote);
     }
 
-    pub async fn start_mining_web(&self, challenge: [u8; 32], offset: u64, cutoff_time: u64) {
-        let nonce = u64::MAX.saturating_div(self.web_workers.len() as u64);
-        let power_level = self.power_level.read().0.saturating_sub(1) as usize;
-        log::info!("Start mining web...");
-        for (i, web_worker) in self.web_workers.iter().enumerate() {
-            let nonce = nonce.saturating_mul(i as u64).saturating_add(offset);
-            if i.le(&power_level) {
-                log::info!("Posting message: {:?} {:?}", i, nonce);
-                web_worker
-                    .post_message(
-                        &to_value(
-                            &(WebWorkerRequest {
-                                challenge,
-                                nonce: nonce.to_le_bytes(),
-                                offset,
-                                cutoff_time,
-                                power_level,
-                            }),
-                        )
-                        .unwrap(),
-                    )
-                    .unwrap();
-            }
+    /// Saves how far each hasher got, so no nonce is hashed twice for the same challenge.
+    fn record_progress(&self, messages: &[WebWorkerResponse]) {
+        if let Some(progress) = self.nonce_progress.borrow_mut().as_mut() {
+            progress.record(messages);
+            progress.save();
         }
     }
 
+    /// Seconds to hash for the proof's next submission under the submit policy.
+    pub fn cutoff_time(&self, proof: &Proof, clock: &Clock) -> u64 {
+        self.submit_policy.read().cutoff_time(cutoff_time(proof, clock))
+    }
+
+    /// True if a solo hasher found the policy's target, so the batch can end early.
+    pub fn reached_target(&self, msg: &WebWorkerResponse) -> bool {
+        self.pool_url.read().is_solo()
+            && self
+                .submit_policy
+                .read()
+                .target()
+                .is_some_and(|target| msg.difficulty.ge(&target))
+    }
+
+    /// Connects to the configured hashing agents. See [`RemoteAgents::connect`].
+    pub fn connect_agents(&self, urls: &[String]) {
+        self.remote.connect(urls);
+    }
+
+    pub fn stop(&self) {
+        let partial = self.backend.stop();
+        self.record_progress(&partial);
+        self.remote.stop();
+    }
+
+    pub fn resize(&self, hashers: usize) {
+        self.backend.resize(hashers);
+    }
+
+    pub fn results(&self) -> UseChannel<WebWorkerResponse> {
+        self.backend.results()
+    }
+
     pub async fn process_web_worker_results(
         &self,
         messages: &Vec<WebWorkerResponse>,
         toolbar_state: &mut Signal<MinerToolbarState>,
+        hashrate: &mut Signal<Hashrate>,
         proof: &mut Resource<GatewayResult<Proof>>,
         gateway: Rc<Gateway>,
         pubkey: Pubkey,
@@ -113,44 +252,50 @@ impl Miner {
             _ => return,
         }
 
-        // Get best solution
-        let mut challenge = [0; 32];
-        let mut offset = 0;
-        let mut best_difficulty = 0;
-        let mut best_solution = Solution::new([0; 16], [0; 8]);
-        let mut best_hash = [0u8; 32];
-        for msg in messages {
-            if msg.difficulty.gt(&best_difficulty) {
-                best_solution = drillx::Solution::new(msg.digest, msg.nonce);
-                best_difficulty = msg.difficulty;
-                best_hash = msg.hash;
-                offset = msg.offset;
-                challenge = msg.challenge;
-            }
+        // Pool members only report shares
+        if let Some(pool) = self.pool() {
+            self.process_pool_results(&pool, messages, toolbar_state, pubkey)
+                .await;
+            return;
         }
 
-        // Kickoff new batch
-        if let Ok(config) = gateway.get_config().await {
-            if best_difficulty.lt(&(config.min_difficulty as u32)) {
-                self.start_mining(challenge, offset, 0).await;
-                return;
-            }
+        // Get best solution
+        self.record_progress(messages);
+        let best = best_batch_result(messages);
+        toolbar_state.set_display_hash(KeccakHash::new_from_array(best.hash));
+
+        // Keep hashing if the solution is below the program or policy minimum
+        let policy = *self.submit_policy.read();
+        let min_difficulty = gateway
+            .get_config()
+            .await
+            .map(|config| config.min_difficulty as u32)
+            .unwrap_or(0)
+            .max(policy.min_difficulty);
+        if best.difficulty.lt(&min_difficulty) {
+            toolbar_state.set_submit_outcome(SubmitOutcome::BelowMinimum(
+                best.difficulty,
+                min_difficulty,
+            ));
+            self.start_mining(best.challenge, 0).await;
+            return;
         }
 
-        let priority_fee = if self
-            .priority_fee_strategy
-            .read()
-            .eq(&PriorityFeeStrategy::Estimate)
-        {
-            if let Ok(priority_fee) = gateway::get_recent_priority_fee_estimate().await {
-                // TODO: to optimze, MI
-                priority_fee // + 10_000
-            } else {
-                PRIORITY_FEE_CAP
-            }
+        // An early solution waits until the safety margin, which the program accepts
+        let outcome = if policy.target().is_some_and(|target| best.difficulty.ge(&target)) {
+            SubmitOutcome::TargetReached(best.difficulty)
         } else {
-            self.priority_fee.read().0
+            SubmitOutcome::Submitted(best.difficulty)
         };
+        toolbar_state.set_submit_outcome(outcome);
+        let wait = self.submit_at.get().saturating_duration_since(Instant::now());
+        if !wait.is_zero() {
+            async_std::task::sleep(wait).await;
+        }
+
+        let priority_fee =
+            mining_priority_fee(*self.priority_fee_strategy.read(), self.priority_fee.read().0)
+                .await;
         log::info!(
             "current priority fee strategy: {}",
             self.priority_fee_strategy
@@ -158,35 +303,56 @@ impl Miner {
         log::info!("current priority fee: {}", priority_fee);
 
         // Update toolbar state
-        toolbar_state.set_display_hash(KeccakHash::new_from_array(best_hash));
         toolbar_state.set_status_message(MinerStatusMessage::Submitting(0, priority_fee));
 
         // Submit solution
         log::info!("submit solution..."); // MI
-        match submit_solution(
+        let balance = proof_balance(proof);
+        let timer = Instant::now();
+        let submission = submit_solution(
             &gateway,
-            best_solution,
+            best.solution,
             *self.priority_fee_strategy.read(),
             priority_fee,
+            policy.bus_strategy,
+            policy.reset_policy,
             toolbar_state,
         )
-        .await
-        {
+        .await;
+        let latency_ms = timer.elapsed().as_millis() as u64;
+        hashrate.write().record_bus(submission.bus);
+        let mut record = SubmissionRecord {
+            id: None,
+            authority: pubkey.to_string(),
+            timestamp: chrono::Utc::now().timestamp(),
+            challenge: KeccakHash::new_from_array(best.challenge).to_string(),
+            difficulty: best.difficulty,
+            bus: submission.bus,
+            priority_fee: submission.priority_fee,
+            tip: gateway.tip_amount(),
+            attempts: submission.attempts,
+            signature: String::new(),
+            latency_ms,
+            reward: 0,
+            error: None,
+        };
+        match submission.result {
             // Start mining again
             Ok(sig) => {
                 log::info!("Sig: {}", sig); // MI
                 proof.restart();
+                let new_proof = gateway.get_proof(pubkey).await;
+                record.signature = sig.to_string();
+                if let (Some(balance), Ok(new_proof)) = (balance, &new_proof) {
+                    record.reward = new_proof.balance.saturating_sub(balance);
+                }
+                history::add(&record).await;
                 if let MinerStatus::Active = toolbar_state.status() {
-                    if let Ok(proof) = gateway.get_proof(pubkey).await {
+                    if let Ok(proof) = new_proof {
                         if let Ok(clock) = gateway.get_clock().await {
                             toolbar_state.set_status_message(MinerStatusMessage::Searching);
-                            let cutoff_time = proof
-                                .last_hash_at
-                                .saturating_add(60)
-                                .saturating_sub(clock.unix_timestamp)
-                                .max(0) as u64;
-                            self.start_mining(proof.challenge.into(), 0, cutoff_time)
-                                .await;
+                            let cutoff_time = self.cutoff_time(&proof, &clock);
+                            self.start_mining(proof.challenge.into(), cutoff_time).await;
                         } else {
                             log::error!("Failed to get clock");
                         }
@@ -198,6 +364,8 @@ impl Miner {
 
             // Display error
             Err(err) => {
+                record.error = Some(format!("{:?}", err));
+                history::add(&record).await;
                 toolbar_state.set_status(MinerStatus::Error);
                 toolbar_state.set_status_message(MinerStatusMessage::Error);
                 log::error!("Failed to submit hash: {:?}", err);
@@ -206,66 +374,171 @@ impl Miner {
     }
 }
 
+/// Stake balance of the last loaded proof.
+#[cfg(feature = "web")]
+fn proof_balance(proof: &Resource<GatewayResult<Proof>>) -> Option<u64> {
+    match *proof.read() {
+        Some(Ok(proof)) => Some(proof.balance),
+        _ => None,
+    }
+}
+
+/// The best hash found across a batch of hasher results.
+pub struct BatchResult {
+    pub solution: Solution,
+    pub difficulty: u32,
+    pub hash: [u8; 32],
+    pub challenge: [u8; 32],
+}
+
+pub fn best_batch_result(messages: &[WebWorkerResponse]) -> BatchResult {
+    let mut best = BatchResult {
+        solution: Solution::new([0; 16], [0; 8]),
+        difficulty: 0,
+        hash: [0; 32],
+        challenge: [0; 32],
+    };
+    for msg in messages {
+        if msg.difficulty.gt(&best.difficulty) {
+            best = BatchResult {
+                solution: Solution::new(msg.digest, msg.nonce),
+                difficulty: msg.difficulty,
+                hash: msg.hash,
+                challenge: msg.challenge,
+            };
+        }
+    }
+    best
+}
+
+/// Seconds left to hash before the proof's next submission window opens.
+pub fn cutoff_time(proof: &Proof, clock: &Clock) -> u64 {
+    proof
+        .last_hash_at
+        .saturating_add(60)
+        .saturating_sub(clock.unix_timestamp)
+        .max(0) as u64
+}
+
+/// Priority fee to submit a solution with under the given strategy.
+pub async fn mining_priority_fee(strategy: PriorityFeeStrategy, static_fee: u64) -> u64 {
+    match strategy {
+        PriorityFeeStrategy::Estimate => {
+            // TODO: to optimze, MI
+            gateway::get_recent_priority_fee_estimate()
+                .await
+                .unwrap_or(PRIORITY_FEE_CAP)
+        }
+        PriorityFeeStrategy::Static => static_fee,
+    }
+}
+
+/// Outcome of a mine transaction, with the details needed to record it.
+pub struct Submission {
+    pub result: GatewayResult<Signature>,
+    pub bus: usize,
+    pub attempts: u64,
+    /// Compute unit price of the last attempt.
+    pub priority_fee: u64,
+}
+
+/// Forwards submission progress and remembers the last attempt.
+struct TrackStatus<'a> {
+    inner: &'a mut dyn SubmitStatus,
+    attempts: u64,
+    priority_fee: u64,
+}
+
+impl SubmitStatus for TrackStatus<'_> {
+    fn submitting(&mut self, attempt: u64, priority_fee: u64) {
+        self.attempts = attempt + 1;
+        self.priority_fee = priority_fee;
+        self.inner.submitting(attempt, priority_fee);
+    }
+}
+
+/// Buses a solution is sent to before giving up, when buses run out of rewards.
+const BUS_RETRIES: usize = 3;
+
 pub async fn submit_solution(
     gateway: &Rc<Gateway>,
     solution: Solution,
     priority_fee_strategy: PriorityFeeStrategy,
     priority_fee: u64,
-    toolbar_state: &mut Signal<MinerToolbarState>,
-) -> GatewayResult<Signature> {
+    bus_strategy: BusStrategy,
+    reset_policy: ResetPolicy,
+    status: &mut dyn SubmitStatus,
+) -> Submission {
     let signer = signer();
+    let mut status = TrackStatus {
+        inner: status,
+        attempts: 0,
+        priority_fee,
+    };
 
     // Build ixs
-    toolbar_state.set_status_message(MinerStatusMessage::Submitting(0, priority_fee));
+    status.submitting(0, priority_fee);
     let cu_limit_ix = ComputeBudgetInstruction::set_compute_unit_limit(CU_LIMIT_MINE);
     let cu_price_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_fee);
     let auth_ix = ore_api::instruction::auth(utils::proof_pubkey(signer.pubkey())); // MI
     let mut ixs = vec![cu_limit_ix, cu_price_ix, auth_ix];
 
     // Reset if needed
-    // if needs_reset(gateway).await { // MI: vanilla
     log::info!("checking if reset required..."); // MI
-    if needs_reset(gateway).await && rand::thread_rng().gen_range(0..100).eq(&0) {
-        ixs.push(ore_api::instruction::reset(signer.pubkey()));
+    let mut with_reset = prepare_reset(gateway, reset_policy, false).await;
+    if with_reset {
+        ixs.push(reset_ix());
     }
 
-    // Build mine tx
-    let bus_id = pick_bus();
-    let ix = ore_api::instruction::mine(
-        signer.pubkey(),
-        signer.pubkey(),
-        ore_api::consts::BUS_ADDRESSES[bus_id],
-        solution,
-    );
-    ixs.push(ix);
-
-    // Send and configm
-    log::info!("starting send and confirm..."); // MI
+    // Send and configm, on another bus if this one ran out of rewards
     let cb = match priority_fee_strategy {
         PriorityFeeStrategy::Estimate => ComputeBudget::FixedLimitEstimatePrice(CU_LIMIT_MINE), // DEFAULT_CU_LIMIT
         PriorityFeeStrategy::Static => {
             ComputeBudget::FixedLimitStaticPrice(CU_LIMIT_MINE, priority_fee)
         }
     };
-    gateway
-        .send_and_confirm(&ixs, cb, false, Some(toolbar_state))
-        .await
-}
+    let mut tried = vec![];
+    let mut reset_retried = false;
+    loop {
+        // Build mine tx
+        let bus_id = pick_bus(gateway, bus_strategy, &tried).await;
+        let ix = ore_api::instruction::mine(
+            signer.pubkey(),
+            signer.pubkey(),
+            ore_api::consts::BUS_ADDRESSES[bus_id],
+            solution,
+        );
+        let mut bus_ixs = ixs.clone();
+        bus_ixs.push(ix);
 
-async fn needs_reset(gateway: &Rc<Gateway>) -> bool {
-    if let Ok(clock) = gateway.get_clock().await {
-        if let Ok(config) = gateway.get_config().await {
-            return config
-                .last_reset_at
-                .saturating_add(EPOCH_DURATION)
-                .saturating_sub(5) // Buffer
-                .le(&clock.unix_timestamp);
+        log::info!("starting send and confirm on bus {}...", bus_id); // MI
+        let result = gateway
+            .send_and_confirm(&bus_ixs, cb, false, Some(&mut status))
+            .await;
+        if result.is_err() {
+            // A pending reset fails every mine until someone resets
+            if !reset_retried && needs_reset(gateway).await {
+                reset_retried = true;
+                if !with_reset && prepare_reset(gateway, reset_policy, true).await {
+                    with_reset = true;
+                    ixs.push(reset_ix());
+                }
+                log::info!("Reset is due, retrying");
+                continue;
+            }
+            if tried.len().lt(&(BUS_RETRIES - 1))
+                && is_bus_exhausted(gateway, bus_id, &solution).await
+            {
+                log::info!("Bus {} is exhausted, retrying on another bus", bus_id);
+                tried.push(bus_id);
+                continue;
+            }
         }
+        return Submission {
+            result,
+            bus: bus_id,
+            attempts: status.attempts,
+            priority_fee: status.priority_fee,
+        };
     }
-    false
-}
-
-fn pick_bus() -> usize {
-    let mut rng = rand::thread_rng();
-    rng.gen_range(0..BUS_COUNT)
 }
@@ -0,0 +1,164 @@
+#[cfg(feature = "web")]
+use std::cell::RefCell;
+use std::sync::{
+    atomic::{AtomicBool, AtomicU64, Ordering},
+    Arc,
+};
+
+#[cfg(feature = "web")]
+use dioxus::prelude::spawn;
+#[cfg(feature = "web")]
+use dioxus_sdk::utils::channel::UseChannel;
+
+#[cfg(feature = "web")]
+use super::HashBackend;
+use super::{find_next_hash, WebWorkerRequest, WebWorkerResponse};
+
+/// Hashes on OS threads at full native speed.
+#[cfg(feature = "web")]
+pub struct NativeBackend {
+    cx: UseChannel<WebWorkerResponse>,
+    stop: RefCell<Arc<AtomicBool>>,
+    /// The running batch's requests and the hashes each thread has done so far.
+    batch: RefCell<Option<(Vec<WebWorkerRequest>, Arc<Vec<AtomicU64>>)>>,
+}
+
+#[cfg(feature = "web")]
+impl NativeBackend {
+    pub fn new(cx: UseChannel<WebWorkerResponse>) -> Self {
+        Self {
+            cx,
+            stop: RefCell::new(Arc::new(AtomicBool::new(false))),
+            batch: RefCell::new(None),
+        }
+    }
+}
+
+#[cfg(feature = "web")]
+impl HashBackend for NativeBackend {
+    fn start(&self, reqs: Vec<WebWorkerRequest>) {
+        log::info!("Start mining native on {} threads...", reqs.len());
+
+        // Stop any batch still running from a previous challenge
+        let stop = Arc::new(AtomicBool::new(false));
+        self.stop.replace(stop.clone()).store(true, Ordering::Relaxed);
+
+        // Forward shares as the threads find them
+        let (share_tx, share_rx) = async_std::channel::unbounded();
+        let cx = self.cx.clone();
+        spawn(async move {
+            while let Ok(res) = share_rx.recv().await {
+                cx.send(res).await.ok();
+            }
+        });
+
+        let hashed: Arc<Vec<AtomicU64>> =
+            Arc::new(reqs.iter().map(|_| AtomicU64::new(0)).collect());
+        self.batch.replace(Some((reqs.clone(), hashed.clone())));
+
+        let cx = self.cx.clone();
+        spawn(async move {
+            let stopped = stop.clone();
+            let on_share = move |res| {
+                share_tx.try_send(res).ok();
+            };
+            let results = async_std::task::spawn_blocking(move || {
+                find_next_hash_native(reqs, stop, hashed, on_share)
+            })
+            .await;
+
+            // Results of an abandoned batch would be mistaken for the next one
+            if stopped.load(Ordering::Relaxed) {
+                return;
+            }
+            for res in results {
+                cx.send(res).await.ok();
+            }
+        });
+    }
+
+    fn stop(&self) -> Vec<WebWorkerResponse> {
+        self.stop.borrow().store(true, Ordering::Relaxed);
+        let Some((reqs, hashed)) = self.batch.take() else {
+            return vec![];
+        };
+        reqs.iter()
+            .zip(hashed.iter())
+            .map(|(req, hashes)| WebWorkerResponse::partial(req, hashes.load(Ordering::Relaxed), 0))
+            .collect()
+    }
+
+    fn resize(&self, _hashers: usize) {
+        // Threads are spawned per batch, one per request
+    }
+
+    fn results(&self) -> UseChannel<WebWorkerResponse> {
+        self.cx.clone()
+    }
+}
+
+/// Number of OS threads available for mining.
+pub fn native_threads() -> usize {
+    std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+}
+
+/// Searches for the best drillx hash on one OS thread per request.
+///
+/// Each thread returns its own result, tagged with the request's `power_level` so the batch is
+/// processed the same way as web worker responses. Setting `stop` ends the search early. Once any
+/// thread finds a hash at `target` difficulty, every thread returns. Hashes at a request's share
+/// difficulty are passed to `on_share` as they are found. Thread `i` keeps `hashed[i]`, if
+/// present, up to date with the hashes it has done, so a stopped batch can still be recorded.
+pub fn find_next_hash_native(
+    reqs: Vec<WebWorkerRequest>,
+    stop: Arc<AtomicBool>,
+    hashed: Arc<Vec<AtomicU64>>,
+    on_share: impl Fn(WebWorkerResponse) + Clone + Send + 'static,
+) -> Vec<WebWorkerResponse> {
+    let found = Arc::new(AtomicBool::new(false));
+    let handles: Vec<_> = reqs
+        .into_iter()
+        .enumerate()
+        .map(|(i, req)| {
+            let stop = stop.clone();
+            let found = found.clone();
+            let hashed = hashed.clone();
+            let on_share = on_share.clone();
+            std::thread::spawn(move || {
+                find_next_hash_par(req, stop, found, hashed.get(i), on_share)
+            })
+        })
+        .collect();
+    handles
+        .into_iter()
+        .filter_map(|handle| handle.join().ok())
+        .collect()
+}
+
+fn find_next_hash_par(
+    req: WebWorkerRequest,
+    stop: Arc<AtomicBool>,
+    found: Arc<AtomicBool>,
+    hashed: Option<&AtomicU64>,
+    on_share: impl Fn(WebWorkerResponse),
+) -> WebWorkerResponse {
+    let target = req.target;
+    let res = find_next_hash(
+        req,
+        |hashes| {
+            if let Some(hashed) = hashed {
+                hashed.store(hashes, Ordering::Relaxed);
+            }
+            stop.load(Ordering::Relaxed) || found.load(Ordering::Relaxed)
+        },
+        on_share,
+    );
+
+    // Stop the other threads once the target is reached
+    if target.is_some_and(|target| res.difficulty.ge(&target)) {
+        found.store(true, Ordering::Relaxed);
+    }
+    res
+}
@@ -0,0 +1,72 @@
+#[cfg(feature = "web")]
+use gloo_storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+
+use super::WebWorkerResponse;
+
+#[cfg(feature = "web")]
+const KEY: &str = "nonce_progress";
+
+/// Number of equal lanes the nonce space is split into.
+const LANES: u64 = 1 << 16;
+
+/// Nonces hashed so far for one challenge.
+///
+/// The nonce space is split into fixed lanes and hasher `i` of every batch hashes lane `i`, from
+/// where that lane left off. Lanes never move, so ranges stay disjoint when the number of
+/// hashers changes, and a paused or reloaded miner picks up where it stopped.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct NonceProgress {
+    pub challenge: [u8; 32],
+    /// Next nonce to hash in each lane. Lanes past the end are untouched.
+    cursors: Vec<u64>,
+}
+
+impl NonceProgress {
+    pub fn new(challenge: [u8; 32]) -> Self {
+        Self {
+            challenge,
+            cursors: vec![],
+        }
+    }
+
+    /// Returns the saved progress for `challenge`, or fresh progress if there is none.
+    #[cfg(feature = "web")]
+    pub fn load(challenge: [u8; 32]) -> Self {
+        LocalStorage::get::<Self>(KEY)
+            .ok()
+            .filter(|progress| progress.challenge.eq(&challenge))
+            .unwrap_or_else(|| Self::new(challenge))
+    }
+
+    #[cfg(feature = "web")]
+    pub fn save(&self) {
+        LocalStorage::set(KEY, self).ok();
+    }
+
+    /// First unhashed nonce of hasher `worker`'s lane.
+    pub fn next(&self, worker: usize) -> u64 {
+        self.cursors
+            .get(worker)
+            .copied()
+            .unwrap_or_else(|| lane_start(worker))
+    }
+
+    /// Advances each lane to where its hasher stopped.
+    pub fn record(&mut self, messages: &[WebWorkerResponse]) {
+        for msg in messages {
+            if msg.challenge.ne(&self.challenge) {
+                continue;
+            }
+            while self.cursors.len().le(&msg.worker) {
+                self.cursors.push(lane_start(self.cursors.len()));
+            }
+            let cursor = &mut self.cursors[msg.worker];
+            *cursor = (*cursor).max(msg.offset);
+        }
+    }
+}
+
+fn lane_start(worker: usize) -> u64 {
+    (u64::MAX / LANES).saturating_mul(worker as u64 % LANES)
+}
@@ -0,0 +1,86 @@
+use dioxus::prelude::*;
+use solana_client_wasm::solana_sdk::{keccak::Hash as KeccakHash, pubkey::Pubkey};
+
+use super::{best_batch_result, Miner, WebWorkerResponse};
+use crate::{
+    gateway::{GatewayResult, PoolClient, PoolJob, PoolShare},
+    hooks::{MinerStatus, MinerStatusMessage, MinerToolbarState, UpdateMinerToolbarState},
+};
+
+impl Miner {
+    /// Returns a client for the configured pool, or `None` when mining solo.
+    pub fn pool(&self) -> Option<PoolClient> {
+        let pool_url = self.pool_url.read();
+        if pool_url.is_solo() {
+            None
+        } else {
+            Some(PoolClient::new(&pool_url.0))
+        }
+    }
+
+    /// Fetches a job from the pool and starts hashing its nonce range.
+    pub async fn start_pool_mining(&self, pool: &PoolClient, pubkey: Pubkey) -> GatewayResult<()> {
+        let job = pool.get_job(pubkey).await?;
+        let nonces = job.nonce_start..job.nonce_end;
+        self.start_batch(
+            job.challenge,
+            |worker, size| {
+                (nonces.end - nonces.start)
+                    .saturating_div(size as u64)
+                    .saturating_mul(worker as u64)
+                    .saturating_add(nonces.start)
+            },
+            job.cutoff_time,
+            None,
+            Some(job.share_difficulty),
+        );
+        *self.pool_job.borrow_mut() = Some(job);
+        Ok(())
+    }
+
+    /// Posts a share to the pool as soon as a hasher finds it, while the batch keeps hashing.
+    pub fn submit_pool_share(&self, msg: &WebWorkerResponse, pubkey: Pubkey) {
+        let job: Option<PoolJob> = self.pool_job.borrow().clone();
+        let (Some(pool), Some(job)) = (self.pool(), job) else {
+            return;
+        };
+        if msg.challenge.ne(&job.challenge) || msg.difficulty.lt(&job.share_difficulty) {
+            return;
+        }
+        let share = PoolShare {
+            member: pubkey.to_string(),
+            round: job.round,
+            digest: msg.digest,
+            nonce: msg.nonce,
+        };
+        spawn(async move {
+            match pool.submit_share(&share).await {
+                Ok(res) if res.accepted => {
+                    log::info!("Share accepted: difficulty {}", res.difficulty)
+                }
+                Ok(res) => log::info!("Share rejected: {:?}", res.reason),
+                Err(err) => log::error!("Failed to submit share: {:?}", err),
+            }
+        });
+    }
+
+    /// Shows the batch's best hash and moves on to the next job. Its shares were already posted
+    /// as they were found.
+    pub(super) async fn process_pool_results(
+        &self,
+        pool: &PoolClient,
+        messages: &[WebWorkerResponse],
+        toolbar_state: &mut Signal<MinerToolbarState>,
+        pubkey: Pubkey,
+    ) {
+        let best = best_batch_result(messages);
+        toolbar_state.set_display_hash(KeccakHash::new_from_array(best.hash));
+
+        toolbar_state.set_status_message(MinerStatusMessage::Searching);
+        if let Err(err) = self.start_pool_mining(pool, pubkey).await {
+            log::error!("Failed to fetch pool job: {:?}", err);
+            toolbar_state.set_status(MinerStatus::Error);
+            toolbar_state.set_status_message(MinerStatusMessage::Error);
+        }
+    }
+}
@@ -0,0 +1,227 @@
+use std::{cell::RefCell, rc::Rc};
+
+use dioxus_sdk::utils::channel::UseChannel;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use web_sys::{MessageEvent, WebSocket};
+
+use super::{WebWorkerRequest, WebWorkerResponse};
+
+/// Message sent by a hashing agent.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum AgentMessage {
+    /// Sent once after connecting.
+    Hello { cores: usize },
+    /// Best hash found for one `Hash` request, or a share found while hashing it.
+    Result(WebWorkerResponse),
+}
+
+/// Message sent to a hashing agent.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum AgentCommand {
+    /// Hash on one core, exactly like a web worker.
+    Hash(WebWorkerRequest),
+    /// Cut the running requests short. Their results are still sent.
+    Stop,
+}
+
+/// Native hashing agents connected over WebSocket.
+///
+/// Each agent core is treated as one more web worker: it gets its own `WebWorkerRequest` and
+/// sends back one final `WebWorkerResponse`, so agent results merge into the same batch as the
+/// local ones. The agent binary lives in `serve/src/bin/agent.rs`.
+pub struct RemoteAgents {
+    cx: UseChannel<WebWorkerResponse>,
+    agents: Rc<RefCell<Vec<RemoteAgent>>>,
+    /// Results sent for stopped batches. They still say how far each core got.
+    stopped: Rc<RefCell<Vec<WebWorkerResponse>>>,
+}
+
+struct RemoteAgent {
+    url: String,
+    socket: WebSocket,
+    cores: usize,
+    pending: Vec<WebWorkerRequest>,
+    stale: usize,
+}
+
+impl RemoteAgents {
+    pub fn new(cx: UseChannel<WebWorkerResponse>) -> Self {
+        Self {
+            cx,
+            agents: Rc::new(RefCell::new(vec![])),
+            stopped: Rc::new(RefCell::new(vec![])),
+        }
+    }
+
+    /// Connects to every url not already connected and closes agents no longer listed.
+    ///
+    /// Agents are removed once their socket closes, so calling this again reconnects lost agents.
+    pub fn connect(&self, urls: &[String]) {
+        let mut agents = self.agents.borrow_mut();
+        for agent in agents.iter() {
+            if !urls.contains(&agent.url) {
+                agent.socket.close().ok();
+            }
+        }
+        for url in urls {
+            if agents.iter().any(|agent| agent.url.eq(url)) {
+                continue;
+            }
+            match create_socket(
+                url,
+                self.agents.clone(),
+                self.stopped.clone(),
+                self.cx.clone(),
+            ) {
+                Ok(socket) => agents.push(RemoteAgent {
+                    url: url.clone(),
+                    socket,
+                    cores: 0,
+                    pending: vec![],
+                    stale: 0,
+                }),
+                Err(err) => log::info!("Failed to connect to agent {}: {:?}", url, err),
+            }
+        }
+    }
+
+    /// Total cores of the agents that are connected and have said hello.
+    pub fn cores(&self) -> usize {
+        self.agents
+            .borrow()
+            .iter()
+            .filter(|agent| agent.socket.ready_state().eq(&WebSocket::OPEN))
+            .map(|agent| agent.cores)
+            .sum()
+    }
+
+    /// Posts one request per agent core, in order of connection.
+    ///
+    /// Requests that cannot be sent are answered with an empty response, so the batch completes.
+    pub fn start(&self, reqs: Vec<WebWorkerRequest>) {
+        let mut reqs = reqs.into_iter();
+        let mut missing = vec![];
+        for agent in self.agents.borrow_mut().iter_mut() {
+            if agent.socket.ready_state().ne(&WebSocket::OPEN) {
+                continue;
+            }
+            for req in reqs.by_ref().take(agent.cores) {
+                let cmd = serde_json::to_string(&AgentCommand::Hash(req.clone())).unwrap();
+                if agent.socket.send_with_str(&cmd).is_ok() {
+                    agent.pending.push(req);
+                } else {
+                    missing.push(req);
+                }
+            }
+        }
+        for req in missing.into_iter().chain(reqs) {
+            send(&self.cx, WebWorkerResponse::missing(&req));
+        }
+    }
+
+    /// Cuts the running batch short. Results still in flight are kept out of the next batch and
+    /// collected by [`RemoteAgents::take_stopped`].
+    pub fn stop(&self) {
+        let cmd = serde_json::to_string(&AgentCommand::Stop).unwrap();
+        for agent in self.agents.borrow_mut().iter_mut() {
+            if agent.pending.is_empty() {
+                continue;
+            }
+            agent.socket.send_with_str(&cmd).ok();
+            agent.stale += agent.pending.len();
+            agent.pending.clear();
+        }
+    }
+
+    /// Takes the results that arrived for stopped batches, to record how far they got.
+    pub fn take_stopped(&self) -> Vec<WebWorkerResponse> {
+        self.stopped.take()
+    }
+}
+
+fn create_socket(
+    url: &str,
+    agents: Rc<RefCell<Vec<RemoteAgent>>>,
+    stopped: Rc<RefCell<Vec<WebWorkerResponse>>>,
+    cx: UseChannel<WebWorkerResponse>,
+) -> Result<WebSocket, JsValue> {
+    log::info!("Connecting to agent {}...", url);
+    let socket = WebSocket::new(url)?;
+
+    // On message
+    let url_ = url.to_string();
+    let agents_ = agents.clone();
+    let cx_ = cx.clone();
+    socket.set_onmessage(Some(&js_sys::Function::unchecked_from_js(
+        Closure::<dyn Fn(MessageEvent)>::new(move |e: MessageEvent| {
+            let Some(text) = e.data().as_string() else {
+                return;
+            };
+            let msg = match serde_json::from_str::<AgentMessage>(&text) {
+                Ok(msg) => msg,
+                Err(err) => {
+                    log::info!("Invalid message from agent {}: {:?}", url_, err);
+                    return;
+                }
+            };
+            let mut agents = agents_.borrow_mut();
+            let Some(agent) = agents.iter_mut().find(|agent| agent.url.eq(&url_)) else {
+                return;
+            };
+            match msg {
+                AgentMessage::Hello { cores } => {
+                    log::info!("Agent {} connected with {} cores", url_, cores);
+                    agent.cores = cores;
+                }
+                AgentMessage::Result(res) if res.share => {
+                    drop(agents);
+                    send(&cx_, res);
+                }
+                AgentMessage::Result(res) => {
+                    if agent.stale.gt(&0) {
+                        agent.stale -= 1;
+                        stopped.borrow_mut().push(res);
+                        return;
+                    }
+                    agent.pending.pop();
+                    drop(agents);
+                    send(&cx_, res);
+                }
+            }
+        })
+        .into_js_value(),
+    )));
+
+    // On close
+    let url_ = url.to_string();
+    socket.set_onclose(Some(&js_sys::Function::unchecked_from_js(
+        Closure::<dyn Fn(JsValue)>::new(move |_| {
+            log::info!("Agent {} disconnected", url_);
+            let pending = {
+                let mut agents = agents.borrow_mut();
+                let Some(i) = agents.iter().position(|agent| agent.url.eq(&url_)) else {
+                    return;
+                };
+                agents.remove(i).pending
+            };
+
+            // Fill in for lost requests so the batch still completes
+            for req in pending {
+                send(&cx, WebWorkerResponse::missing(&req));
+            }
+        })
+        .into_js_value(),
+    )));
+
+    Ok(socket)
+}
+
+pub(super) fn send(cx: &UseChannel<WebWorkerResponse>, res: WebWorkerResponse) {
+    async_std::task::block_on({
+        let cx = cx.clone();
+        async move {
+            cx.send(res).await.ok();
+        }
+    });
+}
@@ -0,0 +1,85 @@
+use std::{rc::Rc, time::Duration};
+
+use ore_api::consts::EPOCH_DURATION;
+use solana_client_wasm::solana_sdk::{instruction::Instruction, signer::Signer};
+
+use crate::{
+    gateway::{signer, ComputeBudget, Gateway, GatewayResult, CU_LIMIT_RESET},
+    hooks::ResetPolicy,
+};
+
+/// Seconds a transaction sent now takes to land, so a reset due by then is sent with it.
+const LANDING_SECS: i64 = 5;
+
+/// Seconds a miner that leaves resets to others waits for one before resetting itself.
+const RESET_WAIT_SECS: u64 = 10;
+
+/// Seconds until the epoch ends by the chain's clock. Negative once a reset is due.
+///
+/// The clock sysvar can drift from this machine's clock, so reset timing never uses local time.
+pub async fn seconds_until_reset(gateway: &Rc<Gateway>) -> Option<i64> {
+    let (Ok(clock), Ok(config)) = (gateway.get_clock().await, gateway.get_config().await) else {
+        return None;
+    };
+    Some(
+        config
+            .last_reset_at
+            .saturating_add(EPOCH_DURATION)
+            .saturating_sub(clock.unix_timestamp),
+    )
+}
+
+/// Returns true if the epoch will have ended by the time a transaction sent now lands.
+pub async fn needs_reset(gateway: &Rc<Gateway>) -> bool {
+    seconds_until_reset(gateway)
+        .await
+        .is_some_and(|secs| secs.le(&LANDING_SECS))
+}
+
+pub fn reset_ix() -> Instruction {
+    ore_api::instruction::reset(signer().pubkey())
+}
+
+/// Sends a reset in its own transaction.
+pub async fn send_reset(gateway: &Rc<Gateway>) -> GatewayResult<()> {
+    log::info!("Sending epoch reset...");
+    gateway
+        .send_and_confirm(
+            &[reset_ix()],
+            ComputeBudget::FixedLimitEstimatePrice(CU_LIMIT_RESET),
+            false,
+            None,
+        )
+        .await
+        .map(|sig| log::info!("Reset: {}", sig))
+}
+
+/// Resets the epoch under `policy` if a reset is due. Returns true if the mine transaction
+/// should carry the reset instruction itself.
+///
+/// `after_failure` is set when a mine just failed. A pending reset then blocks every mine, so a
+/// miner that leaves resets to others gives them a moment and then resets anyway.
+pub async fn prepare_reset(
+    gateway: &Rc<Gateway>,
+    policy: ResetPolicy,
+    after_failure: bool,
+) -> bool {
+    if !needs_reset(gateway).await {
+        return false;
+    }
+    match policy {
+        ResetPolicy::Include => true,
+        ResetPolicy::Standalone => match send_reset(gateway).await {
+            Ok(()) => false,
+            Err(err) => {
+                log::error!("Failed to send reset: {:?}", err);
+                true
+            }
+        },
+        ResetPolicy::Wait if after_failure => {
+            async_std::task::sleep(Duration::from_secs(RESET_WAIT_SECS)).await;
+            needs_reset(gateway).await
+        }
+        ResetPolicy::Wait => false,
+    }
+}
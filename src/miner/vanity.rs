@@ -0,0 +1,147 @@
+use serde::{Deserialize, Serialize};
+use solana_client_wasm::solana_sdk::signature::{Keypair, Signer};
+use web_time::Instant;
+
+/// Characters that can appear in a base58 address.
+pub const BASE58_ALPHABET: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Longest pattern accepted. Each extra character multiplies the search time by up to 58.
+pub const MAX_VANITY_LENGTH: usize = 8;
+
+/// Vanity search request for web workers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VanityRequest {
+    pub worker: usize,
+    pub prefix: String,
+    pub suffix: String,
+    pub case_sensitive: bool,
+    pub duration_ms: u64,
+}
+
+/// Vanity search response for web workers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VanityResponse {
+    pub worker: usize,
+    pub attempts: u64,
+    pub keypair: Option<Vec<u8>>,
+}
+
+/// Returns true if every character of the pattern can appear in an address. When the search
+/// ignores case, a character only needs one of its cases in the alphabet.
+pub fn is_valid_vanity_pattern(pattern: &str, case_sensitive: bool) -> bool {
+    pattern
+        .chars()
+        .all(|c| alphabet_matches(c, case_sensitive).next().is_some())
+}
+
+/// Average number of keypairs to generate before one matches.
+pub fn expected_vanity_attempts(prefix: &str, suffix: &str, case_sensitive: bool) -> f64 {
+    let alphabet_len = BASE58_ALPHABET.len() as f64;
+    let mut prefix = prefix.chars();
+    let first = prefix.next().map_or(1.0, |c| {
+        1.0 / alphabet_matches(c, case_sensitive)
+            .map(first_char_chance)
+            .sum::<f64>()
+    });
+    first
+        * prefix
+            .chain(suffix.chars())
+            .map(|c| alphabet_len / alphabet_matches(c, case_sensitive).count() as f64)
+            .product::<f64>()
+}
+
+/// Alphabet indices of the characters `c` matches.
+fn alphabet_matches(c: char, case_sensitive: bool) -> impl Iterator<Item = usize> {
+    BASE58_ALPHABET
+        .chars()
+        .enumerate()
+        .filter(move |(_, a)| {
+            if case_sensitive {
+                a.eq(&c)
+            } else {
+                a.eq_ignore_ascii_case(&c)
+            }
+        })
+        .map(|(i, _)| i)
+}
+
+/// Chance that an address starts with the character at `index` of the alphabet.
+///
+/// Addresses encode 32-byte keys and 58^43 < 2^256 < 58^44, so about 94% of them are 44
+/// characters long and start with one of `2`..`J`. The rest are shorter and start with any
+/// character, `1` standing for a leading zero byte.
+fn first_char_chance(index: usize) -> f64 {
+    let short = 58f64.powi(43) / 2f64.powi(256);
+    let leading_zero = 1.0 / 256.0;
+    let short_chance = (short - leading_zero) / 57.0;
+    match index {
+        0 => leading_zero,
+        1..=16 => short + short_chance,
+        17 => 1.0 - 17.0 * short + short_chance,
+        _ => short_chance,
+    }
+}
+
+/// Generates keypairs until one matches or the time budget runs out.
+pub fn find_vanity_keypair(req: VanityRequest) -> VanityResponse {
+    let timer = Instant::now();
+    let (prefix, suffix) = if req.case_sensitive {
+        (req.prefix, req.suffix)
+    } else {
+        (req.prefix.to_lowercase(), req.suffix.to_lowercase())
+    };
+    let mut attempts = 0;
+    loop {
+        let keypair = Keypair::new();
+        let address = keypair.pubkey().to_string();
+        let address = if req.case_sensitive {
+            address
+        } else {
+            address.to_lowercase()
+        };
+        attempts += 1;
+        if address.starts_with(&prefix) && address.ends_with(&suffix) {
+            return VanityResponse {
+                worker: req.worker,
+                attempts,
+                keypair: Some(keypair.to_bytes().to_vec()),
+            };
+        }
+
+        // Report back regularly so progress shows and cancellation is quick
+        if attempts % 100 == 0 && timer.elapsed().as_millis().ge(&(req.duration_ms as u128)) {
+            return VanityResponse {
+                worker: req.worker,
+                attempts,
+                keypair: None,
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_char_chances_sum_to_one() {
+        let total: f64 = (0..BASE58_ALPHABET.len()).map(first_char_chance).sum();
+        assert!((total - 1.0).abs().lt(&1e-9));
+    }
+
+    #[test]
+    fn lowercase_prefix_is_rarer() {
+        let upper = expected_vanity_attempts("A", "", true);
+        let lower = expected_vanity_attempts("a", "", true);
+        assert!(upper.lt(&58.0));
+        assert!(lower.gt(&(17.0 * 58.0)));
+        assert_eq!(expected_vanity_attempts("", "a", true), 58.0);
+    }
+
+    #[test]
+    fn ignoring_case_accepts_either_case() {
+        assert!(!is_valid_vanity_pattern("O", true));
+        assert!(is_valid_vanity_pattern("O", false));
+        assert!(!is_valid_vanity_pattern("0", false));
+    }
+}
@@ -1,30 +1,317 @@
+use std::{
+    cell::{Cell, RefCell},
+    rc::{Rc, Weak},
+    time::Duration,
+};
+
+use dioxus::prelude::spawn;
 use dioxus_sdk::utils::channel::UseChannel;
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use serde_wasm_bindgen::{from_value, to_value};
 use wasm_bindgen::prelude::*;
 use web_sys::{DedicatedWorkerGlobalScope, MessageEvent, Worker, WorkerOptions, WorkerType};
 use web_time::Instant;
 
-/// Mining request for web workers
+use super::{
+    find_next_hash, find_vanity_keypair, remote::send, HashBackend, VanityRequest,
+    WebWorkerRequest, WebWorkerResponse,
+};
+
+/// Message posted to a web worker. Mining and vanity search share the same worker script.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct WebWorkerRequest {
-    pub challenge: [u8; 32],
-    pub nonce: [u8; 8],
-    pub offset: u64,
-    pub cutoff_time: u64,
-    pub power_level: usize,
+pub enum WorkerMessage {
+    Mine(WebWorkerRequest),
+    Vanity(VanityRequest),
 }
 
-/// Mining response for web workers
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct WebWorkerResponse {
-    pub hash: [u8; 32],
-    pub digest: [u8; 16],
-    pub nonce: [u8; 8],
-    pub difficulty: u32,
-    pub offset: u64,
-    pub challenge: [u8; 32],
-    pub power_level: usize,
+/// Seconds past its cutoff time a worker may take to answer before it counts as hung.
+const WATCHDOG_GRACE_SECS: u64 = 10;
+
+/// Times a request is retried on a fresh worker before it is given up.
+const MAX_RESTARTS: usize = 2;
+
+/// Hashes in a pool of web workers, one per core.
+///
+/// A watchdog checks every running request. Workers that crash or miss their deadline are
+/// terminated and replaced, and their nonce range is hashed again by the replacement for the
+/// time left in the batch. The pool grows and shrinks with the power level between batches.
+/// Workers cannot report progress mid-hash, so when a batch is stopped each worker's position
+/// is estimated from the last measured hashrate.
+pub struct WebWorkerPool {
+    state: Rc<PoolState>,
+}
+
+struct PoolState {
+    cx: UseChannel<WebWorkerResponse>,
+    slots: RefCell<Vec<WorkerSlot>>,
+    size: Cell<usize>,
+    is_running: Cell<bool>,
+    /// Hashes per millisecond of the last worker to answer.
+    hashrate: Cell<f64>,
+}
+
+struct WorkerSlot {
+    worker: Worker,
+    job: Option<WorkerJob>,
+}
+
+/// A request a worker has not answered yet.
+struct WorkerJob {
+    req: WebWorkerRequest,
+    started_at: Instant,
+    deadline: Instant,
+    restarts: usize,
+    /// Hashes done as of the worker's latest share.
+    hashes: u64,
+}
+
+impl WorkerJob {
+    fn new(req: WebWorkerRequest, restarts: usize) -> Self {
+        let started_at = Instant::now();
+        Self {
+            deadline: started_at + Duration::from_secs(req.cutoff_time + WATCHDOG_GRACE_SECS),
+            started_at,
+            req,
+            restarts,
+            hashes: 0,
+        }
+    }
+
+    /// Estimates how far the worker got, at `hashrate` hashes per millisecond.
+    fn partial(&self, hashrate: f64) -> WebWorkerResponse {
+        let elapsed_ms = self.started_at.elapsed().as_millis() as u64;
+        let estimate = (elapsed_ms as f64 * hashrate) as u64;
+        WebWorkerResponse::partial(&self.req, estimate.max(self.hashes), elapsed_ms)
+    }
+}
+
+impl Drop for PoolState {
+    fn drop(&mut self) {
+        for slot in self.slots.get_mut().drain(..) {
+            slot.worker.terminate();
+        }
+    }
+}
+
+impl WebWorkerPool {
+    pub fn new(cx: UseChannel<WebWorkerResponse>, size: usize) -> Self {
+        let state = Rc::new(PoolState {
+            cx,
+            slots: RefCell::new(vec![]),
+            size: Cell::new(size),
+            is_running: Cell::new(false),
+            hashrate: Cell::new(0.0),
+        });
+        state.fill();
+        spawn(watchdog(Rc::downgrade(&state)));
+        Self { state }
+    }
+}
+
+impl PoolState {
+    /// Spawns workers up to the pool size.
+    fn fill(self: &Rc<Self>) {
+        let mut slots = self.slots.borrow_mut();
+        while slots.len().lt(&self.size.get()) {
+            let worker = create_hasher(Rc::downgrade(self), slots.len());
+            slots.push(WorkerSlot { worker, job: None });
+        }
+    }
+
+    /// Terminates idle workers past the pool size. Busy ones are trimmed once they answer.
+    fn trim(&self) {
+        let mut slots = self.slots.borrow_mut();
+        while slots.len().gt(&self.size.get()) && slots.last().is_some_and(|s| s.job.is_none()) {
+            if let Some(slot) = slots.pop() {
+                slot.worker.terminate();
+            }
+        }
+    }
+
+    /// Replaces the worker in slot `i` and hands it the dead worker's request, if any.
+    fn respawn(self: &Rc<Self>, i: usize) {
+        let mut slots = self.slots.borrow_mut();
+        let Some(slot) = slots.get_mut(i) else {
+            return;
+        };
+        slot.worker.terminate();
+        slot.worker = create_hasher(Rc::downgrade(self), i);
+        let Some(job) = slot.job.take() else {
+            return;
+        };
+        if job.restarts.ge(&MAX_RESTARTS) {
+            log::error!(
+                "Web worker {} failed {} times, skipping its nonces",
+                i,
+                job.restarts + 1
+            );
+            drop(slots);
+            send(&self.cx, WebWorkerResponse::missing(&job.req));
+            self.trim();
+            return;
+        }
+        log::info!("Restarting web worker {}", i);
+        let elapsed = job.started_at.elapsed().as_secs();
+        let req = WebWorkerRequest {
+            cutoff_time: job.req.cutoff_time.saturating_sub(elapsed),
+            ..job.req
+        };
+        if post(&slot.worker, &req) {
+            slot.job = Some(WorkerJob::new(req, job.restarts + 1));
+        } else {
+            drop(slots);
+            send(&self.cx, WebWorkerResponse::missing(&req));
+        }
+    }
+
+    /// Called when the worker in slot `i` answers or sends a share.
+    fn on_result(&self, i: usize, res: WebWorkerResponse) {
+        if res.share {
+            if let Some(job) = self
+                .slots
+                .borrow_mut()
+                .get_mut(i)
+                .and_then(|slot| slot.job.as_mut())
+            {
+                job.hashes = job.hashes.max(res.hashes);
+            }
+        } else {
+            if let Some(slot) = self.slots.borrow_mut().get_mut(i) {
+                slot.job = None;
+            }
+            if res.elapsed_ms.gt(&0) {
+                self.hashrate.set(res.hashes as f64 / res.elapsed_ms as f64);
+            }
+            self.trim();
+        }
+        send(&self.cx, res);
+    }
+
+    /// Called when the worker in slot `i` throws. It is replaced on the next watchdog check.
+    fn on_error(&self, i: usize) {
+        if let Some(job) = self
+            .slots
+            .borrow_mut()
+            .get_mut(i)
+            .and_then(|slot| slot.job.as_mut())
+        {
+            job.deadline = Instant::now();
+        }
+    }
+}
+
+impl HashBackend for WebWorkerPool {
+    fn start(&self, reqs: Vec<WebWorkerRequest>) {
+        self.state.size.set(self.state.size.get().max(reqs.len()));
+        self.state.fill();
+        log::info!("Start mining web...");
+        let mut missing = vec![];
+        let mut slots = self.state.slots.borrow_mut();
+        for (slot, req) in slots.iter_mut().zip(reqs) {
+            log::info!("Posting message: {:?} {:?}", req.worker, req.offset);
+            if post(&slot.worker, &req) {
+                slot.job = Some(WorkerJob::new(req, 0));
+            } else {
+                missing.push(req);
+            }
+        }
+        drop(slots);
+        self.state.is_running.set(true);
+        for req in missing {
+            send(&self.state.cx, WebWorkerResponse::missing(&req));
+        }
+    }
+
+    fn stop(&self) -> Vec<WebWorkerResponse> {
+        // Workers cannot be interrupted mid-batch, so replace them
+        if !self.state.is_running.replace(false) {
+            return vec![];
+        }
+        let hashrate = self.state.hashrate.get();
+        let partial = self
+            .state
+            .slots
+            .borrow_mut()
+            .drain(..)
+            .filter_map(|slot| {
+                slot.worker.terminate();
+                slot.job.map(|job| job.partial(hashrate))
+            })
+            .collect();
+        self.state.fill();
+        partial
+    }
+
+    fn resize(&self, hashers: usize) {
+        self.state.size.set(hashers);
+        self.state.trim();
+        self.state.fill();
+    }
+
+    fn results(&self) -> UseChannel<WebWorkerResponse> {
+        self.state.cx.clone()
+    }
+}
+
+/// Replaces workers that crashed or hung, until the pool is dropped.
+async fn watchdog(state: Weak<PoolState>) {
+    loop {
+        async_std::task::sleep(Duration::from_secs(1)).await;
+        let Some(state) = state.upgrade() else {
+            return;
+        };
+        let now = Instant::now();
+        let expired: Vec<usize> = state
+            .slots
+            .borrow()
+            .iter()
+            .enumerate()
+            .filter(|(_, slot)| slot.job.as_ref().is_some_and(|job| job.deadline.le(&now)))
+            .map(|(i, _)| i)
+            .collect();
+        for i in expired {
+            log::error!("Web worker {} did not answer in time", i);
+            state.respawn(i);
+        }
+    }
+}
+
+/// Creates a mining worker for slot `i` that reports back to the pool.
+fn create_hasher(state: Weak<PoolState>, i: usize) -> Worker {
+    let worker = Worker::new_with_options("worker.js", &worker_options()).unwrap();
+
+    // On message
+    let state_ = state.clone();
+    worker.set_onmessage(Some(&js_sys::Function::unchecked_from_js(
+        Closure::<dyn Fn(MessageEvent)>::new(move |e: MessageEvent| {
+            let (Some(state), Ok(res)) = (state_.upgrade(), from_value(e.data())) else {
+                return;
+            };
+            state.on_result(i, res);
+        })
+        .into_js_value(),
+    )));
+
+    // On error
+    worker.set_onerror(Some(&js_sys::Function::unchecked_from_js(
+        Closure::<dyn Fn(MessageEvent)>::new(move |e: MessageEvent| {
+            log::error!("Error from web worker {}: {:?}", i, e.data());
+            if let Some(state) = state.upgrade() {
+                state.on_error(i);
+            }
+        })
+        .into_js_value(),
+    )));
+
+    worker
+}
+
+/// Posts a request to a worker. Returns false if it could not be sent.
+fn post(worker: &Worker, req: &WebWorkerRequest) -> bool {
+    match to_value(&WorkerMessage::Mine(req.clone())) {
+        Ok(msg) => worker.post_message(&msg).is_ok(),
+        Err(_) => false,
+    }
 }
 
 #[wasm_bindgen]
@@ -38,15 +325,23 @@ pub fn start_worker() {
 
     scope.set_onmessage(Some(&js_sys::Function::unchecked_from_js(
         Closure::<dyn Fn(MessageEvent)>::new(move |e: MessageEvent| {
-            let req: WebWorkerRequest = from_value(e.data()).unwrap();
-            let res = find_next_hash(
-                req.challenge,
-                req.nonce,
-                req.offset,
-                req.cutoff_time,
-                req.power_level,
-            );
-            scope_.post_message(&to_value(&res).unwrap()).unwrap();
+            let res = match from_value::<WorkerMessage>(e.data()) {
+                Ok(WorkerMessage::Mine(req)) => to_value(&find_next_hash(
+                    req,
+                    |_| false,
+                    |share| {
+                        if let Ok(msg) = to_value(&share) {
+                            scope_.post_message(&msg).ok();
+                        }
+                    },
+                )),
+                Ok(WorkerMessage::Vanity(req)) => to_value(&find_vanity_keypair(req)),
+                Err(err) => {
+                    log::error!("Dropping unknown web worker message: {:?}", err);
+                    return;
+                }
+            };
+            scope_.post_message(&res.unwrap()).unwrap();
         })
         .into_js_value(),
     )))
@@ -58,14 +353,17 @@ fn worker_options() -> WorkerOptions {
     options
 }
 
-pub fn create_web_worker(cx: UseChannel<WebWorkerResponse>) -> Worker {
+pub fn create_web_worker<T>(cx: UseChannel<T>) -> Worker
+where
+    T: DeserializeOwned + Clone + 'static,
+{
     log::info!("Creating webworker..."); // MI
     let worker = Worker::new_with_options("worker.js", &worker_options()).unwrap();
 
     // On message
     worker.set_onmessage(Some(&js_sys::Function::unchecked_from_js(
         Closure::<dyn Fn(MessageEvent)>::new(move |e: MessageEvent| {
-            let res: WebWorkerResponse = from_value(e.data()).unwrap();
+            let res: T = from_value(e.data()).unwrap();
             async_std::task::block_on({
                 let cx = cx.clone();
                 async move {
@@ -86,54 +384,3 @@ pub fn create_web_worker(cx: UseChannel<WebWorkerResponse>) -> Worker {
 
     worker
 }
-
-pub fn find_next_hash(
-    challenge: [u8; 32],
-    nonce: [u8; 8],
-    offset: u64,
-    cutoff_time: u64,
-    power_level: usize,
-) -> WebWorkerResponse {
-    let timer = Instant::now();
-    let mut i = 0;
-    let mut nonce = u64::from_le_bytes(nonce);
-    let mut best_hash = [0u8; 32];
-    let mut best_digest = [0u8; 16];
-    let mut best_nonce = [0u8; 8];
-    let mut best_difficulty = 0u32;
-    let mut memory = drillx::equix::SolverMemory::new();
-    loop {
-        if let Ok(hash) = drillx::hash_with_memory(&mut memory, &challenge, &nonce.to_le_bytes()) {
-            let difficulty = hash.difficulty();
-            if difficulty.gt(&best_difficulty) {
-                best_digest = hash.d;
-                best_difficulty = difficulty;
-                best_nonce = nonce.to_le_bytes();
-                best_hash = hash.h;
-            }
-        }
-
-        // Break if time has elapsed and batch size is processed
-        if nonce % 20 == 0 {
-            // log::info!("Hash: {:?} {:?}", i, nonce);
-            if timer.elapsed().as_secs().gt(&cutoff_time) {
-                if i.ge(&100) {
-                    break;
-                }
-            }
-        }
-
-        nonce += 1;
-        i += 1;
-    }
-
-    WebWorkerResponse {
-        digest: best_digest,
-        hash: best_hash,
-        nonce: best_nonce,
-        difficulty: best_difficulty,
-        offset: offset + i,
-        challenge,
-        power_level,
-    }
-}
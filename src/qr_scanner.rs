@@ -0,0 +1,65 @@
+use js_sys::{Array, Promise, Reflect, Uint8Array};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{Blob, ClipboardEvent, Event, File};
+
+// Bindings to the browser's Shape Detection API. Not every browser ships it,
+// so the constructor is looked up at runtime.
+#[wasm_bindgen]
+extern "C" {
+    type BarcodeDetector;
+
+    #[wasm_bindgen(constructor, catch)]
+    fn new(options: &JsValue) -> Result<BarcodeDetector, JsValue>;
+
+    #[wasm_bindgen(method)]
+    fn detect(this: &BarcodeDetector, image: &JsValue) -> Promise;
+
+    #[wasm_bindgen(js_name = createImageBitmap)]
+    fn create_image_bitmap(image: &Blob) -> Promise;
+}
+
+/// Returns true if the browser can decode QR codes from images.
+pub fn is_supported() -> bool {
+    Reflect::has(&js_sys::global(), &"BarcodeDetector".into()).unwrap_or(false)
+}
+
+/// Decodes every QR code found in an image file.
+pub async fn decode_qr_image(bytes: &[u8]) -> Result<Vec<String>, JsValue> {
+    let parts = Array::of1(&Uint8Array::from(bytes));
+    let blob = Blob::new_with_u8_array_sequence(&parts)?;
+    decode_qr_blob(&blob).await
+}
+
+/// Returns the images pasted with a clipboard event, e.g. a copied screenshot.
+pub fn clipboard_images(event: &Event) -> Vec<File> {
+    let Some(files) = event
+        .dyn_ref::<ClipboardEvent>()
+        .and_then(|event| event.clipboard_data())
+        .and_then(|data| data.files())
+    else {
+        return vec![];
+    };
+    (0..files.length())
+        .filter_map(|i| files.get(i))
+        .filter(|file| file.type_().starts_with("image/"))
+        .collect()
+}
+
+/// Decodes every QR code found in an image blob.
+pub async fn decode_qr_blob(blob: &Blob) -> Result<Vec<String>, JsValue> {
+    let image = JsFuture::from(create_image_bitmap(blob)).await?;
+
+    let options = js_sys::Object::new();
+    Reflect::set(&options, &"formats".into(), &Array::of1(&"qr_code".into()))?;
+    let detector = BarcodeDetector::new(&options)?;
+    let results: Array = JsFuture::from(detector.detect(&image))
+        .await?
+        .unchecked_into();
+
+    Ok(results
+        .iter()
+        .filter_map(|result| Reflect::get(&result, &"rawValue".into()).ok())
+        .filter_map(|value| value.as_string())
+        .collect())
+}
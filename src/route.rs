@@ -1,8 +1,8 @@
 use dioxus::prelude::*;
 
 use crate::components::{
-    Claim, Download, ExportKey, Home, ImportKey, Landing, Mine, MinerToolbarLayout, Navbar, OreTokenomics,
-    PageNotFound, Pay, Send, Settings, SimpleNavbar, Stake, User, Upgrade, WhatIsMining,
+    Backup, Claim, Dashboard, Download, ExportKey, Home, ImportKey, Landing, Mine, MinerToolbarLayout, Navbar, NewKey, OreTokenomics,
+    PageNotFound, Pay, Reauthenticate, Send, SetPassphrase, Settings, SignMessage, SimpleNavbar, Stake, User, Upgrade, Vanity, VerifyMessage, WhatIsMining,
 };
 
 #[rustfmt::skip]
@@ -24,21 +24,39 @@ pub enum Route {
         #[layout(MinerToolbarLayout)]
             #[route("/home")]
             Home {},
-            #[route("/claim")]
-            Claim {},
+            #[route("/dashboard")]
+            Dashboard {},
             #[route("/mine")]
             Mine {},
             #[route("/stake")]
             Stake {},
             #[route("/settings")]
             Settings {},
-            #[route("/settings/export-key")]
-            ExportKey {},
-            #[route("/settings/import-key")]
-            ImportKey {},
-            #[route("/send/:to")]
-            Send {
-                to: String
+            #[route("/settings/passphrase")]
+            SetPassphrase {},
+            #[layout(Reauthenticate)]
+                #[route("/settings/backup")]
+                Backup {},
+                #[route("/claim")]
+                Claim {},
+                #[route("/settings/export-key")]
+                ExportKey {},
+                #[route("/settings/import-key")]
+                ImportKey {},
+                #[route("/settings/new-key")]
+                NewKey {},
+                #[route("/send/:to")]
+                Send {
+                    to: String
+                },
+                #[route("/settings/sign-message")]
+                SignMessage {},
+                #[route("/settings/vanity")]
+                Vanity {},
+            #[end_layout]
+            #[route("/verify-message?:pubkey")]
+            VerifyMessage {
+                pubkey: String,
             },
             #[route("/pay")]
             Pay {},
@@ -2,6 +2,7 @@ use cached::proc_macro::cached;
 use ore_api::consts::PROOF;
 use solana_client_wasm::solana_sdk::pubkey::Pubkey;
 
+#[cfg(feature = "web")]
 pub fn asset_path(relative_path: &str) -> String {
     relative_path.to_string()
 }
@@ -9,4 +10,37 @@ pub fn asset_path(relative_path: &str) -> String {
 #[cached]
 pub fn proof_pubkey(authority: Pubkey) -> Pubkey {
     Pubkey::find_program_address(&[PROOF, authority.as_ref()], &ore_api::ID).0
-}
\ No newline at end of file
+}
+
+/// Prompts the browser to save `contents` as a file named `filename`.
+#[cfg(feature = "web")]
+pub fn download_file(filename: &str, contents: &str, mime_type: &str) {
+    use wasm_bindgen::JsCast;
+    use web_sys::{window, Blob, BlobPropertyBag, HtmlAnchorElement, Url};
+
+    let parts = js_sys::Array::of1(&contents.into());
+    let Ok(blob) =
+        Blob::new_with_str_sequence_and_options(&parts, BlobPropertyBag::new().type_(mime_type))
+    else {
+        log::error!("Failed to create blob for {}", filename);
+        return;
+    };
+    let Ok(url) = Url::create_object_url_with_blob(&blob) else {
+        log::error!("Failed to create object url for {}", filename);
+        return;
+    };
+    let document = window()
+        .expect("should have a window")
+        .document()
+        .expect("should have a document");
+    if let Some(anchor) = document
+        .create_element("a")
+        .ok()
+        .and_then(|el| el.dyn_into::<HtmlAnchorElement>().ok())
+    {
+        anchor.set_href(&url);
+        anchor.set_download(filename);
+        anchor.click();
+    }
+    Url::revoke_object_url(&url).ok();
+}
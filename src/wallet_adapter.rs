@@ -0,0 +1,78 @@
+use base64::{prelude::BASE64_STANDARD, Engine};
+use serde::{Deserialize, Serialize};
+use serde_wasm_bindgen::{from_value, to_value};
+use solana_client_wasm::solana_sdk::{pubkey::Pubkey, transaction::Transaction};
+use wasm_bindgen::prelude::*;
+use web_sys::{window, CustomEvent};
+
+use crate::gateway::{GatewayError, GatewayResult};
+
+/// Id of the element the wallet adapter button is rendered into.
+pub const WALLET_ADAPTER_ELEMENT_ID: &str = "ore-wallet-adapter";
+
+/// Name of the event dispatched by the bridge whenever the connected wallet changes.
+const PUBKEY_EVENT: &str = "ore-pubkey";
+
+// Bindings to the wallet bridge in `public/wallet.js` (or `public/wallet_mock.js` in tests).
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_name = MountWalletAdapter, catch)]
+    fn mount_wallet_adapter_js() -> Result<(), JsValue>;
+
+    #[wasm_bindgen(js_name = OreTxSigner, catch)]
+    async fn ore_tx_signer(msg: JsValue) -> Result<JsValue, JsValue>;
+}
+
+#[derive(Debug, Deserialize)]
+struct PubkeyEventDetail {
+    pubkey: Option<Vec<u8>>,
+}
+
+#[derive(Debug, Serialize)]
+struct SignTransactionRequest {
+    b64: String,
+}
+
+/// Renders the wallet connect button into the `ore-wallet-adapter` element.
+pub fn mount_wallet_adapter() {
+    if let Err(err) = mount_wallet_adapter_js() {
+        log::error!("Failed to mount wallet adapter: {:?}", err);
+    }
+}
+
+/// Registers a callback invoked with the connected wallet's pubkey, or `None` on disconnect.
+pub fn on_wallet_change(mut callback: impl FnMut(Option<Pubkey>) + 'static) {
+    let closure = Closure::<dyn FnMut(CustomEvent)>::new(move |e: CustomEvent| {
+        let pubkey = from_value::<PubkeyEventDetail>(e.detail())
+            .ok()
+            .and_then(|detail| detail.pubkey)
+            .and_then(|bytes| Pubkey::try_from(bytes.as_slice()).ok());
+        callback(pubkey);
+    });
+    window()
+        .expect("should have a window")
+        .add_event_listener_with_callback(PUBKEY_EVENT, closure.as_ref().unchecked_ref())
+        .ok();
+    closure.forget();
+}
+
+/// Asks the connected wallet to sign the transaction. Existing signatures are preserved.
+pub async fn sign_transaction(tx: &Transaction) -> GatewayResult<Transaction> {
+    let bytes = bincode::serialize(tx).or(Err(GatewayError::FailedDeserialization))?;
+    let msg = to_value(&SignTransactionRequest {
+        b64: BASE64_STANDARD.encode(bytes),
+    })
+    .or(Err(GatewayError::WalletUnavailable))?;
+    let signed = ore_tx_signer(msg)
+        .await
+        .map_err(|err| {
+            log::error!("Wallet signer unavailable: {:?}", err);
+            GatewayError::WalletUnavailable
+        })?
+        .as_string()
+        .ok_or(GatewayError::WalletRejected)?;
+    let bytes = BASE64_STANDARD
+        .decode(signed)
+        .or(Err(GatewayError::FailedDeserialization))?;
+    bincode::deserialize(&bytes).or(Err(GatewayError::FailedDeserialization))
+}